@@ -13,6 +13,22 @@ pub struct Connection {
     inner: rusqlite::Connection,
 }
 
+/// Rolls back the open transaction when dropped while still armed
+struct RollbackGuard<'a> {
+    conn: &'a mut Connection,
+    rollback: &'static str,
+    armed: bool,
+}
+
+impl Drop for RollbackGuard<'_> {
+    fn drop(&mut self) {
+        if self.armed {
+            error!("[DB] transaction: rolled back after a panic");
+            self.conn.roll_back(self.rollback);
+        }
+    }
+}
+
 impl Connection {
     pub fn new(path: &str) -> Result<Self> {
         debug!("[DB] Opening database connection: {}", path);
@@ -61,6 +77,62 @@ impl Connection {
         &mut self.inner
     }
 
+    /// Run a unit of work inside a single transaction.
+    ///
+    /// The transaction is committed if `f` returns `Ok` and rolled back if it returns `Err`.
+    /// When called while a transaction is already open on this connection, a savepoint is
+    /// used instead so units of work can be nested.
+    pub fn transaction<T, F>(&mut self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Connection) -> Result<T>,
+    {
+        let nested = !self.inner.is_autocommit();
+        let (begin, commit, rollback) = if nested {
+            ("SAVEPOINT unit_of_work", "RELEASE unit_of_work", "ROLLBACK TO unit_of_work; RELEASE unit_of_work")
+        } else {
+            ("BEGIN IMMEDIATE", "COMMIT", "ROLLBACK")
+        };
+
+        debug!("[DB] transaction: {}", begin);
+        let start = std::time::Instant::now();
+
+        self.inner.execute_batch(begin)
+            .map_err(|e| {
+                error!("[DB] Failed to begin transaction: {}", e);
+                anyhow::anyhow!("Failed to begin transaction: {}", e)
+            })?;
+
+        // Rolls back if `f` unwinds, so the connection never goes back to the pool with
+        // the transaction still open
+        let mut guard = RollbackGuard { conn: self, rollback, armed: true };
+        let outcome = f(guard.conn);
+        guard.armed = false;
+        let conn = &mut *guard.conn;
+
+        match outcome {
+            Ok(result) => {
+                if let Err(e) = conn.inner.execute_batch(commit) {
+                    error!("[DB] Failed to commit transaction: {}", e);
+                    conn.roll_back(rollback);
+                    return Err(anyhow::anyhow!("Failed to commit transaction: {}", e));
+                }
+                debug!("[DB] transaction: committed in {:?}", start.elapsed());
+                Ok(result)
+            }
+            Err(e) => {
+                conn.roll_back(rollback);
+                error!("[DB] transaction: rolled back after {:?}: {}", start.elapsed(), e);
+                Err(e)
+            }
+        }
+    }
+
+    fn roll_back(&mut self, rollback: &str) {
+        if let Err(rollback_error) = self.inner.execute_batch(rollback) {
+            error!("[DB] Failed to roll back transaction: {}", rollback_error);
+        }
+    }
+
     pub fn execute(
         &self,
         sql: &str,
@@ -123,3 +195,68 @@ impl Connection {
 pub use rusqlite::params;
 use rusqlite::ParamsFromIter;
 pub use rusqlite::ToSql;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connection() -> Connection {
+        let mut conn = Connection::new(":memory:").unwrap();
+        conn.inner().execute_batch(
+            "CREATE TABLE parents (id TEXT PRIMARY KEY);
+             CREATE TABLE children (
+                 id TEXT PRIMARY KEY,
+                 parent_id TEXT REFERENCES parents(id) DEFERRABLE INITIALLY DEFERRED
+             );",
+        ).unwrap();
+        conn
+    }
+
+    fn count(conn: &mut Connection, table: &str) -> i64 {
+        conn.inner().query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0)).unwrap()
+    }
+
+    #[test]
+    fn failed_commit_is_rolled_back() {
+        let mut conn = connection();
+        // The deferred foreign key is only checked by COMMIT, which then leaves the transaction open
+        let result = conn.transaction(|conn| {
+            conn.inner().execute("INSERT INTO children (id, parent_id) VALUES ('c', 'missing')", [])?;
+            Ok(())
+        });
+        assert!(result.is_err());
+        assert!(conn.inner().is_autocommit());
+        assert_eq!(count(&mut conn, "children"), 0);
+    }
+
+    #[test]
+    fn panic_is_rolled_back() {
+        let mut conn = connection();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            conn.transaction(|conn| -> Result<()> {
+                conn.inner().execute("INSERT INTO parents (id) VALUES ('p')", [])?;
+                panic!("unit of work failed");
+            })
+        }));
+        assert!(result.is_err());
+        assert!(conn.inner().is_autocommit());
+        assert_eq!(count(&mut conn, "parents"), 0);
+    }
+
+    #[test]
+    fn nested_panic_rolls_back_to_the_savepoint() {
+        let mut conn = connection();
+        conn.transaction(|conn| {
+            conn.inner().execute("INSERT INTO parents (id) VALUES ('outer')", [])?;
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                conn.transaction(|conn| -> Result<()> {
+                    conn.inner().execute("INSERT INTO parents (id) VALUES ('inner')", [])?;
+                    panic!("nested unit of work failed");
+                })
+            }));
+            assert!(result.is_err());
+            Ok(())
+        }).unwrap();
+        assert_eq!(count(&mut conn, "parents"), 1);
+    }
+}
@@ -0,0 +1,8 @@
+use rusqlite_migration::M;
+
+pub fn migration() -> M<'static> {
+    M::up(
+        "ALTER TABLE work_items ADD COLUMN updated_by TEXT;",
+    )
+        .down("ALTER TABLE work_items DROP COLUMN updated_by;")
+}
@@ -0,0 +1,8 @@
+use rusqlite_migration::M;
+
+pub fn migration() -> M<'static> {
+    M::up(
+        "ALTER TABLE work_item_types ADD COLUMN workflow TEXT;",
+    )
+        .down("ALTER TABLE work_item_types DROP COLUMN workflow;")
+}
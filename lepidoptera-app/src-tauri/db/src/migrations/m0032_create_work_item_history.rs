@@ -0,0 +1,28 @@
+use rusqlite_migration::M;
+
+pub fn migration() -> M<'static> {
+    M::up(
+        "CREATE TABLE IF NOT EXISTS work_item_history (
+            id TEXT PRIMARY KEY,
+            project_id TEXT NOT NULL,
+            work_item_id TEXT NOT NULL,
+            change_type TEXT NOT NULL,
+            field TEXT,
+            old_value TEXT,
+            new_value TEXT,
+            changed_by TEXT NOT NULL,
+            changed_at TEXT NOT NULL,
+            FOREIGN KEY (work_item_id) REFERENCES work_items(id),
+            FOREIGN KEY (project_id) REFERENCES projects(id)
+        );
+        CREATE INDEX IF NOT EXISTS idx_work_item_history_work_item ON work_item_history(work_item_id, changed_at);
+        CREATE INDEX IF NOT EXISTS idx_work_item_history_project ON work_item_history(project_id, changed_at);
+        CREATE INDEX IF NOT EXISTS idx_work_item_history_changed_by ON work_item_history(changed_by);",
+    )
+        .down(
+            "DROP INDEX IF EXISTS idx_work_item_history_changed_by;
+             DROP INDEX IF EXISTS idx_work_item_history_project;
+             DROP INDEX IF EXISTS idx_work_item_history_work_item;
+             DROP TABLE IF EXISTS work_item_history;"
+        )
+}
@@ -0,0 +1,50 @@
+use rusqlite_migration::M;
+
+/// Adds typed projections of custom field values so they can be sorted and range-filtered
+/// by value rather than by text, and backfills them for existing rows.
+///
+/// - `value_number` holds the value of number fields
+/// - `value_date` holds the value of date/datetime fields as UTC `YYYY-MM-DDTHH:MM:SSZ`
+pub fn migration() -> M<'static> {
+    M::up(
+        "ALTER TABLE work_item_field_values ADD COLUMN value_number REAL;
+        ALTER TABLE work_item_field_values ADD COLUMN value_date TEXT;
+        CREATE INDEX IF NOT EXISTS idx_work_item_field_values_number ON work_item_field_values(field_id, value_number);
+        CREATE INDEX IF NOT EXISTS idx_work_item_field_values_date ON work_item_field_values(field_id, value_date);
+
+        UPDATE work_item_field_values
+        SET value_number = CAST(trim(value) AS REAL)
+        WHERE is_assignment_field = 0
+          AND trim(value) GLOB '*[0-9]*'
+          AND NOT trim(value) GLOB '*[^0-9.eE+-]*'
+          AND EXISTS (
+              SELECT 1
+              FROM work_items wi
+              JOIN work_item_types wit ON wit.id = wi.type_id
+              JOIN json_each(wit.work_item_fields) field
+              WHERE wi.id = work_item_field_values.work_item_id
+                AND json_extract(field.value, '$.id') = work_item_field_values.field_id
+                AND json_extract(field.value, '$.field_type') IN ('number', 'integer')
+          );
+
+        UPDATE work_item_field_values
+        SET value_date = strftime('%Y-%m-%dT%H:%M:%SZ', trim(value))
+        WHERE is_assignment_field = 0
+          AND trim(value) <> ''
+          AND EXISTS (
+              SELECT 1
+              FROM work_items wi
+              JOIN work_item_types wit ON wit.id = wi.type_id
+              JOIN json_each(wit.work_item_fields) field
+              WHERE wi.id = work_item_field_values.work_item_id
+                AND json_extract(field.value, '$.id') = work_item_field_values.field_id
+                AND json_extract(field.value, '$.field_type') IN ('date', 'datetime')
+          );",
+    )
+        .down(
+            "DROP INDEX IF EXISTS idx_work_item_field_values_date;
+            DROP INDEX IF EXISTS idx_work_item_field_values_number;
+            ALTER TABLE work_item_field_values DROP COLUMN value_date;
+            ALTER TABLE work_item_field_values DROP COLUMN value_number;",
+        )
+}
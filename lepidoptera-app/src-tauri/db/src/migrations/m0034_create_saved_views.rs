@@ -0,0 +1,28 @@
+use rusqlite_migration::M;
+
+pub fn migration() -> M<'static> {
+    M::up(
+        "CREATE TABLE IF NOT EXISTS saved_views (
+            id TEXT PRIMARY KEY,
+            project_id TEXT NOT NULL,
+            person_id TEXT,
+            name TEXT NOT NULL,
+            query TEXT NOT NULL,
+            include_fields TEXT,
+            created_at TEXT NOT NULL,
+            updated_at TEXT,
+            created_by TEXT NOT NULL,
+            updated_by TEXT,
+            is_active INTEGER NOT NULL DEFAULT 1,
+            FOREIGN KEY (project_id) REFERENCES projects(id),
+            FOREIGN KEY (person_id) REFERENCES persons(id)
+        );
+        CREATE INDEX IF NOT EXISTS idx_saved_views_project ON saved_views(project_id, is_active);
+        CREATE INDEX IF NOT EXISTS idx_saved_views_person ON saved_views(person_id);",
+    )
+        .down(
+            "DROP INDEX IF EXISTS idx_saved_views_person;
+             DROP INDEX IF EXISTS idx_saved_views_project;
+             DROP TABLE IF EXISTS saved_views;"
+        )
+}
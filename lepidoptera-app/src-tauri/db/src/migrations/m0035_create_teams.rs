@@ -0,0 +1,40 @@
+use rusqlite_migration::M;
+
+/// Creates the tables behind the people crate's `Team` and `TeamMember` entities,
+/// so team assignment fields can reference real teams.
+pub fn migration() -> M<'static> {
+    M::up(
+        "CREATE TABLE IF NOT EXISTS teams (
+            id TEXT PRIMARY KEY,
+            created_at TEXT NOT NULL,
+            created_by TEXT NOT NULL,
+            updated_at TEXT,
+            updated_by TEXT,
+            name TEXT NOT NULL,
+            description TEXT NOT NULL DEFAULT '',
+            is_active INTEGER NOT NULL DEFAULT 1,
+            display_settings TEXT NOT NULL DEFAULT '{}'
+        );
+        CREATE TABLE IF NOT EXISTS team_members (
+            id TEXT PRIMARY KEY,
+            created_at TEXT NOT NULL,
+            created_by TEXT NOT NULL,
+            updated_at TEXT,
+            updated_by TEXT,
+            team_id TEXT NOT NULL,
+            person_id TEXT NOT NULL,
+            role TEXT NOT NULL,
+            FOREIGN KEY (team_id) REFERENCES teams(id),
+            FOREIGN KEY (person_id) REFERENCES persons(id),
+            UNIQUE(team_id, person_id)
+        );
+        CREATE INDEX IF NOT EXISTS idx_team_members_team_id ON team_members(team_id);
+        CREATE INDEX IF NOT EXISTS idx_team_members_person_id ON team_members(person_id);",
+    )
+        .down(
+            "DROP INDEX IF EXISTS idx_team_members_person_id;
+             DROP INDEX IF EXISTS idx_team_members_team_id;
+             DROP TABLE IF EXISTS team_members;
+             DROP TABLE IF EXISTS teams;"
+        )
+}
@@ -0,0 +1,16 @@
+use rusqlite_migration::M;
+
+/// Archived work items are kept with their field values and relationships until they are
+/// restored or purged.
+pub fn migration() -> M<'static> {
+    M::up(
+        "ALTER TABLE work_items ADD COLUMN archived_at TEXT;
+         ALTER TABLE work_items ADD COLUMN archived_by TEXT;
+         CREATE INDEX IF NOT EXISTS idx_work_items_archived_at ON work_items(project_id, archived_at);",
+    )
+        .down(
+            "DROP INDEX IF EXISTS idx_work_items_archived_at;
+             ALTER TABLE work_items DROP COLUMN archived_by;
+             ALTER TABLE work_items DROP COLUMN archived_at;",
+        )
+}
@@ -0,0 +1,24 @@
+use rusqlite_migration::M;
+
+/// One row per soft-deleted entity, whatever soft delete column its own table uses.
+/// `project_id` is empty for entities that belong to the workspace, such as persons.
+pub fn migration() -> M<'static> {
+    M::up(
+        "CREATE TABLE IF NOT EXISTS trash_entries (
+            id TEXT PRIMARY KEY,
+            project_id TEXT,
+            entity_type TEXT NOT NULL,
+            entity_id TEXT NOT NULL,
+            display_name TEXT NOT NULL,
+            deleted_at TEXT NOT NULL,
+            deleted_by TEXT NOT NULL,
+            FOREIGN KEY (project_id) REFERENCES projects(id),
+            UNIQUE(entity_type, entity_id)
+        );
+        CREATE INDEX IF NOT EXISTS idx_trash_entries_project ON trash_entries(project_id, deleted_at);",
+    )
+        .down(
+            "DROP INDEX IF EXISTS idx_trash_entries_project;
+             DROP TABLE IF EXISTS trash_entries;"
+        )
+}
@@ -0,0 +1,26 @@
+use rusqlite_migration::M;
+
+/// Numbers a work item had before it moved to another project. `project_id` is the project
+/// that issued the number, so the old number keeps resolving after the move.
+pub fn migration() -> M<'static> {
+    M::up(
+        "CREATE TABLE IF NOT EXISTS work_item_number_aliases (
+            id TEXT PRIMARY KEY,
+            work_item_id TEXT NOT NULL,
+            project_id TEXT NOT NULL,
+            sequential_number TEXT NOT NULL,
+            created_at TEXT NOT NULL,
+            created_by TEXT NOT NULL,
+            FOREIGN KEY (work_item_id) REFERENCES work_items(id),
+            FOREIGN KEY (project_id) REFERENCES projects(id),
+            UNIQUE(project_id, sequential_number)
+        );
+        CREATE INDEX IF NOT EXISTS idx_work_item_number_aliases_number ON work_item_number_aliases(sequential_number);
+        CREATE INDEX IF NOT EXISTS idx_work_item_number_aliases_work_item ON work_item_number_aliases(work_item_id);",
+    )
+        .down(
+            "DROP INDEX IF EXISTS idx_work_item_number_aliases_work_item;
+             DROP INDEX IF EXISTS idx_work_item_number_aliases_number;
+             DROP TABLE IF EXISTS work_item_number_aliases;"
+        )
+}
@@ -27,6 +27,15 @@ mod m0026_create_messages;
 mod m0027_create_read_records;
 mod m0028_create_tags;
 mod m0029_create_tagged_items;
+mod m0030_add_work_items_updated_by;
+mod m0031_add_work_item_types_workflow;
+mod m0032_create_work_item_history;
+mod m0033_add_work_item_field_value_projections;
+mod m0034_create_saved_views;
+mod m0035_create_teams;
+mod m0036_add_work_items_archived;
+mod m0037_create_trash_entries;
+mod m0038_create_work_item_number_aliases;
 
 use rusqlite_migration::{Migrations};
 
@@ -61,6 +70,15 @@ pub fn get_migrations() -> Migrations<'static> {
         m0027_create_read_records::migration(),
         m0028_create_tags::migration(),
         m0029_create_tagged_items::migration(),
+        m0030_add_work_items_updated_by::migration(),
+        m0031_add_work_item_types_workflow::migration(),
+        m0032_create_work_item_history::migration(),
+        m0033_add_work_item_field_value_projections::migration(),
+        m0034_create_saved_views::migration(),
+        m0035_create_teams::migration(),
+        m0036_add_work_items_archived::migration(),
+        m0037_create_trash_entries::migration(),
+        m0038_create_work_item_number_aliases::migration(),
       ])
 }
 
@@ -46,6 +46,27 @@ impl<E: Entity> GenericRepository<E> {
         f(pooled_conn.get_mut())
     }
 
+    /// Run `f` on the provided connection, or on a pooled connection if none is provided.
+    ///
+    /// Repositories use this so their methods can take part in a caller's unit of work.
+    pub fn with_optional_connection<F, T>(&self, conn: Option<&mut Connection>, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Connection) -> Result<T>,
+    {
+        match conn {
+            Some(conn_ref) => f(conn_ref),
+            None => self.with_connection(f),
+        }
+    }
+
+    /// Run `f` inside a single transaction on a pooled connection
+    pub fn with_transaction<F, T>(&self, f: F) -> Result<T>
+    where
+        F: FnOnce(&mut Connection) -> Result<T>,
+    {
+        self.with_connection(|conn| conn.transaction(f))
+    }
+
     pub fn connection(&self) -> Result<PooledConnection> {
         self.pool.get()
     }
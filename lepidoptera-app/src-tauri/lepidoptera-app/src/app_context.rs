@@ -18,6 +18,8 @@ use attachment_store::attachment_ports::AttachmentsManager;
 use attachment_store::attachments_manager::manager::SqliteAttachmentsManager;
 use people::people_ports::PersonManager;
 use people::people_manager::manager::SqlitePeopleManager;
+use trash::trash_ports::{TrashManager, TRASH_RETENTION_SETTING, DEFAULT_TRASH_RETENTION_DAYS};
+use trash::trash_manager::manager::SqliteTrashManager;
 use sync::entities::LocalMachine;
 use crate::settings::settings_store::SettingsStore;
 
@@ -28,6 +30,7 @@ pub struct AppContext {
     pub sync: Arc<dyn SyncManager>,
     pub attachments: Arc<dyn AttachmentsManager>,
     pub people: Arc<dyn PersonManager>,
+    pub trash: Arc<dyn TrashManager>,
     pub local_settings: Arc<Mutex<LocalSettingsStore>>,
     pub local_machine: LocalMachine,
 }
@@ -71,18 +74,69 @@ impl AppContextBuilder {
         let sync_manager = Arc::new(SqliteSyncManager::new(connection_pool.clone()));
         let attachments_manager = Arc::new(SqliteAttachmentsManager::new(connection_pool.clone()));
         let people_manager = Arc::new(SqlitePeopleManager::new(connection_pool.clone()));
+        let trash_manager = Arc::new(SqliteTrashManager::new(connection_pool.clone(), work_items_manager.clone()));
         let local_machine = sync_manager.get_local_machine()?;
         // println!("Local machine: {:?}", local_machine);
 
-        Ok(AppContext {
+        let ctx = AppContext {
             work_items: work_items_manager,
             projects: projects_manager,
             documents: documents_manager,
             sync: sync_manager,
             attachments: attachments_manager,
             people: people_manager,
+            trash: trash_manager,
             local_settings: Arc::new(Mutex::new(self.settings)),
             local_machine,
-        })
+        };
+
+        ctx.purge_expired_trash();
+        Ok(ctx)
+    }
+}
+
+impl AppContext {
+    /// How many days entries stay in a project's trash, from its `TRASH_RETENTION_DAYS` setting.
+    /// The workspace trash (`None`) always uses the default.
+    pub fn trash_retention_days(&self, project_id: Option<&str>) -> Result<u32> {
+        let Some(project_id) = project_id else {
+            return Ok(DEFAULT_TRASH_RETENTION_DAYS);
+        };
+
+        match self.projects.get_project_setting(project_id.to_string(), TRASH_RETENTION_SETTING.to_string())? {
+            None => Ok(DEFAULT_TRASH_RETENTION_DAYS),
+            Some(value) => value.as_u64()
+                .or_else(|| value.as_str().and_then(|s| s.trim().parse().ok()))
+                .and_then(|days| u32::try_from(days).ok())
+                .ok_or_else(|| anyhow::anyhow!("{} setting must be a whole number of days", TRASH_RETENTION_SETTING)),
+        }
+    }
+
+    /// Purge trash entries older than their project's retention period. Failures are logged,
+    /// they don't stop the application from starting.
+    fn purge_expired_trash(&self) {
+        // Every project's trash, then the workspace trash
+        let mut scopes: Vec<Option<String>> = match self.projects.get_projects() {
+            Ok(projects) => projects.into_iter().map(|project| project.id).filter(Option::is_some).collect(),
+            Err(e) => {
+                log::warn!("Failed to list projects for trash retention: {}", e);
+                return;
+            }
+        };
+        scopes.push(None);
+
+        for scope in &scopes {
+            let project_id = scope.as_deref();
+            let result = self.trash_retention_days(project_id)
+                .and_then(|days| self.trash.empty_trash(project_id, Some(days)));
+            match result {
+                Ok(result) if !result.purged.is_empty() || !result.skipped.is_empty() => log::info!(
+                    "Purged {} expired trash entries ({} kept) for {}",
+                    result.purged.len(), result.skipped.len(), project_id.unwrap_or("workspace")
+                ),
+                Ok(_) => {}
+                Err(e) => log::warn!("Failed to purge expired trash for {}: {}", project_id.unwrap_or("workspace"), e),
+            }
+        }
     }
 }
@@ -16,7 +16,7 @@ pub fn get_default_kanban_template() -> Vec<WorkItemTypeTemplate> {
             ],
             allowed_priorities: vec![
                 json!({"id": "low", "label": "Low", "value": 1, "color": "#6b7280"}),
-                json!({"id": "medium", "label": "Medium", "value": 2, "color": "#f59e0b"}),
+                json!({"id": "medium", "label": "Medium", "value": 2, "color": "#f59e0b", "is_default": true}),
                 json!({"id": "high", "label": "High", "value": 3, "color": "#ef4444"}),
             ],
             assignment_field_definitions: vec![
@@ -43,7 +43,7 @@ pub fn get_default_kanban_template() -> Vec<WorkItemTypeTemplate> {
             ],
             allowed_priorities: vec![
                 json!({"id": "low", "label": "Low", "value": 1, "color": "#6b7280"}),
-                json!({"id": "medium", "label": "Medium", "value": 2, "color": "#f59e0b"}),
+                json!({"id": "medium", "label": "Medium", "value": 2, "color": "#f59e0b", "is_default": true}),
                 json!({"id": "high", "label": "High", "value": 3, "color": "#ef4444"}),
             ],
             assignment_field_definitions: vec![
@@ -70,7 +70,7 @@ pub fn get_default_kanban_template() -> Vec<WorkItemTypeTemplate> {
             ],
             allowed_priorities: vec![
                 json!({"id": "low", "label": "Low", "value": 1, "color": "#6b7280"}),
-                json!({"id": "medium", "label": "Medium", "value": 2, "color": "#f59e0b"}),
+                json!({"id": "medium", "label": "Medium", "value": 2, "color": "#f59e0b", "is_default": true}),
                 json!({"id": "high", "label": "High", "value": 3, "color": "#ef4444"}),
             ],
             assignment_field_definitions: vec![
@@ -94,7 +94,7 @@ pub fn get_default_kanban_template() -> Vec<WorkItemTypeTemplate> {
             ],
             allowed_priorities: vec![
                 json!({"id": "low", "label": "Low", "value": 1, "color": "#6b7280"}),
-                json!({"id": "medium", "label": "Medium", "value": 2, "color": "#f59e0b"}),
+                json!({"id": "medium", "label": "Medium", "value": 2, "color": "#f59e0b", "is_default": true}),
                 json!({"id": "high", "label": "High", "value": 3, "color": "#ef4444"}),
             ],
             assignment_field_definitions: vec![
@@ -7,4 +7,5 @@ pub mod file_commands;
 pub mod logging;
 pub mod default_kanban_template;
 pub mod person_commands;
-pub mod window_commands;
\ No newline at end of file
+pub mod window_commands;
+pub mod trash_commands;
\ No newline at end of file
@@ -56,6 +56,10 @@ pub fn get_navigation(
 
     build_top_level_sections(&mut sections);
     build_conversation_section(&mut sections);
+    if let Err(e) = build_saved_views_section(&mut sections, &ctx, &project_id) {
+        error!("[COMMAND] {} failed to build saved views section: {}", command_name, e);
+        // Continue execution - don't fail the entire navigation if saved views fail
+    }
     if let Err(e) = build_work_item_section(&mut sections, &ctx, &project_id) {
         error!("[COMMAND] {} failed to build work item section: {}", command_name, e);
         // Continue execution - don't fail the entire navigation if work items fail
@@ -87,6 +91,46 @@ fn build_conversation_section(sections: &mut Vec<NavigationSection>) {
     })
 }
 
+fn build_saved_views_section(
+    sections: &mut Vec<NavigationSection>,
+    ctx: &Arc<AppContext>,
+    project_id: &str,
+) -> Result<(), String> {
+    // Only shared views are listed; personal views are not part of the project navigation
+    let saved_views = ctx.work_items.list_saved_views(project_id, None)
+        .map_err(|e| format!("Failed to get saved views: {}", e))?;
+
+    if saved_views.is_empty() {
+        return Ok(());
+    }
+
+    let view_items: Vec<NavigationItem> = saved_views
+        .iter()
+        .filter_map(|view| {
+            let view_id = view.id.as_ref()?;
+            Some(NavigationItem {
+                id: format!("view-{}", view_id),
+                label: view.name.clone(),
+                icon: None,
+                children: None,
+                show_hover_menu: Some(true),
+                unread: None,
+                sequential_number: None,
+            })
+        })
+        .collect();
+
+    sections.push(NavigationSection {
+        id: "saved-views".to_string(),
+        label: "Views".to_string(),
+        icon: Some("FaList".to_string()),
+        items: view_items,
+        spacing_before: Some(true),
+    });
+
+    Ok(())
+}
+
 fn build_work_item_section(
     sections: &mut Vec<NavigationSection>,
     ctx: &Arc<AppContext>,
@@ -137,6 +181,7 @@ fn build_work_item_section(
             project_id: project_id.to_string(),
             type_id: Some(type_id.clone()),
             statuses: None,
+            status_categories: None,
             priority: None,
             priority_min: None,
             priority_max: None,
@@ -152,11 +197,13 @@ fn build_work_item_section(
             offset: None,
             sort_by: None,
             sort_direction: None,
+            include_archived: None,
         };
 
         let request = WorkItemListRequest {
             query,
             include_fields: None,
+            include_category_counts: None,
         };
 
         let work_items_response = ctx.work_items.list_work_items(request)
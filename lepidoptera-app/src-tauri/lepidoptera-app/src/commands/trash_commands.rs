@@ -0,0 +1,127 @@
+use std::sync::Arc;
+use crate::app_context::AppContext;
+use trash::models::{TrashEntityType, TrashEntryModel, EmptyTrashResult};
+use tauri::State;
+use log::{debug, error, info};
+
+#[tauri::command]
+pub fn move_to_trash(
+    state: State<'_, Arc<AppContext>>,
+    entity_type: TrashEntityType,
+    entity_id: String,
+    deleted_by: String,
+) -> Result<TrashEntryModel, String> {
+    let command_name = "move_to_trash";
+    debug!("[COMMAND] {} called: entity_type={}, entity_id={}, deleted_by={}", command_name, entity_type.as_str(), entity_id, deleted_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let trash_manager = ctx.trash.clone();
+    
+    match trash_manager.move_to_trash(entity_type, &entity_id, &deleted_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn list_trash(
+    state: State<'_, Arc<AppContext>>,
+    project_id: Option<String>,
+) -> Result<Vec<TrashEntryModel>, String> {
+    let command_name = "list_trash";
+    debug!("[COMMAND] {} called: project_id={:?}", command_name, project_id);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let trash_manager = ctx.trash.clone();
+    
+    match trash_manager.list_trash(project_id.as_deref()) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (found {} entries)", command_name, duration, result.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn restore_from_trash(
+    state: State<'_, Arc<AppContext>>,
+    trash_entry_id: String,
+    restored_by: String,
+) -> Result<TrashEntryModel, String> {
+    let command_name = "restore_from_trash";
+    debug!("[COMMAND] {} called: trash_entry_id={}, restored_by={}", command_name, trash_entry_id, restored_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let trash_manager = ctx.trash.clone();
+    
+    match trash_manager.restore_from_trash(&trash_entry_id, &restored_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+/// Empty a project's trash, or the workspace trash when `project_id` is omitted.
+/// With `expired_only`, only entries older than the project's retention period are purged.
+#[tauri::command]
+pub fn empty_trash(
+    state: State<'_, Arc<AppContext>>,
+    project_id: Option<String>,
+    expired_only: bool,
+) -> Result<EmptyTrashResult, String> {
+    let command_name = "empty_trash";
+    debug!("[COMMAND] {} called: project_id={:?}, expired_only={}", command_name, project_id, expired_only);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let trash_manager = ctx.trash.clone();
+
+    let retention_days = if expired_only {
+        match ctx.trash_retention_days(project_id.as_deref()) {
+            Ok(days) => Some(days),
+            Err(e) => {
+                error!("[COMMAND] {} failed to read the trash retention period: {}", command_name, e);
+                return Err(e.to_string());
+            }
+        }
+    } else {
+        None
+    };
+    
+    match trash_manager.empty_trash(project_id.as_deref(), retention_days) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (purged {}, kept {})", command_name, duration, result.purged.len(), result.skipped.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
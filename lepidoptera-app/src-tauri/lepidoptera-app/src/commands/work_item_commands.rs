@@ -1,6 +1,7 @@
 use std::sync::Arc;
 use crate::app_context::AppContext;
-use work_items::models::{WorkItemModel, WorkItemTypeModel, WorkItemListRequest, WorkItemListResponse};
+use work_items::models::{WorkItemModel, WorkItemTypeModel, WorkItemQuery, WorkItemListRequest, WorkItemListResponse, WorkItemHistoryEntryModel, WorkItemHierarchyEntry, WorkItemTreeNode, SavedViewModel, WorkItemSelection, WorkItemPatch, BulkUpdateResult, WorkItemTypeChange, WorkItemTypeImpact, WorkItemTypeMigration, WorkItemFieldValueModel, FieldState, WorkItemCloneOptions, WorkItemCloneResult, WorkItemMove, WorkItemMoveResult};
+use work_items::schemas::AllowedStatus;
 use tauri::State;
 use log::{debug, error, info};
 use serde_json::Value;
@@ -16,41 +17,441 @@ pub fn create_work_item(
     
     let ctx = state.inner();
     
-    // Get machine_id from local_machine (using the sync instance ID, not os_machine_id)
-    // This ID is synced across machines and used to track assigned number ranges
-    let machine_id = ctx.local_machine.id
-        .as_ref()
-        .ok_or_else(|| {
-            error!("[COMMAND] {} local_machine.id is None", command_name);
-            "Local machine ID is not available".to_string()
-        })?
-        .clone();
+    let machine_id = local_machine_id(ctx, command_name)?;
+    let sequence_prefix_setting = project_sequence_prefix(ctx, &work_item.project_id, command_name)?;
     
-    // Get sequence_prefix from project settings
-    let projects_manager = ctx.projects.clone();
-    let project_id = work_item.project_id.clone();
+    debug!("[COMMAND] {} using sequence_prefix={}, machine_id={}", command_name, sequence_prefix_setting, machine_id);
     
-    let sequence_prefix_setting = match projects_manager.get_project_setting(project_id.clone(), "SEQUENCE_PREFIX".to_string()) {
-        Ok(Some(Value::String(prefix))) => prefix,
-        Ok(Some(_)) => {
-            error!("[COMMAND] {} SEQUENCE_PREFIX setting is not a string", command_name);
-            return Err("SEQUENCE_PREFIX setting must be a string".to_string());
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.create_work_item(work_item, &sequence_prefix_setting, &machine_id) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn update_work_item(
+    state: State<'_, Arc<AppContext>>,
+    work_item: WorkItemModel,
+    updated_by: String,
+) -> Result<WorkItemModel, String> {
+    let command_name = "update_work_item";
+    debug!("[COMMAND] {} called: id={:?}, updated_by={}", command_name, work_item.id, updated_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.update_work_item(work_item, &updated_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn bulk_update_work_items(
+    state: State<'_, Arc<AppContext>>,
+    selection: WorkItemSelection,
+    patch: WorkItemPatch,
+    updated_by: String,
+) -> Result<BulkUpdateResult, String> {
+    let command_name = "bulk_update_work_items";
+    debug!("[COMMAND] {} called: updated_by={}", command_name, updated_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.bulk_update_work_items(selection, patch, &updated_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} ({} updated, {} failed, {} selected)",
+                command_name, duration, result.updated, result.failed, result.results.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn change_work_item_type(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+    change: WorkItemTypeChange,
+    updated_by: String,
+) -> Result<WorkItemModel, String> {
+    let command_name = "change_work_item_type";
+    debug!("[COMMAND] {} called: work_item_id={}, new_type_id={}, updated_by={}", command_name, work_item_id, change.new_type_id, updated_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.change_work_item_type(&work_item_id, change, &updated_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn archive_work_item(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+    archived_by: String,
+) -> Result<WorkItemModel, String> {
+    let command_name = "archive_work_item";
+    debug!("[COMMAND] {} called: work_item_id={}, archived_by={}", command_name, work_item_id, archived_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.archive_work_item(&work_item_id, &archived_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn restore_work_item(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+    restored_by: String,
+) -> Result<WorkItemModel, String> {
+    let command_name = "restore_work_item";
+    debug!("[COMMAND] {} called: work_item_id={}, restored_by={}", command_name, work_item_id, restored_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.restore_work_item(&work_item_id, &restored_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
         }
+    }
+}
+
+#[tauri::command]
+pub fn purge_work_item(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+) -> Result<(), String> {
+    let command_name = "purge_work_item";
+    debug!("[COMMAND] {} called: work_item_id={}", command_name, work_item_id);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.purge_work_item(&work_item_id) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn clone_work_item(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+    options: WorkItemCloneOptions,
+    cloned_by: String,
+) -> Result<WorkItemCloneResult, String> {
+    let command_name = "clone_work_item";
+    debug!("[COMMAND] {} called: work_item_id={}, options={:?}, cloned_by={}", command_name, work_item_id, options, cloned_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    // Copies are numbered in the source's project
+    let project_id = match work_items_manager.get_work_item(&work_item_id) {
+        Ok(Some(work_item)) => work_item.project_id,
         Ok(None) => {
-            error!("[COMMAND] {} SEQUENCE_PREFIX setting not found for project {}", command_name, project_id);
-            return Err(format!("SEQUENCE_PREFIX setting not found for project. Please configure a sequence prefix for this project."));
+            error!("[COMMAND] {} work item not found: {}", command_name, work_item_id);
+            return Err(format!("Work item not found: {}", work_item_id));
         }
         Err(e) => {
-            error!("[COMMAND] {} failed to get SEQUENCE_PREFIX setting: {}", command_name, e);
-            return Err(format!("Failed to get SEQUENCE_PREFIX setting: {}", e));
+            error!("[COMMAND] {} failed to get work item: {}", command_name, e);
+            return Err(e.to_string());
         }
     };
+    let machine_id = local_machine_id(ctx, command_name)?;
+    let sequence_prefix = project_sequence_prefix(ctx, &project_id, command_name)?;
     
-    debug!("[COMMAND] {} using sequence_prefix={}, machine_id={}", command_name, sequence_prefix_setting, machine_id);
+    match work_items_manager.clone_work_item(&work_item_id, options, &cloned_by, &sequence_prefix, &machine_id) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}: {} work item(s) cloned", command_name, duration, result.cloned.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn move_work_item(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+    request: WorkItemMove,
+    moved_by: String,
+) -> Result<WorkItemMoveResult, String> {
+    let command_name = "move_work_item";
+    debug!("[COMMAND] {} called: work_item_id={}, destination_project_id={}, move_children={}, moved_by={}",
+        command_name, work_item_id, request.destination_project_id, request.move_children, moved_by);
+    let start = std::time::Instant::now();
     
+    let ctx = state.inner();
     let work_items_manager = ctx.work_items.clone();
     
-    match work_items_manager.create_work_item(work_item, &sequence_prefix_setting, &machine_id) {
+    // Moved work items are numbered in the destination project
+    let machine_id = local_machine_id(ctx, command_name)?;
+    let sequence_prefix = project_sequence_prefix(ctx, &request.destination_project_id, command_name)?;
+    
+    match work_items_manager.move_work_item(&work_item_id, request, &moved_by, &sequence_prefix, &machine_id) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}: {} work item(s) moved, {} relationship(s) detached",
+                command_name, duration, result.moved.len(), result.detached_relationships);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn resolve_work_item_number(
+    state: State<'_, Arc<AppContext>>,
+    sequential_number: String,
+) -> Result<Option<WorkItemModel>, String> {
+    let command_name = "resolve_work_item_number";
+    debug!("[COMMAND] {} called: sequential_number={}", command_name, sequential_number);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.resolve_work_item_number(&sequential_number) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_next_work_item_statuses(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+) -> Result<Vec<AllowedStatus>, String> {
+    let command_name = "get_next_work_item_statuses";
+    debug!("[COMMAND] {} called: work_item_id={}", command_name, work_item_id);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.get_next_statuses(&work_item_id) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (found {} statuses)", command_name, duration, result.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_field_states(
+    state: State<'_, Arc<AppContext>>,
+    work_item_type_id: String,
+    status: String,
+    field_values: Vec<WorkItemFieldValueModel>,
+) -> Result<Vec<FieldState>, String> {
+    let command_name = "get_field_states";
+    debug!("[COMMAND] {} called: work_item_type_id={}, status={}", command_name, work_item_type_id, status);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.get_field_states(&work_item_type_id, &status, field_values) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (found {} fields)", command_name, duration, result.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_work_item_history(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+) -> Result<Vec<WorkItemHistoryEntryModel>, String> {
+    let command_name = "get_work_item_history";
+    debug!("[COMMAND] {} called: work_item_id={}", command_name, work_item_id);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.get_work_item_history(&work_item_id) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (found {} entries)", command_name, duration, result.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_work_item_ancestors(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+) -> Result<Vec<WorkItemHierarchyEntry>, String> {
+    let command_name = "get_work_item_ancestors";
+    debug!("[COMMAND] {} called: work_item_id={}", command_name, work_item_id);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.get_work_item_ancestors(&work_item_id) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (found {} ancestors)", command_name, duration, result.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_work_item_descendants(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+    max_depth: Option<u32>,
+) -> Result<Vec<WorkItemHierarchyEntry>, String> {
+    let command_name = "get_work_item_descendants";
+    debug!("[COMMAND] {} called: work_item_id={}, max_depth={:?}", command_name, work_item_id, max_depth);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.get_work_item_descendants(&work_item_id, max_depth) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (found {} descendants)", command_name, duration, result.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_work_item_subtree(
+    state: State<'_, Arc<AppContext>>,
+    work_item_id: String,
+    max_depth: Option<u32>,
+) -> Result<Option<WorkItemTreeNode>, String> {
+    let command_name = "get_work_item_subtree";
+    debug!("[COMMAND] {} called: work_item_id={}, max_depth={:?}", command_name, work_item_id, max_depth);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.get_work_item_subtree(&work_item_id, max_depth) {
         Ok(result) => {
             let duration = start.elapsed();
             info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
@@ -90,6 +491,60 @@ pub fn get_work_item_types_by_project(
     }
 }
 
+#[tauri::command]
+pub fn analyze_work_item_type_update(
+    state: State<'_, Arc<AppContext>>,
+    work_item_type: WorkItemTypeModel,
+) -> Result<WorkItemTypeImpact, String> {
+    let command_name = "analyze_work_item_type_update";
+    debug!("[COMMAND] {} called: id={:?}", command_name, work_item_type.id);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.analyze_work_item_type_update(work_item_type) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} ({} work items)", command_name, duration, result.work_item_count);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn update_work_item_type(
+    state: State<'_, Arc<AppContext>>,
+    work_item_type: WorkItemTypeModel,
+    migration: Option<WorkItemTypeMigration>,
+    updated_by: String,
+) -> Result<WorkItemTypeModel, String> {
+    let command_name = "update_work_item_type";
+    debug!("[COMMAND] {} called: id={:?}, updated_by={}", command_name, work_item_type.id, updated_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.update_work_item_type(work_item_type, migration.unwrap_or_default(), &updated_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
 #[tauri::command]
 pub fn get_work_item(
     state: State<'_, Arc<AppContext>>,
@@ -150,3 +605,224 @@ pub fn list_work_items(
     }
 }
 
+#[tauri::command]
+pub fn parse_work_item_query(
+    state: State<'_, Arc<AppContext>>,
+    project_id: String,
+    query: String,
+    current_user: Option<String>,
+) -> Result<WorkItemQuery, String> {
+    let command_name = "parse_work_item_query";
+    debug!("[COMMAND] {} called: project_id={}, query={}", command_name, project_id, query);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.parse_work_item_query(&project_id, &query, current_user.as_deref()) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn create_saved_view(
+    state: State<'_, Arc<AppContext>>,
+    saved_view: SavedViewModel,
+    created_by: String,
+) -> Result<SavedViewModel, String> {
+    let command_name = "create_saved_view";
+    debug!("[COMMAND] {} called: project_id={}, name={}", command_name, saved_view.project_id, saved_view.name);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.create_saved_view(saved_view, &created_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn update_saved_view(
+    state: State<'_, Arc<AppContext>>,
+    saved_view: SavedViewModel,
+    updated_by: String,
+) -> Result<SavedViewModel, String> {
+    let command_name = "update_saved_view";
+    debug!("[COMMAND] {} called: id={:?}, updated_by={}", command_name, saved_view.id, updated_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.update_saved_view(saved_view, &updated_by) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn get_saved_view(
+    state: State<'_, Arc<AppContext>>,
+    id: String,
+) -> Result<Option<SavedViewModel>, String> {
+    let command_name = "get_saved_view";
+    debug!("[COMMAND] {} called: id={}", command_name, id);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.get_saved_view(&id) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (found: {})", command_name, duration, result.is_some());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn list_saved_views(
+    state: State<'_, Arc<AppContext>>,
+    project_id: String,
+    person_id: Option<String>,
+) -> Result<Vec<SavedViewModel>, String> {
+    let command_name = "list_saved_views";
+    debug!("[COMMAND] {} called: project_id={}, person_id={:?}", command_name, project_id, person_id);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.list_saved_views(&project_id, person_id.as_deref()) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (found {} views)", command_name, duration, result.len());
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn delete_saved_view(
+    state: State<'_, Arc<AppContext>>,
+    id: String,
+    deleted_by: String,
+) -> Result<(), String> {
+    let command_name = "delete_saved_view";
+    debug!("[COMMAND] {} called: id={}, deleted_by={}", command_name, id, deleted_by);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.delete_saved_view(&id, &deleted_by) {
+        Ok(()) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?}", command_name, duration);
+            Ok(())
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+#[tauri::command]
+pub fn execute_saved_view(
+    state: State<'_, Arc<AppContext>>,
+    id: String,
+    page: Option<usize>,
+    page_size: Option<usize>,
+) -> Result<WorkItemListResponse, String> {
+    let command_name = "execute_saved_view";
+    debug!("[COMMAND] {} called: id={}, page={:?}, page_size={:?}", command_name, id, page, page_size);
+    let start = std::time::Instant::now();
+    
+    let ctx = state.inner();
+    let work_items_manager = ctx.work_items.clone();
+    
+    match work_items_manager.execute_saved_view(&id, page, page_size) {
+        Ok(result) => {
+            let duration = start.elapsed();
+            info!("[COMMAND] {} completed successfully in {:?} (found {} items, total: {})", 
+                command_name, duration, result.items.len(), result.total);
+            Ok(result)
+        }
+        Err(e) => {
+            let duration = start.elapsed();
+            error!("[COMMAND] {} failed after {:?}: {}", command_name, duration, e);
+            Err(e.to_string())
+        }
+    }
+}
+
+/// The machine id that claims number ranges: the sync instance id, not os_machine_id.
+/// It is synced across machines and used to track assigned number ranges.
+fn local_machine_id(ctx: &AppContext, command_name: &str) -> Result<String, String> {
+    ctx.local_machine.id
+        .as_ref()
+        .ok_or_else(|| {
+            error!("[COMMAND] {} local_machine.id is None", command_name);
+            "Local machine ID is not available".to_string()
+        })
+        .cloned()
+}
+
+/// The project's SEQUENCE_PREFIX setting, which prefixes its work item numbers
+fn project_sequence_prefix(ctx: &AppContext, project_id: &str, command_name: &str) -> Result<String, String> {
+    match ctx.projects.get_project_setting(project_id.to_string(), "SEQUENCE_PREFIX".to_string()) {
+        Ok(Some(Value::String(prefix))) => Ok(prefix),
+        Ok(Some(_)) => {
+            error!("[COMMAND] {} SEQUENCE_PREFIX setting is not a string", command_name);
+            Err("SEQUENCE_PREFIX setting must be a string".to_string())
+        }
+        Ok(None) => {
+            error!("[COMMAND] {} SEQUENCE_PREFIX setting not found for project {}", command_name, project_id);
+            Err("SEQUENCE_PREFIX setting not found for project. Please configure a sequence prefix for this project.".to_string())
+        }
+        Err(e) => {
+            error!("[COMMAND] {} failed to get SEQUENCE_PREFIX setting: {}", command_name, e);
+            Err(format!("Failed to get SEQUENCE_PREFIX setting: {}", e))
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use crate::app_context::AppContextBuilder;
-use crate::commands::work_item_commands::{create_work_item, get_work_item, get_work_item_types_by_project, list_work_items};
+use crate::commands::work_item_commands::{create_work_item, update_work_item, bulk_update_work_items, change_work_item_type, archive_work_item, restore_work_item, purge_work_item, clone_work_item, move_work_item, resolve_work_item_number, get_next_work_item_statuses, get_field_states, get_work_item_history, get_work_item_ancestors, get_work_item_descendants, get_work_item_subtree, get_work_item, get_work_item_types_by_project, analyze_work_item_type_update, update_work_item_type, list_work_items, parse_work_item_query, create_saved_view, update_saved_view, get_saved_view, list_saved_views, delete_saved_view, execute_saved_view};
 use crate::commands::project_template_commands::apply_project_template;
 use crate::commands::project_commands::{create_project, get_project_setting, set_project_setting, ensure_initial_project, get_project_by_id, update_project};
 use crate::commands::person_commands::{ensure_initial_user, get_persons};
 use crate::commands::attachment_commands::{create_attachment, get_attachment};
+use crate::commands::trash_commands::{move_to_trash, list_trash, restore_from_trash, empty_trash};
 use crate::commands::file_commands::read_file_binary;
 use crate::commands::window_commands::open_new_window;
 use crate::settings::local_settings_store::LocalSettingsStore;
@@ -55,9 +56,33 @@ pub fn run() {
         .invoke_handler(tauri::generate_handler![
             greet, 
             create_work_item,
+            update_work_item,
+            bulk_update_work_items,
+            change_work_item_type,
+            archive_work_item,
+            restore_work_item,
+            purge_work_item,
+            clone_work_item,
+            move_work_item,
+            resolve_work_item_number,
+            get_next_work_item_statuses,
+            get_field_states,
+            get_work_item_history,
+            get_work_item_ancestors,
+            get_work_item_descendants,
+            get_work_item_subtree,
             get_work_item,
             get_work_item_types_by_project,
+            analyze_work_item_type_update,
+            update_work_item_type,
             list_work_items,
+            parse_work_item_query,
+            create_saved_view,
+            update_saved_view,
+            get_saved_view,
+            list_saved_views,
+            delete_saved_view,
+            execute_saved_view,
             get_navigation,
             get_projects,
             create_project,
@@ -71,6 +96,10 @@ pub fn run() {
             get_persons,
             create_attachment,
             get_attachment,
+            move_to_trash,
+            list_trash,
+            restore_from_trash,
+            empty_trash,
             read_file_binary,
             open_new_window])
         .run(tauri::generate_context!())
@@ -0,0 +1,3 @@
+pub mod trash_entry;
+
+pub use trash_entry::TrashEntry;
@@ -0,0 +1,81 @@
+use db::repository_base::Entity;
+use rusqlite::{Row, ToSql};
+use serde::{Deserialize, Serialize};
+use db::to_sql_vec;
+
+/// Entity recording that another entity was soft deleted.
+///
+/// The entity itself stays in its own table, marked deleted the way that table does it
+/// (`deleted_at`, `is_active` or `archived_at`), until it is restored or the trash is emptied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntry {
+    pub id: Option<String>,
+    pub project_id: Option<String>, // None for workspace entities such as persons
+    pub entity_type: String,        // e.g., "work_item", "document", "attachment"
+    pub entity_id: String,
+    pub display_name: String,
+    pub deleted_at: String,
+    pub deleted_by: String,
+}
+
+impl Entity for TrashEntry {
+    fn table_name() -> &'static str {
+        "trash_entries"
+    }
+
+    fn columns() -> &'static [&'static str] {
+        &[
+            "id",
+            "project_id",
+            "entity_type",
+            "entity_id",
+            "display_name",
+            "deleted_at",
+            "deleted_by",
+        ]
+    }
+
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            project_id: row.get(1)?,
+            entity_type: row.get(2)?,
+            entity_id: row.get(3)?,
+            display_name: row.get(4)?,
+            deleted_at: row.get(5)?,
+            deleted_by: row.get(6)?,
+        })
+    }
+
+    fn id(&self) -> Option<String> {
+        self.id.clone()
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = Some(id);
+    }
+
+    fn insert_values(&self) -> Vec<Box<dyn ToSql>> {
+        to_sql_vec![
+            self.id.clone(),
+            self.project_id.clone(),
+            self.entity_type.clone(),
+            self.entity_id.clone(),
+            self.display_name.clone(),
+            self.deleted_at.clone(),
+            self.deleted_by.clone(),
+        ]
+    }
+
+    fn update_values(&self) -> Vec<Box<dyn ToSql>> {
+        to_sql_vec![
+            self.id.clone(), // id (won't actually be updated, but required for SQL generation)
+            self.project_id.clone(),
+            self.entity_type.clone(),
+            self.entity_id.clone(),
+            self.display_name.clone(),
+            self.deleted_at.clone(),
+            self.deleted_by.clone(),
+        ]
+    }
+}
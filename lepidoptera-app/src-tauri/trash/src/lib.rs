@@ -0,0 +1,6 @@
+pub mod entities;
+pub mod models;
+pub mod trash_ports;
+pub mod trash_manager;
+mod trash_sqlite_repository;
+mod trashed_entities_repository;
@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use crate::entities::TrashEntry as TrashEntryEntity;
+
+/// Kinds of entity that can be moved to the trash
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrashEntityType {
+    /// A work item; trashing archives it
+    WorkItem,
+    /// A work item type; trashing marks it inactive
+    WorkItemType,
+    /// A document; trashing marks it inactive
+    Document,
+    /// An attachment; trashing sets `deleted_at`
+    Attachment,
+    /// A person; trashing marks them inactive. Persons belong to the workspace, not a project.
+    Person,
+}
+
+impl TrashEntityType {
+    /// Convert to string representation for database storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrashEntityType::WorkItem => "work_item",
+            TrashEntityType::WorkItemType => "work_item_type",
+            TrashEntityType::Document => "document",
+            TrashEntityType::Attachment => "attachment",
+            TrashEntityType::Person => "person",
+        }
+    }
+
+    /// Parse from string representation
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "work_item" => Some(TrashEntityType::WorkItem),
+            "work_item_type" => Some(TrashEntityType::WorkItemType),
+            "document" => Some(TrashEntityType::Document),
+            "attachment" => Some(TrashEntityType::Attachment),
+            "person" => Some(TrashEntityType::Person),
+            _ => None,
+        }
+    }
+}
+
+/// Domain model for an entity in the trash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEntryModel {
+    pub id: Option<String>,
+    pub project_id: Option<String>,
+    pub entity_type: TrashEntityType,
+    pub entity_id: String,
+    pub display_name: String,
+    pub deleted_at: String,
+    pub deleted_by: String,
+}
+
+impl TrashEntryModel {
+    /// Convert from entity to model
+    pub fn from_entity(entity: TrashEntryEntity) -> anyhow::Result<Self> {
+        let entity_type = TrashEntityType::parse(&entity.entity_type)
+            .ok_or_else(|| anyhow::anyhow!("Invalid trash entity type: {}", entity.entity_type))?;
+
+        Ok(Self {
+            id: entity.id,
+            project_id: entity.project_id,
+            entity_type,
+            entity_id: entity.entity_id,
+            display_name: entity.display_name,
+            deleted_at: entity.deleted_at,
+            deleted_by: entity.deleted_by,
+        })
+    }
+
+    /// Convert from model to entity
+    pub fn to_entity(&self) -> TrashEntryEntity {
+        TrashEntryEntity {
+            id: self.id.clone(),
+            project_id: self.project_id.clone(),
+            entity_type: self.entity_type.as_str().to_string(),
+            entity_id: self.entity_id.clone(),
+            display_name: self.display_name.clone(),
+            deleted_at: self.deleted_at.clone(),
+            deleted_by: self.deleted_by.clone(),
+        }
+    }
+}
+
+/// Outcome of emptying the trash
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmptyTrashResult {
+    /// Entries whose entity was permanently deleted
+    pub purged: Vec<TrashEntryModel>,
+    /// Entries that were kept because their entity could not be deleted
+    pub skipped: Vec<SkippedTrashEntry>,
+}
+
+/// A trash entry that could not be purged, e.g. a work item type still used by work items
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedTrashEntry {
+    pub entry: TrashEntryModel,
+    pub error: String,
+}
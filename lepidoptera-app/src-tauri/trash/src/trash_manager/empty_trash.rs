@@ -0,0 +1,72 @@
+use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
+use work_items::work_items_port::WorkItemsManager;
+use crate::models::{TrashEntityType, TrashEntryModel, EmptyTrashResult, SkippedTrashEntry};
+use crate::trash_sqlite_repository::TrashRepository;
+use crate::trashed_entities_repository::TrashedEntitiesRepository;
+use anyhow::Result;
+use chrono::{Duration, Utc};
+
+/// Permanently delete the entities in a project's trash, or the workspace trash for `None`.
+///
+/// With `retention_days`, only entries deleted longer ago than that are purged.
+pub fn empty_trash(
+    repository: &Arc<dyn TrashRepository>,
+    entities_repository: &Arc<dyn TrashedEntitiesRepository>,
+    work_items: &Arc<dyn WorkItemsManager>,
+    pool: &Arc<ConnectionPool>,
+    project_id: Option<&str>,
+    retention_days: Option<u32>,
+) -> Result<EmptyTrashResult> {
+    let deleted_before = retention_days
+        .map(|days| (Utc::now() - Duration::days(i64::from(days))).to_rfc3339());
+
+    let mut result = EmptyTrashResult {
+        purged: Vec::new(),
+        skipped: Vec::new(),
+    };
+
+    for entity in repository.find_by_project_id(project_id, deleted_before.as_deref())? {
+        let entry = TrashEntryModel::from_entity(entity)?;
+        match purge_entry(repository, entities_repository, work_items, pool, &entry) {
+            Ok(true) => result.purged.push(entry),
+            Ok(false) => {}
+            Err(e) => result.skipped.push(SkippedTrashEntry {
+                entry,
+                error: e.to_string(),
+            }),
+        }
+    }
+
+    Ok(result)
+}
+
+/// Purge one entry's entity and remove the entry. Returns false if the entry was stale,
+/// i.e. its work item had been restored or deleted some other way.
+fn purge_entry(
+    repository: &Arc<dyn TrashRepository>,
+    entities_repository: &Arc<dyn TrashedEntitiesRepository>,
+    work_items: &Arc<dyn WorkItemsManager>,
+    pool: &Arc<ConnectionPool>,
+    entry: &TrashEntryModel,
+) -> Result<bool> {
+    let trash_entry_id = entry.id.as_deref()
+        .ok_or_else(|| anyhow::anyhow!("Trash entry must have an id"))?;
+
+    if entry.entity_type == TrashEntityType::WorkItem {
+        let archived = work_items.get_work_item(&entry.entity_id)?
+            .is_some_and(|work_item| work_item.archived_at.is_some());
+        if archived {
+            work_items.purge_work_item(&entry.entity_id)?;
+        }
+        repository.delete(trash_entry_id, None)?;
+        return Ok(archived);
+    }
+
+    pool.get()?.get_mut().transaction(|conn| {
+        entities_repository.purge(entry.entity_type, &entry.entity_id, Some(conn))?;
+        repository.delete(trash_entry_id, Some(conn))
+    })?;
+
+    Ok(true)
+}
@@ -0,0 +1,15 @@
+use std::sync::Arc;
+use crate::models::TrashEntryModel;
+use crate::trash_sqlite_repository::TrashRepository;
+use anyhow::Result;
+
+/// List a project's trash, or the workspace trash for `None`, newest first
+pub fn list_trash(
+    repository: &Arc<dyn TrashRepository>,
+    project_id: Option<&str>,
+) -> Result<Vec<TrashEntryModel>> {
+    repository.find_by_project_id(project_id, None)?
+        .into_iter()
+        .map(TrashEntryModel::from_entity)
+        .collect()
+}
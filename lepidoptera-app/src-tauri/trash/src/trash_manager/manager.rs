@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
+use work_items::work_items_port::WorkItemsManager;
+use crate::models::{TrashEntityType, TrashEntryModel, EmptyTrashResult};
+use crate::trash_ports::TrashManager;
+use crate::trash_sqlite_repository::{TrashRepository, SqliteTrashRepository};
+use crate::trashed_entities_repository::{TrashedEntitiesRepository, SqliteTrashedEntitiesRepository};
+use crate::trash_manager::{move_to_trash, list_trash, restore_from_trash, empty_trash};
+
+pub struct SqliteTrashManager {
+    repository: Arc<dyn TrashRepository>,
+    entities_repository: Arc<dyn TrashedEntitiesRepository>,
+    work_items: Arc<dyn WorkItemsManager>,
+    pool: Arc<ConnectionPool>,
+}
+
+impl SqliteTrashManager {
+    /// Work items are archived, restored and purged through `work_items`
+    pub fn new(pool: Arc<ConnectionPool>, work_items: Arc<dyn WorkItemsManager>) -> Self {
+        let repository: Arc<dyn TrashRepository> =
+            Arc::new(SqliteTrashRepository::new(pool.clone()));
+        let entities_repository: Arc<dyn TrashedEntitiesRepository> =
+            Arc::new(SqliteTrashedEntitiesRepository::new(pool.clone()));
+        Self {
+            repository,
+            entities_repository,
+            work_items,
+            pool,
+        }
+    }
+}
+
+impl TrashManager for SqliteTrashManager {
+    fn move_to_trash(&self, entity_type: TrashEntityType, entity_id: &str, deleted_by: &str) -> anyhow::Result<TrashEntryModel> {
+        move_to_trash::move_to_trash(
+            &self.repository,
+            &self.entities_repository,
+            &self.work_items,
+            &self.pool,
+            entity_type,
+            entity_id,
+            deleted_by,
+        )
+    }
+
+    fn list_trash(&self, project_id: Option<&str>) -> anyhow::Result<Vec<TrashEntryModel>> {
+        list_trash::list_trash(&self.repository, project_id)
+    }
+
+    fn restore_from_trash(&self, trash_entry_id: &str, restored_by: &str) -> anyhow::Result<TrashEntryModel> {
+        restore_from_trash::restore_from_trash(
+            &self.repository,
+            &self.entities_repository,
+            &self.work_items,
+            &self.pool,
+            trash_entry_id,
+            restored_by,
+        )
+    }
+
+    fn empty_trash(&self, project_id: Option<&str>, retention_days: Option<u32>) -> anyhow::Result<EmptyTrashResult> {
+        empty_trash::empty_trash(
+            &self.repository,
+            &self.entities_repository,
+            &self.work_items,
+            &self.pool,
+            project_id,
+            retention_days,
+        )
+    }
+}
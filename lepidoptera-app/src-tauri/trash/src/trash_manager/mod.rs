@@ -0,0 +1,5 @@
+pub mod manager;
+mod move_to_trash;
+mod list_trash;
+mod restore_from_trash;
+mod empty_trash;
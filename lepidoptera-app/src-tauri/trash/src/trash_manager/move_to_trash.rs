@@ -0,0 +1,67 @@
+use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
+use work_items::models::WorkItemModel;
+use work_items::work_items_port::WorkItemsManager;
+use crate::models::{TrashEntityType, TrashEntryModel};
+use crate::trash_sqlite_repository::TrashRepository;
+use crate::trashed_entities_repository::TrashedEntitiesRepository;
+use anyhow::Result;
+use chrono::Utc;
+use ulid::Ulid;
+
+/// Soft delete an entity and record it in the trash.
+///
+/// Entities that are already soft deleted, e.g. persons deactivated before the trash existed
+/// or archived work items, are only recorded.
+pub fn move_to_trash(
+    repository: &Arc<dyn TrashRepository>,
+    entities_repository: &Arc<dyn TrashedEntitiesRepository>,
+    work_items: &Arc<dyn WorkItemsManager>,
+    pool: &Arc<ConnectionPool>,
+    entity_type: TrashEntityType,
+    entity_id: &str,
+    deleted_by: &str,
+) -> Result<TrashEntryModel> {
+    if repository.find_by_entity(entity_type.as_str(), entity_id, None)?.is_some() {
+        return Err(anyhow::anyhow!("{} {} is already in the trash", entity_type.as_str(), entity_id));
+    }
+
+    let mut entry = TrashEntryModel {
+        id: Some(Ulid::new().to_string()),
+        project_id: None,
+        entity_type,
+        entity_id: entity_id.to_string(),
+        display_name: String::new(),
+        deleted_at: Utc::now().to_rfc3339(),
+        deleted_by: deleted_by.to_string(),
+    };
+
+    pool.get()?.get_mut().transaction(|conn| {
+        if entity_type == TrashEntityType::WorkItem {
+            // Work items are archived through the work items manager, on this unit of work
+            let work_item = work_items.ensure_work_item_archived(conn, entity_id, deleted_by)?;
+            entry.project_id = Some(work_item.project_id.clone());
+            entry.display_name = work_item_display_name(&work_item);
+            repository.create(entry.to_entity(), Some(conn))?;
+            return Ok(entry);
+        }
+
+        let entity = entities_repository.find(entity_type, entity_id, Some(conn))?
+            .ok_or_else(|| anyhow::anyhow!("{} not found: {}", entity_type.as_str(), entity_id))?;
+        if !entity.is_deleted {
+            entities_repository.soft_delete(entity_type, entity_id, deleted_by, &entry.deleted_at, Some(conn))?;
+        }
+
+        entry.project_id = entity.project_id;
+        entry.display_name = entity.display_name;
+        repository.create(entry.to_entity(), Some(conn))?;
+        Ok(entry)
+    })
+}
+
+fn work_item_display_name(work_item: &WorkItemModel) -> String {
+    match &work_item.sequential_number {
+        Some(number) => format!("{} {}", number, work_item.title),
+        None => work_item.title.clone(),
+    }
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
+use work_items::work_items_port::WorkItemsManager;
+use crate::models::{TrashEntityType, TrashEntryModel};
+use crate::trash_sqlite_repository::TrashRepository;
+use crate::trashed_entities_repository::TrashedEntitiesRepository;
+use anyhow::Result;
+use chrono::Utc;
+
+/// Restore a trashed entity and remove its trash entry
+pub fn restore_from_trash(
+    repository: &Arc<dyn TrashRepository>,
+    entities_repository: &Arc<dyn TrashedEntitiesRepository>,
+    work_items: &Arc<dyn WorkItemsManager>,
+    pool: &Arc<ConnectionPool>,
+    trash_entry_id: &str,
+    restored_by: &str,
+) -> Result<TrashEntryModel> {
+    let entry = match repository.find_by_id(trash_entry_id, None)? {
+        Some(entity) => TrashEntryModel::from_entity(entity)?,
+        None => return Err(anyhow::anyhow!("Trash entry not found: {}", trash_entry_id)),
+    };
+
+    let now = Utc::now().to_rfc3339();
+    pool.get()?.get_mut().transaction(|conn| {
+        match entry.entity_type {
+            // The work item may already have been restored from the archive directly
+            TrashEntityType::WorkItem => {
+                work_items.ensure_work_item_restored(conn, &entry.entity_id, restored_by)?;
+            }
+            entity_type => entities_repository.restore(entity_type, &entry.entity_id, restored_by, &now, Some(conn))?,
+        }
+        repository.delete(trash_entry_id, Some(conn))
+    })?;
+
+    Ok(entry)
+}
@@ -0,0 +1,26 @@
+use crate::models::{TrashEntityType, TrashEntryModel, EmptyTrashResult};
+use anyhow::Result;
+
+/// Project setting holding how many days entries stay in a project's trash
+pub const TRASH_RETENTION_SETTING: &str = "TRASH_RETENTION_DAYS";
+
+/// Retention used when a project has no `TRASH_RETENTION_DAYS` setting, and for workspace entries
+pub const DEFAULT_TRASH_RETENTION_DAYS: u32 = 30;
+
+pub trait TrashManager: Send + Sync {
+    /// Soft delete an entity and record it in the trash
+    fn move_to_trash(&self, entity_type: TrashEntityType, entity_id: &str, deleted_by: &str) -> Result<TrashEntryModel>;
+
+    /// List a project's trash, newest first. `None` lists the workspace trash,
+    /// which holds the entities that belong to no project, such as persons.
+    fn list_trash(&self, project_id: Option<&str>) -> Result<Vec<TrashEntryModel>>;
+
+    /// Restore a trashed entity and remove its trash entry
+    fn restore_from_trash(&self, trash_entry_id: &str, restored_by: &str) -> Result<TrashEntryModel>;
+
+    /// Permanently delete the entities in a project's trash (or the workspace trash for `None`).
+    ///
+    /// With `retention_days`, only entries deleted longer ago than that are purged. Each entry
+    /// is purged in its own unit of work; entries that can't be purged are kept and reported.
+    fn empty_trash(&self, project_id: Option<&str>, retention_days: Option<u32>) -> Result<EmptyTrashResult>;
+}
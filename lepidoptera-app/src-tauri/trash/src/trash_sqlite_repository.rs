@@ -0,0 +1,84 @@
+use std::sync::Arc;
+use db::Connection;
+use db::connection_pool::ConnectionPool;
+use db::repository_base::{Entity, GenericRepository};
+use crate::entities::TrashEntry;
+use anyhow::Result;
+use rusqlite::ToSql;
+
+pub trait TrashRepository: Send + Sync {
+    /// Find a trash entry by id
+    fn find_by_id(&self, id: &str, conn: Option<&mut Connection>) -> Result<Option<TrashEntry>>;
+
+    /// Find the trash entry of an entity, if it is in the trash
+    fn find_by_entity(&self, entity_type: &str, entity_id: &str, conn: Option<&mut Connection>) -> Result<Option<TrashEntry>>;
+
+    /// Find the entries of a project, newest first. `None` finds the workspace entries
+    /// that belong to no project. With `deleted_before`, only older entries are returned.
+    fn find_by_project_id(&self, project_id: Option<&str>, deleted_before: Option<&str>) -> Result<Vec<TrashEntry>>;
+
+    /// Create a new trash entry
+    fn create(&self, entry: TrashEntry, conn: Option<&mut Connection>) -> Result<TrashEntry>;
+
+    /// Delete a trash entry, after its entity was restored or purged
+    fn delete(&self, id: &str, conn: Option<&mut Connection>) -> Result<()>;
+}
+
+pub struct SqliteTrashRepository {
+    inner: GenericRepository<TrashEntry>,
+}
+
+impl SqliteTrashRepository {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Self {
+            inner: GenericRepository::new(pool),
+        }
+    }
+}
+
+impl TrashRepository for SqliteTrashRepository {
+    fn find_by_id(&self, id: &str, conn: Option<&mut Connection>) -> Result<Option<TrashEntry>> {
+        self.inner.find_by_id(id, conn)
+    }
+
+    fn find_by_entity(&self, entity_type: &str, entity_id: &str, conn: Option<&mut Connection>) -> Result<Option<TrashEntry>> {
+        self.inner.with_optional_connection(conn, |conn| {
+            let params: &[&dyn ToSql] = &[&entity_type, &entity_id];
+            let mut results = conn.query(
+                "SELECT id, project_id, entity_type, entity_id, display_name, deleted_at, deleted_by
+                 FROM trash_entries
+                 WHERE entity_type = ?1 AND entity_id = ?2",
+                params,
+                TrashEntry::from_row,
+            )?;
+
+            Ok(results.pop())
+        })
+    }
+
+    fn find_by_project_id(&self, project_id: Option<&str>, deleted_before: Option<&str>) -> Result<Vec<TrashEntry>> {
+        self.inner.with_connection(|conn| {
+            let params: &[&dyn ToSql] = &[&project_id, &deleted_before];
+            let results = conn.query(
+                "SELECT id, project_id, entity_type, entity_id, display_name, deleted_at, deleted_by
+                 FROM trash_entries
+                 WHERE project_id IS ?1
+                   AND (?2 IS NULL OR deleted_at < ?2)
+                 ORDER BY deleted_at DESC",
+                params,
+                TrashEntry::from_row,
+            )?;
+
+            Ok(results)
+        })
+    }
+
+    fn create(&self, entry: TrashEntry, conn: Option<&mut Connection>) -> Result<TrashEntry> {
+        self.inner.create(entry, conn)
+    }
+
+    fn delete(&self, id: &str, conn: Option<&mut Connection>) -> Result<()> {
+        self.inner.delete(id, conn)?;
+        Ok(())
+    }
+}
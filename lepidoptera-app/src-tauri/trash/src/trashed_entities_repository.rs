@@ -0,0 +1,191 @@
+use std::sync::Arc;
+use db::{to_sql_vec, Connection};
+use db::connection_pool::ConnectionPool;
+use db::repository_base::GenericRepository;
+use crate::entities::TrashEntry;
+use crate::models::TrashEntityType;
+use anyhow::Result;
+use rusqlite::ToSql;
+
+/// What the trash needs to know about an entity that can be trashed
+#[derive(Debug, Clone)]
+pub struct TrashedEntity {
+    pub project_id: Option<String>,
+    pub display_name: String,
+    pub is_deleted: bool,
+}
+
+/// Soft deletes, restores and purges entities in their own tables, each the way that
+/// table marks deleted rows.
+///
+/// Work items are not handled here: they are archived and purged through the work items
+/// manager, which owns their history and cascading cleanup.
+pub trait TrashedEntitiesRepository: Send + Sync {
+    /// Find an entity, whether or not it is deleted
+    fn find(&self, entity_type: TrashEntityType, entity_id: &str, conn: Option<&mut Connection>) -> Result<Option<TrashedEntity>>;
+
+    /// Mark an entity as deleted
+    fn soft_delete(&self, entity_type: TrashEntityType, entity_id: &str, deleted_by: &str, deleted_at: &str, conn: Option<&mut Connection>) -> Result<()>;
+
+    /// Clear an entity's deleted mark
+    fn restore(&self, entity_type: TrashEntityType, entity_id: &str, restored_by: &str, restored_at: &str, conn: Option<&mut Connection>) -> Result<()>;
+
+    /// Permanently delete an entity and the rows that only exist for it.
+    /// Fails if other data still references it.
+    fn purge(&self, entity_type: TrashEntityType, entity_id: &str, conn: Option<&mut Connection>) -> Result<()>;
+}
+
+pub struct SqliteTrashedEntitiesRepository {
+    inner: GenericRepository<TrashEntry>,
+}
+
+impl SqliteTrashedEntitiesRepository {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Self {
+            inner: GenericRepository::new(pool),
+        }
+    }
+}
+
+impl TrashedEntitiesRepository for SqliteTrashedEntitiesRepository {
+    fn find(&self, entity_type: TrashEntityType, entity_id: &str, conn: Option<&mut Connection>) -> Result<Option<TrashedEntity>> {
+        let sql = match entity_type {
+            TrashEntityType::WorkItem => return Err(work_items_not_handled()),
+            TrashEntityType::WorkItemType => "SELECT project_id, display_name, is_active = 0 FROM work_item_types WHERE id = ?1",
+            TrashEntityType::Document =>
+                "SELECT d.project_id,
+                        COALESCE((SELECT v.title FROM document_versions v WHERE v.document_id = d.id ORDER BY v.version DESC LIMIT 1), d.slug),
+                        d.is_active = 0
+                 FROM documents d WHERE d.id = ?1",
+            TrashEntityType::Attachment => "SELECT project_id, file_name, deleted_at IS NOT NULL FROM attachments WHERE id = ?1",
+            TrashEntityType::Person => "SELECT NULL, display_name, is_active = 0 FROM persons WHERE id = ?1",
+        };
+
+        self.inner.with_optional_connection(conn, |conn| {
+            let params: &[&dyn ToSql] = &[&entity_id];
+            let mut results = conn.query(sql, params, |row| {
+                Ok(TrashedEntity {
+                    project_id: row.get(0)?,
+                    display_name: row.get(1)?,
+                    is_deleted: row.get(2)?,
+                })
+            })?;
+
+            Ok(results.pop())
+        })
+    }
+
+    fn soft_delete(&self, entity_type: TrashEntityType, entity_id: &str, deleted_by: &str, deleted_at: &str, conn: Option<&mut Connection>) -> Result<()> {
+        let (sql, values) = match entity_type {
+            TrashEntityType::WorkItem => return Err(work_items_not_handled()),
+            TrashEntityType::WorkItemType => (
+                "UPDATE work_item_types SET is_active = 0, updated_at = ?1 WHERE id = ?2",
+                to_sql_vec![deleted_at.to_string(), entity_id.to_string()],
+            ),
+            TrashEntityType::Document => (
+                "UPDATE documents SET is_active = 0, updated_at = ?1, updated_by = ?2 WHERE id = ?3",
+                to_sql_vec![deleted_at.to_string(), deleted_by.to_string(), entity_id.to_string()],
+            ),
+            TrashEntityType::Attachment => (
+                "UPDATE attachments SET deleted_at = ?1, deleted_by = ?2 WHERE id = ?3",
+                to_sql_vec![deleted_at.to_string(), deleted_by.to_string(), entity_id.to_string()],
+            ),
+            TrashEntityType::Person => (
+                "UPDATE persons SET is_active = 0, updated_at = ?1 WHERE id = ?2",
+                to_sql_vec![deleted_at.to_string(), entity_id.to_string()],
+            ),
+        };
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(sql, rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())))?;
+            Ok(())
+        })
+    }
+
+    fn restore(&self, entity_type: TrashEntityType, entity_id: &str, restored_by: &str, restored_at: &str, conn: Option<&mut Connection>) -> Result<()> {
+        let (sql, values) = match entity_type {
+            TrashEntityType::WorkItem => return Err(work_items_not_handled()),
+            TrashEntityType::WorkItemType => (
+                "UPDATE work_item_types SET is_active = 1, updated_at = ?1 WHERE id = ?2",
+                to_sql_vec![restored_at.to_string(), entity_id.to_string()],
+            ),
+            TrashEntityType::Document => (
+                "UPDATE documents SET is_active = 1, updated_at = ?1, updated_by = ?2 WHERE id = ?3",
+                to_sql_vec![restored_at.to_string(), restored_by.to_string(), entity_id.to_string()],
+            ),
+            TrashEntityType::Attachment => (
+                "UPDATE attachments SET deleted_at = NULL, deleted_by = NULL, updated_at = ?1, updated_by = ?2 WHERE id = ?3",
+                to_sql_vec![restored_at.to_string(), restored_by.to_string(), entity_id.to_string()],
+            ),
+            TrashEntityType::Person => (
+                "UPDATE persons SET is_active = 1, updated_at = ?1 WHERE id = ?2",
+                to_sql_vec![restored_at.to_string(), entity_id.to_string()],
+            ),
+        };
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(sql, rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())))?;
+            Ok(())
+        })
+    }
+
+    fn purge(&self, entity_type: TrashEntityType, entity_id: &str, conn: Option<&mut Connection>) -> Result<()> {
+        let statements: &[&str] = match entity_type {
+            TrashEntityType::WorkItem => return Err(work_items_not_handled()),
+            TrashEntityType::WorkItemType => &["DELETE FROM work_item_types WHERE id = ?1"],
+            TrashEntityType::Document => &[
+                // Child documents move up to the purged document's parent
+                "UPDATE documents SET parent_id = (SELECT parent_id FROM documents WHERE id = ?1) WHERE parent_id = ?1",
+                "DELETE FROM document_versions WHERE document_id = ?1",
+                "DELETE FROM tagged_items WHERE reference_type = 'document' AND reference_id = ?1",
+                "DELETE FROM read_records WHERE conversation_id IN
+                     (SELECT id FROM conversations WHERE conversation_scope = 'document' AND conversation_scope_id = ?1)",
+                "DELETE FROM messages WHERE conversation_id IN
+                     (SELECT id FROM conversations WHERE conversation_scope = 'document' AND conversation_scope_id = ?1)",
+                "DELETE FROM conversations WHERE conversation_scope = 'document' AND conversation_scope_id = ?1",
+                "DELETE FROM documents WHERE id = ?1",
+            ],
+            TrashEntityType::Attachment => &["DELETE FROM attachments WHERE id = ?1"],
+            TrashEntityType::Person => &["DELETE FROM persons WHERE id = ?1"],
+        };
+
+        self.inner.with_optional_connection(conn, |conn| {
+            ensure_unreferenced(conn, entity_type, entity_id)?;
+
+            for sql in statements {
+                let values = to_sql_vec![entity_id.to_string()];
+                conn.execute(sql, rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())))?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Refuse to purge work item types that work items still use, and persons that are still
+/// team members, own saved views or have read conversations
+fn ensure_unreferenced(conn: &mut Connection, entity_type: TrashEntityType, entity_id: &str) -> Result<()> {
+    let sql = match entity_type {
+        TrashEntityType::WorkItemType => "SELECT COUNT(*) FROM work_items WHERE type_id = ?1",
+        TrashEntityType::Person =>
+            "SELECT (SELECT COUNT(*) FROM team_members WHERE person_id = ?1)
+                  + (SELECT COUNT(*) FROM saved_views WHERE person_id = ?1)
+                  + (SELECT COUNT(*) FROM read_records WHERE person_id = ?1)",
+        _ => return Ok(()),
+    };
+
+    let params: &[&dyn ToSql] = &[&entity_id];
+    let count = conn.query(sql, params, |row| row.get::<_, i64>(0))?.pop().unwrap_or(0);
+    match (entity_type, count) {
+        (_, 0) => Ok(()),
+        (TrashEntityType::WorkItemType, count) => Err(anyhow::anyhow!(
+            "Work item type is still used by {} work item(s)", count
+        )),
+        (_, count) => Err(anyhow::anyhow!(
+            "Person is still referenced by {} team membership(s), saved view(s) or read record(s)", count
+        )),
+    }
+}
+
+fn work_items_not_handled() -> anyhow::Error {
+    anyhow::anyhow!("Work items are trashed through the work items manager")
+}
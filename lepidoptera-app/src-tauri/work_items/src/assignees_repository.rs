@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
+use db::ToSql;
+use anyhow::Result;
+
+/// Looks up the people and teams that work items can be assigned to
+pub trait AssigneesRepository: Send + Sync {
+    /// Find persons by id. Returns whether each person that exists is active; unknown ids are absent.
+    fn find_persons(&self, ids: &[String]) -> Result<HashMap<String, bool>>;
+
+    /// Find teams by id. Returns whether each team that exists is active; unknown ids are absent.
+    fn find_teams(&self, ids: &[String]) -> Result<HashMap<String, bool>>;
+}
+
+pub struct SqliteAssigneesRepository {
+    pool: Arc<ConnectionPool>,
+}
+
+impl SqliteAssigneesRepository {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Self { pool }
+    }
+
+    fn find_active_flags(&self, table: &str, ids: &[String]) -> Result<HashMap<String, bool>> {
+        if ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let pooled_conn = self.pool.get()?;
+        let conn = pooled_conn.get();
+
+        let placeholders: Vec<String> = (1..=ids.len()).map(|i| format!("?{}", i)).collect();
+        let params: Vec<&dyn ToSql> = ids.iter().map(|id| id as &dyn ToSql).collect();
+        let rows = conn.query(
+            &format!("SELECT id, is_active FROM {} WHERE id IN ({})", table, placeholders.join(", ")),
+            &params,
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, bool>(1)?)),
+        )?;
+
+        Ok(rows.into_iter().collect())
+    }
+}
+
+impl AssigneesRepository for SqliteAssigneesRepository {
+    fn find_persons(&self, ids: &[String]) -> Result<HashMap<String, bool>> {
+        self.find_active_flags("persons", ids)
+    }
+
+    fn find_teams(&self, ids: &[String]) -> Result<HashMap<String, bool>> {
+        self.find_active_flags("teams", ids)
+    }
+}
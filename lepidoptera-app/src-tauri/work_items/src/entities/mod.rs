@@ -2,11 +2,17 @@ pub mod work_item;
 pub mod work_item_type;
 pub mod work_item_field_value;
 pub mod work_item_number_range;
+pub mod work_item_number_alias;
 pub mod work_item_relationship;
+pub mod work_item_history_entry;
+pub mod saved_view;
 
 pub use work_item::WorkItem;
 pub use work_item_type::WorkItemType;
 pub use work_item_field_value::WorkItemFieldValue;
 pub use work_item_number_range::WorkItemNumberRange;
+pub use work_item_number_alias::WorkItemNumberAlias;
 pub use work_item_relationship::WorkItemRelationship;
+pub use work_item_history_entry::WorkItemHistoryEntry;
+pub use saved_view::SavedView;
 
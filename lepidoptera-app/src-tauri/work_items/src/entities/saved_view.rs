@@ -0,0 +1,100 @@
+use db::repository_base::Entity;
+use rusqlite::{Row, ToSql};
+use serde::{Deserialize, Serialize};
+use db::to_sql_vec;
+
+/// Entity representing a named work item query saved for a project.
+///
+/// Views without a `person_id` are shared with everyone on the project,
+/// otherwise they belong to that person only.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedView {
+    pub id: Option<String>,
+    pub project_id: String,
+    pub person_id: Option<String>,
+    pub name: String,
+    pub query: String, // JSON field
+    pub include_fields: Option<String>, // JSON field
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub created_by: String,
+    pub updated_by: Option<String>,
+    pub is_active: bool,
+}
+
+impl Entity for SavedView {
+    fn table_name() -> &'static str {
+        "saved_views"
+    }
+
+    fn columns() -> &'static [&'static str] {
+        &[
+            "id",
+            "project_id",
+            "person_id",
+            "name",
+            "query",
+            "include_fields",
+            "created_at",
+            "updated_at",
+            "created_by",
+            "updated_by",
+            "is_active",
+        ]
+    }
+
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            project_id: row.get(1)?,
+            person_id: row.get(2)?,
+            name: row.get(3)?,
+            query: row.get(4)?,
+            include_fields: row.get(5)?,
+            created_at: row.get(6)?,
+            updated_at: row.get(7)?,
+            created_by: row.get(8)?,
+            updated_by: row.get(9)?,
+            is_active: row.get(10)?,
+        })
+    }
+
+    fn id(&self) -> Option<String> {
+        self.id.clone()
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = Some(id);
+    }
+
+    fn insert_values(&self) -> Vec<Box<dyn ToSql>> {
+        to_sql_vec![
+            self.id.clone(),
+            self.project_id.clone(),
+            self.person_id.clone(),
+            self.name.clone(),
+            self.query.clone(),
+            self.include_fields.clone(),
+            self.created_at.clone(),
+            self.updated_at.clone(),
+            self.created_by.clone(),
+            self.updated_by.clone(),
+            self.is_active,
+        ]
+    }
+
+    fn update_values(&self) -> Vec<Box<dyn ToSql>> {
+        to_sql_vec![
+            self.project_id.clone(),
+            self.person_id.clone(),
+            self.name.clone(),
+            self.query.clone(),
+            self.include_fields.clone(),
+            self.created_at.clone(),
+            self.updated_at.clone(),
+            self.created_by.clone(),
+            self.updated_by.clone(),
+            self.is_active,
+        ]
+    }
+}
@@ -17,6 +17,9 @@ pub struct WorkItem {
     pub project_id: String,
     pub type_id: String, // Foreign key to WorkItemType
     pub sequential_number: Option<String>, // Sequential number for display (e.g., M-0003, M-1045, etc.)
+    pub updated_by: Option<String>, // User who last updated this work item
+    pub archived_at: Option<String>, // Set while the work item is archived
+    pub archived_by: Option<String>,
 }
 
 impl Entity for WorkItem {
@@ -38,6 +41,9 @@ impl Entity for WorkItem {
             "project_id",
             "type_id",
             "sequential_number",
+            "updated_by",
+            "archived_at",
+            "archived_by",
         ]
     }
 
@@ -55,6 +61,9 @@ impl Entity for WorkItem {
             project_id: row.get(9)?,
             type_id: row.get(10)?,
             sequential_number: row.get(11)?,
+            updated_by: row.get(12)?, // nullable column
+            archived_at: row.get(13)?,
+            archived_by: row.get(14)?,
         })
     }
 
@@ -80,6 +89,9 @@ impl Entity for WorkItem {
             self.project_id.clone(),
             self.type_id.clone(),
             self.sequential_number.clone(),
+            self.updated_by.clone(),
+            self.archived_at.clone(),
+            self.archived_by.clone(),
         ]
     }
 
@@ -95,8 +107,11 @@ impl Entity for WorkItem {
             self.created_by.clone(), // created_by (won't actually be updated, but required for SQL generation)
             self.assigned_to.clone(),
             self.project_id.clone(), // project_id (won't actually be updated, but required for SQL generation)
-            self.type_id.clone(), // type_id (won't actually be updated, but required for SQL generation)
+            self.type_id.clone(),
             self.sequential_number.clone(),
+            self.updated_by.clone(),
+            self.archived_at.clone(),
+            self.archived_by.clone(),
         ]
     }
 
@@ -21,6 +21,8 @@ pub struct WorkItemFieldValue {
     pub created_by: String, // User who created this field value
     pub updated_by: Option<String>, // User who last updated this field value
     pub is_active: bool,
+    pub value_number: Option<f64>, // Numeric projection of the value for number fields
+    pub value_date: Option<String>, // UTC `YYYY-MM-DDTHH:MM:SSZ` projection of the value for date fields
 }
 
 impl Entity for WorkItemFieldValue {
@@ -31,6 +33,7 @@ impl Entity for WorkItemFieldValue {
     fn columns() -> &'static [&'static str] {
         // Columns that are inserted (must match insert_values length)
         &[
+            "id",
             "project_id",
             "work_item_id",
             "field_id",
@@ -39,6 +42,8 @@ impl Entity for WorkItemFieldValue {
             "created_at",
             "created_by",
             "is_active",
+            "value_number",
+            "value_date",
         ]
     }
 
@@ -56,6 +61,8 @@ impl Entity for WorkItemFieldValue {
             created_by: row.get(8)?,
             updated_by: row.get(9)?, // nullable column
             is_active: row.get(10)?,
+            value_number: row.get(11)?, // nullable column
+            value_date: row.get(12)?, // nullable column
         })
     }
 
@@ -69,6 +76,7 @@ impl Entity for WorkItemFieldValue {
 
     fn insert_values(&self) -> Vec<Box<dyn ToSql>> {
         to_sql_vec![
+            self.id.clone(),
             self.project_id.clone(),
             self.work_item_id.clone(),
             self.field_id.clone(),
@@ -77,11 +85,14 @@ impl Entity for WorkItemFieldValue {
             self.created_at.clone(),
             self.created_by.clone(),
             self.is_active,
+            self.value_number,
+            self.value_date.clone(),
         ]
     }
 
     fn update_values(&self) -> Vec<Box<dyn ToSql>> {
         to_sql_vec![
+            self.id.clone(), // id (won't actually be updated, but required for SQL generation)
             self.project_id.clone(),
             self.work_item_id.clone(),
             self.field_id.clone(),
@@ -90,6 +101,8 @@ impl Entity for WorkItemFieldValue {
             self.updated_at.clone(),
             self.updated_by.clone(),
             self.is_active,
+            self.value_number,
+            self.value_date.clone(),
         ]
     }
 
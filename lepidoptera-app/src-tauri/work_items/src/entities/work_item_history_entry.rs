@@ -0,0 +1,95 @@
+use db::repository_base::Entity;
+use rusqlite::{Row, ToSql};
+use serde::{Deserialize, Serialize};
+use db::to_sql_vec;
+
+/// Entity representing a single recorded change to a work item.
+/// 
+/// History entries are append-only. Each entry records one change:
+/// - "created" - the work item was created
+/// - "updated" - a core column changed (field = column name, e.g. "status")
+/// - "field_value" / "assignment_field_value" - a custom field value changed (field = field_id)
+/// - "relationship_added" / "relationship_removed" - a relationship changed
+///   (field = relationship type, value = the other work item's id)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemHistoryEntry {
+    pub id: Option<String>,
+    pub project_id: String,
+    pub work_item_id: String, // Foreign key to work_item
+    pub change_type: String,
+    pub field: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub changed_by: String, // User who made the change
+    pub changed_at: String,
+}
+
+impl Entity for WorkItemHistoryEntry {
+    fn table_name() -> &'static str {
+        "work_item_history"
+    }
+
+    fn columns() -> &'static [&'static str] {
+        &[
+            "id",
+            "project_id",
+            "work_item_id",
+            "change_type",
+            "field",
+            "old_value",
+            "new_value",
+            "changed_by",
+            "changed_at",
+        ]
+    }
+
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            project_id: row.get(1)?,
+            work_item_id: row.get(2)?,
+            change_type: row.get(3)?,
+            field: row.get(4)?,
+            old_value: row.get(5)?,
+            new_value: row.get(6)?,
+            changed_by: row.get(7)?,
+            changed_at: row.get(8)?,
+        })
+    }
+
+    fn id(&self) -> Option<String> {
+        self.id.clone()
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = Some(id);
+    }
+
+    fn insert_values(&self) -> Vec<Box<dyn ToSql>> {
+        to_sql_vec![
+            self.id.clone(),
+            self.project_id.clone(),
+            self.work_item_id.clone(),
+            self.change_type.clone(),
+            self.field.clone(),
+            self.old_value.clone(),
+            self.new_value.clone(),
+            self.changed_by.clone(),
+            self.changed_at.clone(),
+        ]
+    }
+
+    fn update_values(&self) -> Vec<Box<dyn ToSql>> {
+        // History entries are never updated
+        to_sql_vec![
+            self.project_id.clone(),
+            self.work_item_id.clone(),
+            self.change_type.clone(),
+            self.field.clone(),
+            self.old_value.clone(),
+            self.new_value.clone(),
+            self.changed_by.clone(),
+            self.changed_at.clone(),
+        ]
+    }
+}
@@ -0,0 +1,76 @@
+use db::repository_base::Entity;
+use rusqlite::{Row, ToSql};
+use serde::{Deserialize, Serialize};
+use db::to_sql_vec;
+
+/// Entity representing a sequential number a work item had before it moved to
+/// another project.
+///
+/// Aliases are never updated; they are deleted together with their work item.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemNumberAlias {
+    pub id: Option<String>,
+    pub work_item_id: String, // Foreign key to work_items
+    pub project_id: String,   // Project that issued the number
+    pub sequential_number: String,
+    pub created_at: String,
+    pub created_by: String,
+}
+
+impl Entity for WorkItemNumberAlias {
+    fn table_name() -> &'static str {
+        "work_item_number_aliases"
+    }
+
+    fn columns() -> &'static [&'static str] {
+        &[
+            "id",
+            "work_item_id",
+            "project_id",
+            "sequential_number",
+            "created_at",
+            "created_by",
+        ]
+    }
+
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Self {
+            id: row.get(0)?,
+            work_item_id: row.get(1)?,
+            project_id: row.get(2)?,
+            sequential_number: row.get(3)?,
+            created_at: row.get(4)?,
+            created_by: row.get(5)?,
+        })
+    }
+
+    fn id(&self) -> Option<String> {
+        self.id.clone()
+    }
+
+    fn set_id(&mut self, id: String) {
+        self.id = Some(id);
+    }
+
+    fn insert_values(&self) -> Vec<Box<dyn ToSql>> {
+        to_sql_vec![
+            self.id.clone().unwrap_or_default(),
+            self.work_item_id.clone(),
+            self.project_id.clone(),
+            self.sequential_number.clone(),
+            self.created_at.clone(),
+            self.created_by.clone(),
+        ]
+    }
+
+    fn update_values(&self) -> Vec<Box<dyn ToSql>> {
+        to_sql_vec![
+            self.id.clone().unwrap_or_default(),
+            self.work_item_id.clone(),
+            self.project_id.clone(),
+            self.sequential_number.clone(),
+            self.created_at.clone(),
+            self.created_by.clone(),
+        ]
+    }
+}
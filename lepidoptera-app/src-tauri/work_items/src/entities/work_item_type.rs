@@ -9,6 +9,7 @@ use crate::schemas::{
     AssignmentFieldDefinitions,
     WorkItemDetails,
     WorkItemFields,
+    WorkflowDefinition,
 };
 use anyhow::Result;
 use serde_json;
@@ -28,6 +29,7 @@ pub struct WorkItemType {
     pub assignment_field_definitions: String, // JSON field
     pub work_item_details: String, // JSON field
     pub work_item_fields: String, // JSON field
+    pub workflow: Option<String>, // JSON field
 }
 
 impl Entity for WorkItemType {
@@ -50,6 +52,7 @@ impl Entity for WorkItemType {
             "work_item_fields",
             "name",
             "display_name",
+            "workflow",
         ]
     }
 
@@ -68,6 +71,7 @@ impl Entity for WorkItemType {
             work_item_fields: row.get(10)?,
             name: row.get(11)?,
             display_name: row.get(12)?,
+            workflow: row.get(13)?,
         })
     }
 
@@ -94,6 +98,7 @@ impl Entity for WorkItemType {
             self.work_item_fields.clone(),
             self.name.clone(),
             self.display_name.clone(),
+            self.workflow.clone(),
         ]
     }
 
@@ -110,6 +115,7 @@ impl Entity for WorkItemType {
             self.work_item_fields.clone(),
             self.name.clone(),
             self.display_name.clone(),
+            self.workflow.clone(),
         ]
     }
 
@@ -151,5 +157,13 @@ impl WorkItemType {
         serde_json::from_str(&self.work_item_fields)
             .map_err(|e| anyhow::anyhow!("Failed to parse work_item_fields: {}", e))
     }
+
+    /// Parse workflow JSON string into typed struct
+    pub fn parse_workflow(&self) -> Result<Option<WorkflowDefinition>> {
+        self.workflow.as_deref()
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| anyhow::anyhow!("Failed to parse workflow: {}", e))
+    }
 }
 
@@ -1,4 +1,5 @@
 use thiserror::Error;
+use crate::models::RelationshipGraph;
 
 /// Custom error type for field validation failures
 #[derive(Debug, Error)]
@@ -21,3 +22,64 @@ impl FieldValidationError {
     }
 }
 
+
+/// Error type for work item hierarchy violations
+#[derive(Debug, Error)]
+pub enum HierarchyError {
+    #[error("Work item type '{parent_type_name}' does not allow children of type '{child_type_name}'")]
+    ChildTypeNotAllowed {
+        parent_type_id: String,
+        parent_type_name: String,
+        child_type_id: String,
+        child_type_name: String,
+    },
+    #[error("Work item type '{type_name}' lists unknown child type '{child_type_name}'")]
+    UnknownChildType {
+        type_name: String,
+        child_type_name: String,
+    },
+}
+
+/// Error returned when a relationship would close a cycle
+#[derive(Debug, Error)]
+#[error("Relationship would create a cycle in the {} graph: {}", graph.as_str(), path.join(" -> "))]
+pub struct RelationshipCycleError {
+    pub graph: RelationshipGraph,
+    /// Work items along the cycle, starting and ending with the same id
+    pub path: Vec<String>,
+}
+
+/// Error returned when a text query can't be parsed or compiled.
+///
+/// `position` and `length` are character offsets into the query text, so the
+/// offending part can be highlighted.
+#[derive(Debug, Error)]
+#[error("{message} at column {}", .position + 1)]
+pub struct QueryParseError {
+    pub message: String,
+    pub position: usize,
+    pub length: usize,
+}
+
+impl QueryParseError {
+    pub fn new(message: String, position: usize, length: usize) -> Self {
+        Self {
+            message,
+            position,
+            length,
+        }
+    }
+}
+
+/// Error returned when a formula field's expression can't be parsed or type-checked.
+///
+/// `position` and `length` are character offsets into the formula, as for `QueryParseError`.
+#[derive(Debug, Error)]
+#[error("Formula of field '{field_label}': {message} at column {}", .position + 1)]
+pub struct FormulaError {
+    pub field_id: String,
+    pub field_label: String,
+    pub message: String,
+    pub position: usize,
+    pub length: usize,
+}
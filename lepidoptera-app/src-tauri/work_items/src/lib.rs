@@ -4,11 +4,19 @@ mod work_items_sqlite_repository;
 mod work_item_types_repository;
 mod work_item_types_sqlite_repository;
 mod work_item_number_ranges_repository;
+mod work_item_number_aliases_repository;
 mod work_item_relationships_repository;
 mod work_item_relationships_sqlite_repository;
+mod work_item_field_values_repository;
+mod work_item_history_repository;
+mod saved_views_repository;
+mod assignees_repository;
 pub mod work_items_port;
 pub mod work_items_manager;
 pub mod schemas;
 pub mod models;
 pub mod errors;
 
+
+#[cfg(test)]
+mod test_support;
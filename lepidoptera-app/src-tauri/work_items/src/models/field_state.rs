@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Whether a custom field is shown and required for a work item in its current state,
+/// after applying the field's `visible_when` and `required_when` conditions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldState {
+    pub field_id: String,
+    pub visible: bool,
+    pub required: bool,
+}
@@ -10,13 +10,32 @@ pub mod work_item;
 pub mod work_item_field_value;
 pub mod work_item_query;
 pub mod work_item_relationship;
+pub mod work_item_history_entry;
+pub mod work_item_hierarchy;
+pub mod saved_view;
+pub mod work_item_bulk_update;
+pub mod work_item_type_change;
+pub mod work_item_type_migration;
+pub mod work_item_clone;
+pub mod work_item_move;
+pub mod field_state;
 
 pub use work_item_type::{WorkItemTypeModel, WorkItemTypeTemplate};
 pub use work_item::WorkItemModel;
 pub use work_item_field_value::{WorkItemFieldValueModel, FieldDefinition};
 pub use work_item_query::{
     WorkItemQuery, WorkItemListRequest, WorkItemListItem, WorkItemListResponse,
-    FieldValueQuery, SortField, SortDirection,
+    FieldValueQuery, FieldValueOperator, SortField, SortDirection, StatusCategoryCounts,
 };
-pub use work_item_relationship::{WorkItemRelationshipModel, RelationshipType};
-
+pub use work_item_relationship::{WorkItemRelationshipModel, RelationshipType, RelationshipGraph, RelationshipCycle};
+pub use work_item_history_entry::{WorkItemHistoryEntryModel, WorkItemChangeType};
+pub use work_item_hierarchy::{WorkItemHierarchyEntry, WorkItemTreeNode};
+pub use saved_view::SavedViewModel;
+pub use work_item_bulk_update::{
+    WorkItemSelection, WorkItemPatch, FieldValuePatch, BulkItemStatus, BulkItemResult, BulkUpdateResult,
+};
+pub use work_item_type_change::WorkItemTypeChange;
+pub use work_item_type_migration::{WorkItemTypeImpact, WorkItemTypeMigration, RemovedDefinitionUsage};
+pub use work_item_clone::{WorkItemCloneOptions, ClonedWorkItem, WorkItemCloneResult};
+pub use work_item_move::{WorkItemMove, MovedWorkItem, WorkItemMoveResult};
+pub use field_state::FieldState;
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use crate::entities::SavedView as SavedViewEntity;
+use crate::models::WorkItemQuery;
+use anyhow::{Result, Context};
+
+/// Domain model for a saved view: a named work item query and its column selection
+///
+/// A view with no `person_id` is shared with the whole project; otherwise it is
+/// only listed for that person.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SavedViewModel {
+    pub id: Option<String>,
+    pub project_id: String,
+    pub person_id: Option<String>,
+    pub name: String,
+    // Hydrated JSON fields
+    pub query: WorkItemQuery,
+    /// Field IDs shown as columns, passed to `WorkItemListRequest::include_fields`
+    pub include_fields: Option<Vec<String>>,
+    pub created_at: String,
+    pub updated_at: Option<String>,
+    pub created_by: String,
+    pub updated_by: Option<String>,
+    pub is_active: bool,
+}
+
+impl SavedViewModel {
+    /// Whether the view is shared with everyone on the project
+    pub fn is_shared(&self) -> bool {
+        self.person_id.is_none()
+    }
+
+    /// Convert from entity (storage format) to model (domain format)
+    pub fn from_entity(entity: SavedViewEntity) -> Result<Self> {
+        Ok(Self {
+            id: entity.id,
+            project_id: entity.project_id,
+            person_id: entity.person_id,
+            name: entity.name,
+            query: serde_json::from_str(&entity.query)
+                .context("Failed to parse saved view query")?,
+            include_fields: entity.include_fields.as_deref()
+                .map(serde_json::from_str)
+                .transpose()
+                .context("Failed to parse saved view include_fields")?,
+            created_at: entity.created_at,
+            updated_at: entity.updated_at,
+            created_by: entity.created_by,
+            updated_by: entity.updated_by,
+            is_active: entity.is_active,
+        })
+    }
+
+    /// Convert from model (domain format) to entity (storage format)
+    pub fn to_entity(&self) -> Result<SavedViewEntity> {
+        Ok(SavedViewEntity {
+            id: self.id.clone(),
+            project_id: self.project_id.clone(),
+            person_id: self.person_id.clone(),
+            name: self.name.clone(),
+            query: serde_json::to_string(&self.query)
+                .context("Failed to serialize saved view query")?,
+            include_fields: self.include_fields.as_ref()
+                .map(serde_json::to_string)
+                .transpose()
+                .context("Failed to serialize saved view include_fields")?,
+            created_at: self.created_at.clone(),
+            updated_at: self.updated_at.clone(),
+            created_by: self.created_by.clone(),
+            updated_by: self.updated_by.clone(),
+            is_active: self.is_active,
+        })
+    }
+}
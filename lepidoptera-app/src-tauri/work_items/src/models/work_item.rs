@@ -11,9 +11,13 @@ pub struct WorkItemModel {
     pub id: Option<String>,
     pub title: String,
     pub description: Option<String>,
+    /// Empty on create to start in the type's initial status
+    #[serde(default)]
     pub status: String,
     pub created_at: String,
     pub updated_at: Option<String>,
+    /// 0 on create to use the type's default priority
+    #[serde(default)]
     pub priority: i32,
     pub created_by: String,
     pub assigned_to: Option<String>,
@@ -21,6 +25,14 @@ pub struct WorkItemModel {
     pub type_id: String,
     /// Sequential number for display (e.g., M-0003, M-1045, etc.)
     pub sequential_number: Option<String>,
+    /// User who last updated this work item
+    pub updated_by: Option<String>,
+    /// When the work item was archived; archived work items are hidden from lists
+    #[serde(default)]
+    pub archived_at: Option<String>,
+    /// User who archived the work item
+    #[serde(default)]
+    pub archived_by: Option<String>,
     /// Custom field values for this work item
     pub field_values: Vec<WorkItemFieldValueModel>,
 }
@@ -41,6 +53,9 @@ impl WorkItemModel {
             project_id: entity.project_id,
             type_id: entity.type_id,
             sequential_number: entity.sequential_number,
+            updated_by: entity.updated_by,
+            archived_at: entity.archived_at,
+            archived_by: entity.archived_by,
             field_values: Vec::new(), // Field values must be loaded separately
         }
     }
@@ -60,6 +75,9 @@ impl WorkItemModel {
             project_id: self.project_id.clone(),
             type_id: self.type_id.clone(),
             sequential_number: self.sequential_number.clone(),
+            updated_by: self.updated_by.clone(),
+            archived_at: self.archived_at.clone(),
+            archived_by: self.archived_by.clone(),
         }
     }
 
@@ -81,6 +99,9 @@ impl WorkItemModel {
             project_id: entity.project_id,
             type_id: entity.type_id,
             sequential_number: entity.sequential_number,
+            updated_by: entity.updated_by,
+            archived_at: entity.archived_at,
+            archived_by: entity.archived_by,
             field_values,
         }
     }
@@ -0,0 +1,73 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use crate::models::WorkItemQuery;
+
+/// The work items a bulk operation applies to
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WorkItemSelection {
+    /// Explicit work item ids
+    Ids { ids: Vec<String> },
+    /// Every work item matching a query. Pagination on the query is ignored.
+    Query { query: Box<WorkItemQuery> },
+}
+
+/// Changes applied to every selected work item. Fields that are not set are left alone.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkItemPatch {
+    pub status: Option<String>,
+    pub priority: Option<i32>,
+    /// `Some(None)` (JSON `null`) unassigns the work items; a missing key leaves them alone
+    #[serde(default, deserialize_with = "deserialize_present")]
+    pub assigned_to: Option<Option<String>>,
+    #[serde(default)]
+    pub field_values: Vec<FieldValuePatch>,
+}
+
+/// Sets or clears one custom field or assignment field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldValuePatch {
+    pub field_id: String,
+    #[serde(default)]
+    pub is_assignment_field: bool,
+    /// The new value, or `None` to remove the field's value
+    pub value: Option<String>,
+}
+
+/// Outcome of a bulk operation for a single work item
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemStatus {
+    /// The patch was applied
+    Updated,
+    /// The work item already matched the patch
+    Unchanged,
+    /// The work item could not be patched; see `error`
+    Failed,
+    /// The patch was valid for this work item, but nothing was applied because another item failed
+    Skipped,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkItemResult {
+    pub work_item_id: String,
+    pub sequential_number: Option<String>,
+    pub status: BulkItemStatus,
+    pub error: Option<String>,
+}
+
+/// Result of a bulk update. Changes are all-or-nothing: if any item fails, no item is updated.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkUpdateResult {
+    pub results: Vec<BulkItemResult>,
+    pub updated: usize,
+    pub failed: usize,
+}
+
+/// Distinguishes an explicit `null` from a missing key for `Option<Option<T>>` fields
+fn deserialize_present<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use super::WorkItemModel;
+
+/// What a cloned work item keeps from its source
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkItemCloneOptions {
+    /// Also clone the work item's descendants, recreating the parent/child links between the copies
+    #[serde(default)]
+    pub recursive: bool,
+    /// Start the copies in their type's initial status instead of the source's status
+    #[serde(default)]
+    pub reset_status: bool,
+    /// Leave the copies unassigned: clears the assignee and all assignment field values
+    #[serde(default)]
+    pub clear_assignees: bool,
+}
+
+/// One work item created by a clone
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClonedWorkItem {
+    pub source_work_item_id: String,
+    pub work_item_id: String,
+    pub sequential_number: Option<String>,
+}
+
+/// The outcome of a clone: the copy of the requested work item, and every copy made
+/// (the root first, then descendants by depth)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemCloneResult {
+    pub work_item: WorkItemModel,
+    pub cloned: Vec<ClonedWorkItem>,
+}
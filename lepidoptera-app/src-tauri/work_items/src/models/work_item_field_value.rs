@@ -100,6 +100,9 @@ impl WorkItemFieldValueModel {
             created_by: self.created_by.clone(),
             updated_by: self.updated_by.clone(),
             is_active: self.is_active,
+            // Projections are derived from the field type when the value is written
+            value_number: None,
+            value_date: None,
         }
     }
 }
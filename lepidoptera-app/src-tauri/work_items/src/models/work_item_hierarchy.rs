@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use crate::models::WorkItemModel;
+
+/// A work item reached by walking the parent/child hierarchy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemHierarchyEntry {
+    /// The work item (field values are not loaded)
+    pub work_item: WorkItemModel,
+    /// The work item this one was reached from: its child for ancestors, its parent for descendants
+    pub via_work_item_id: String,
+    /// Number of parent/child steps from the starting work item
+    pub depth: u32,
+}
+
+/// A work item together with its nested children
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemTreeNode {
+    /// The work item (field values are not loaded)
+    pub work_item: WorkItemModel,
+    /// Depth below the root of the subtree (the root is 0)
+    pub depth: u32,
+    pub children: Vec<WorkItemTreeNode>,
+}
@@ -0,0 +1,117 @@
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use crate::entities::WorkItemHistoryEntry as WorkItemHistoryEntryEntity;
+
+/// Kinds of change recorded in a work item's history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkItemChangeType {
+    /// The work item was created
+    Created,
+    /// A core column (title, status, priority, ...) changed
+    Updated,
+    /// A custom field value changed
+    FieldValue,
+    /// An assignment field value changed
+    AssignmentFieldValue,
+    /// A relationship to another work item was added
+    RelationshipAdded,
+    /// A relationship to another work item was removed
+    RelationshipRemoved,
+    /// The work item was archived
+    Archived,
+    /// The work item was restored from the archive
+    Restored,
+    /// The work item moved to another project and got a new sequential number
+    Moved,
+}
+
+impl WorkItemChangeType {
+    /// Convert to string representation for database storage
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkItemChangeType::Created => "created",
+            WorkItemChangeType::Updated => "updated",
+            WorkItemChangeType::FieldValue => "field_value",
+            WorkItemChangeType::AssignmentFieldValue => "assignment_field_value",
+            WorkItemChangeType::RelationshipAdded => "relationship_added",
+            WorkItemChangeType::RelationshipRemoved => "relationship_removed",
+            WorkItemChangeType::Archived => "archived",
+            WorkItemChangeType::Restored => "restored",
+            WorkItemChangeType::Moved => "moved",
+        }
+    }
+}
+
+impl FromStr for WorkItemChangeType {
+    type Err = anyhow::Error;
+
+    /// Parse from string representation
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "created" => Ok(WorkItemChangeType::Created),
+            "updated" => Ok(WorkItemChangeType::Updated),
+            "field_value" => Ok(WorkItemChangeType::FieldValue),
+            "assignment_field_value" => Ok(WorkItemChangeType::AssignmentFieldValue),
+            "relationship_added" => Ok(WorkItemChangeType::RelationshipAdded),
+            "relationship_removed" => Ok(WorkItemChangeType::RelationshipRemoved),
+            "archived" => Ok(WorkItemChangeType::Archived),
+            "restored" => Ok(WorkItemChangeType::Restored),
+            "moved" => Ok(WorkItemChangeType::Moved),
+            _ => Err(anyhow::anyhow!("Invalid change type: {}", s)),
+        }
+    }
+}
+
+/// Domain model for a work item history entry
+///
+/// `field` holds the column name for `Updated` changes, the field id for field value
+/// changes and the relationship type for relationship changes. For relationship
+/// changes the values hold the id of the other work item. For `Moved` changes `field`
+/// holds the previous project id and the values hold the old and new sequential numbers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemHistoryEntryModel {
+    pub id: Option<String>,
+    pub project_id: String,
+    pub work_item_id: String,
+    pub change_type: WorkItemChangeType,
+    pub field: Option<String>,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub changed_by: String,
+    pub changed_at: String,
+}
+
+impl WorkItemHistoryEntryModel {
+    /// Convert from entity to model
+    pub fn from_entity(entity: WorkItemHistoryEntryEntity) -> anyhow::Result<Self> {
+        let change_type = entity.change_type.parse::<WorkItemChangeType>()?;
+
+        Ok(Self {
+            id: entity.id,
+            project_id: entity.project_id,
+            work_item_id: entity.work_item_id,
+            change_type,
+            field: entity.field,
+            old_value: entity.old_value,
+            new_value: entity.new_value,
+            changed_by: entity.changed_by,
+            changed_at: entity.changed_at,
+        })
+    }
+
+    /// Convert from model to entity
+    pub fn to_entity(&self) -> WorkItemHistoryEntryEntity {
+        WorkItemHistoryEntryEntity {
+            id: self.id.clone(),
+            project_id: self.project_id.clone(),
+            work_item_id: self.work_item_id.clone(),
+            change_type: self.change_type.as_str().to_string(),
+            field: self.field.clone(),
+            old_value: self.old_value.clone(),
+            new_value: self.new_value.clone(),
+            changed_by: self.changed_by.clone(),
+            changed_at: self.changed_at.clone(),
+        }
+    }
+}
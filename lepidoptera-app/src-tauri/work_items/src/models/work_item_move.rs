@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+use super::{WorkItemModel, WorkItemTypeChange};
+
+/// Where a work item moves to and how its types carry over
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemMove {
+    pub destination_project_id: String,
+    /// Source type id -> destination type and status/field mappings for the work items of
+    /// that type. A type without an entry moves to the active destination type of the same name.
+    #[serde(default)]
+    pub type_changes: HashMap<String, WorkItemTypeChange>,
+    /// Move the work item's descendants along, keeping the relationships between the moved
+    /// items. Otherwise only the work item moves. Either way, relationships to work items
+    /// that stay behind are detached.
+    #[serde(default)]
+    pub move_children: bool,
+}
+
+/// One work item moved to the destination project
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MovedWorkItem {
+    pub work_item_id: String,
+    /// The number it had before the move, which keeps resolving as an alias
+    pub previous_sequential_number: Option<String>,
+    pub sequential_number: String,
+}
+
+/// The outcome of a move: the moved work item, every work item moved (the requested one
+/// first, then descendants by depth) and how many relationships were detached
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemMoveResult {
+    pub work_item: WorkItemModel,
+    pub moved: Vec<MovedWorkItem>,
+    pub detached_relationships: usize,
+}
@@ -1,5 +1,32 @@
 use serde::{Deserialize, Serialize};
-use crate::schemas::{AllowedStatus, AllowedPriority};
+use crate::schemas::{AllowedStatus, AllowedPriority, StatusCategory};
+
+/// Comparison operator for a field value query
+///
+/// Comparisons follow the field's `field_type`: number fields compare numerically,
+/// date and datetime fields compare chronologically and all other fields compare as text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldValueOperator {
+    #[default]
+    Eq,
+    /// Matches work items without a value equal to `value`, including those without the field
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    /// Case-insensitive substring match
+    Contains,
+    /// Case-insensitive prefix match
+    StartsWith,
+    /// Matches any of `values`
+    In,
+    /// Matches work items without a (non-empty) value for the field
+    IsEmpty,
+    /// Matches work items with a non-empty value for the field
+    IsNotEmpty,
+}
 
 /// Query criteria for filtering work items by field values
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -8,8 +35,15 @@ pub struct FieldValueQuery {
     pub field_id: String,
     /// Whether this is an assignment field
     pub is_assignment_field: bool,
-    /// The value to match (exact match for now, can be extended for operators)
+    /// How the stored value is compared (defaults to exact match)
+    #[serde(default)]
+    pub operator: FieldValueOperator,
+    /// The value to compare against (unused by `in`, `is_empty` and `is_not_empty`)
+    #[serde(default)]
     pub value: String,
+    /// The values to match for `in`
+    #[serde(default)]
+    pub values: Vec<String>,
 }
 
 /// Sort direction for query results
@@ -28,6 +62,8 @@ pub enum SortField {
     UpdatedAt,
     Title,
     Status,
+    /// Sort by status category (todo, in progress, done)
+    StatusCategory,
     Priority,
     TypeId,
     /// Sort by a custom field value
@@ -46,6 +82,8 @@ pub struct WorkItemQuery {
     pub project_id: String,
     /// Filter by statuses (IN clause) - can contain one or more status IDs
     pub statuses: Option<Vec<String>>,
+    /// Filter by status categories (e.g. todo and in_progress for all open items)
+    pub status_categories: Option<Vec<StatusCategory>>,
     /// Filter by exact priority
     pub priority: Option<i32>,
     /// Filter by minimum priority (>=)
@@ -62,7 +100,8 @@ pub struct WorkItemQuery {
     pub created_by: Option<String>,
     /// Filter by title containing text (LIKE)
     pub title_contains: Option<String>,
-    /// Filter by sequential numbers (IN clause) - can contain one or more formatted sequence numbers (e.g., M-0003, M-1045)
+    /// Filter by sequential numbers (IN clause) - can contain one or more formatted sequence numbers (e.g., M-0003, M-1045).
+    /// Numbers a work item had before it moved to this project match it as well.
     pub sequence_numbers: Option<Vec<String>>,
     /// Filter by field values
     pub field_value_queries: Option<Vec<FieldValueQuery>>,
@@ -78,6 +117,9 @@ pub struct WorkItemQuery {
     pub sort_by: Option<SortField>,
     /// Sort direction
     pub sort_direction: Option<SortDirection>,
+    /// Include archived work items (excluded by default)
+    #[serde(default)]
+    pub include_archived: Option<bool>,
 }
 
 /// Requested fields to include in the response
@@ -88,6 +130,8 @@ pub struct WorkItemListRequest {
     /// Field IDs to include in the response (both WorkItemField and AssignmentField IDs)
     /// If None or empty, no custom fields are included
     pub include_fields: Option<Vec<String>>,
+    /// Whether to include the number of matching items per status category
+    pub include_category_counts: Option<bool>,
 }
 
 /// Response DTO for a work item in a list
@@ -117,8 +161,14 @@ pub struct WorkItemListItem {
     pub type_id: String,
     /// Sequential number for display (e.g., M-0003, M-1045, etc.)
     pub sequential_number: Option<String>,
+    /// Last updated by user ID
+    pub updated_by: Option<String>,
+    /// When the work item was archived, if it is
+    pub archived_at: Option<String>,
     /// Hydrated status details from WorkItemType
     pub status_detail: Option<AllowedStatus>,
+    /// Category of the status, resolved from the WorkItemType
+    pub status_category: Option<StatusCategory>,
     /// Hydrated priority details from WorkItemType
     pub priority_detail: Option<AllowedPriority>,
     /// Requested field values (only those specified in include_fields)
@@ -138,5 +188,17 @@ pub struct WorkItemListResponse {
     pub page_size: Option<usize>,
     /// Total number of pages (if using page-based pagination)
     pub total_pages: Option<usize>,
+    /// Number of matching items per status category (if requested)
+    pub category_counts: Option<StatusCategoryCounts>,
+}
+
+/// Number of work items in each status category
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatusCategoryCounts {
+    pub todo: usize,
+    pub in_progress: usize,
+    pub done: usize,
+    /// Items whose status is not one of their type's allowed statuses
+    pub uncategorized: usize,
 }
 
@@ -51,6 +51,29 @@ impl RelationshipType {
         }
     }
 
+    /// The dependency graph this relationship type belongs to, if any
+    pub fn graph(&self) -> Option<RelationshipGraph> {
+        match self {
+            RelationshipType::Parent | RelationshipType::Child => Some(RelationshipGraph::Hierarchy),
+            RelationshipType::Blocks | RelationshipType::BlockedBy => Some(RelationshipGraph::Blocking),
+            _ => None,
+        }
+    }
+
+    /// Get the edge this relationship adds to its graph, as (from, to).
+    ///
+    /// Hierarchy edges point from parent to child and blocking edges point from the
+    /// blocking work item to the blocked one.
+    pub fn graph_edge<'a>(&self, source: &'a str, target: &'a str) -> Option<(RelationshipGraph, &'a str, &'a str)> {
+        match self {
+            RelationshipType::Parent => Some((RelationshipGraph::Hierarchy, source, target)),
+            RelationshipType::Child => Some((RelationshipGraph::Hierarchy, target, source)),
+            RelationshipType::Blocks => Some((RelationshipGraph::Blocking, source, target)),
+            RelationshipType::BlockedBy => Some((RelationshipGraph::Blocking, target, source)),
+            _ => None,
+        }
+    }
+
     /// Get the inverse relationship type
     pub fn inverse(&self) -> Option<Self> {
         match self {
@@ -65,6 +88,34 @@ impl RelationshipType {
     }
 }
 
+/// Graphs formed by directional relationships, which must stay acyclic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RelationshipGraph {
+    /// Parent/child relationships
+    Hierarchy,
+    /// Blocks/blocked-by relationships
+    Blocking,
+}
+
+impl RelationshipGraph {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RelationshipGraph::Hierarchy => "hierarchy",
+            RelationshipGraph::Blocking => "blocking",
+        }
+    }
+}
+
+/// A cycle in a relationship graph.
+///
+/// `work_item_ids` lists the work items along the cycle, starting and ending with the same id.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipCycle {
+    pub graph: RelationshipGraph,
+    pub work_item_ids: Vec<String>,
+}
+
 /// Domain model for WorkItemRelationship
 /// 
 /// Represents a directional relationship between two work items.
@@ -7,6 +7,8 @@ use crate::schemas::{
     AssignmentFieldDefinitions,
     WorkItemDetails,
     WorkItemFields,
+    WorkflowDefinition,
+    StatusCategory,
 };
 use crate::entities::WorkItemType as WorkItemTypeEntity;
 use anyhow::{Result, Context};
@@ -28,6 +30,8 @@ pub struct WorkItemTypeModel {
     pub assignment_field_definitions: AssignmentFieldDefinitions,
     pub work_item_details: WorkItemDetails,
     pub work_item_fields: WorkItemFields,
+    #[serde(default)]
+    pub workflow: Option<WorkflowDefinition>,
 }
 
 impl WorkItemTypeModel {
@@ -53,9 +57,47 @@ impl WorkItemTypeModel {
                 .context("Failed to parse work_item_details")?,
             work_item_fields: serde_json::from_str(&entity.work_item_fields)
                 .context("Failed to parse work_item_fields")?,
+            workflow: entity.workflow.as_deref()
+                .map(serde_json::from_str)
+                .transpose()
+                .context("Failed to parse workflow")?,
         })
     }
 
+    /// Whether `field_id` is one of this type's computed (non-assignment) fields
+    pub fn is_computed_field(&self, field_id: &str) -> bool {
+        self.work_item_fields.iter().any(|field| field.id == field_id && field.is_computed())
+    }
+
+    /// Get the category of one of this type's statuses.
+    ///
+    /// Uses the status's explicit category if set. Otherwise terminal statuses and the last
+    /// status are done, the initial (or first) status is todo and anything else is in progress.
+    pub fn status_category(&self, status_id: &str) -> Option<StatusCategory> {
+        let status = self.allowed_statuses.iter().find(|s| s.id == status_id)?;
+        if let Some(category) = status.category {
+            return Some(category);
+        }
+
+        let workflow = self.workflow.as_ref();
+        if workflow.is_some_and(|w| w.terminal_statuses.iter().any(|s| s == status_id)) {
+            return Some(StatusCategory::Done);
+        }
+
+        let initial_status = workflow
+            .and_then(|w| w.initial_status.as_deref())
+            .or_else(|| self.allowed_statuses.first().map(|s| s.id.as_str()));
+        if initial_status == Some(status_id) {
+            return Some(StatusCategory::Todo);
+        }
+
+        if self.allowed_statuses.last().map(|s| s.id.as_str()) == Some(status_id) {
+            return Some(StatusCategory::Done);
+        }
+
+        Some(StatusCategory::InProgress)
+    }
+
     /// Convert from model (domain format) to entity (storage format)
     pub fn to_entity(&self) -> Result<WorkItemTypeEntity> {
         Ok(WorkItemTypeEntity {
@@ -78,6 +120,10 @@ impl WorkItemTypeModel {
                 .context("Failed to serialize work_item_details")?,
             work_item_fields: serde_json::to_string(&self.work_item_fields)
                 .context("Failed to serialize work_item_fields")?,
+            workflow: self.workflow.as_ref()
+                .map(serde_json::to_string)
+                .transpose()
+                .context("Failed to serialize workflow")?,
         })
     }
 }
@@ -95,5 +141,7 @@ pub struct WorkItemTypeTemplate {
     pub assignment_field_definitions: Vec<Value>,
     pub work_item_details: Value,
     pub work_item_fields: Vec<Value>,
+    #[serde(default)]
+    pub workflow: Option<Value>,
 }
 
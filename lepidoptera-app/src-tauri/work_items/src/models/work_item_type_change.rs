@@ -0,0 +1,18 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// How a work item's statuses and field values carry over when it changes type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemTypeChange {
+    pub new_type_id: String,
+    /// Old status id -> new status id. An unmapped status is kept if the new type allows it.
+    #[serde(default)]
+    pub status_mapping: HashMap<String, String>,
+    /// Old field id -> new field id for custom fields. A field the new type also defines
+    /// carries over without a mapping; any other value is kept as an inactive row.
+    #[serde(default)]
+    pub field_mapping: HashMap<String, String>,
+    /// Old field id -> new field id for assignment fields, following the same rules
+    #[serde(default)]
+    pub assignment_field_mapping: HashMap<String, String>,
+}
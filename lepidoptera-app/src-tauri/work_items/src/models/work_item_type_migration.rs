@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
+
+/// A status or field removed by a work item type update, with how many work items still use it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemovedDefinitionUsage {
+    pub id: String,
+    pub label: String,
+    pub work_item_count: usize,
+}
+
+/// What an update to a work item type would do to the work items of that type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkItemTypeImpact {
+    pub work_item_type_id: String,
+    /// Number of work items of the type
+    pub work_item_count: usize,
+    pub removed_statuses: Vec<RemovedDefinitionUsage>,
+    pub removed_fields: Vec<RemovedDefinitionUsage>,
+    pub removed_assignment_fields: Vec<RemovedDefinitionUsage>,
+}
+
+impl WorkItemTypeImpact {
+    /// Whether any work item still uses a removed status or field
+    pub fn affects_work_items(&self) -> bool {
+        self.removed_statuses.iter()
+            .chain(&self.removed_fields)
+            .chain(&self.removed_assignment_fields)
+            .any(|usage| usage.work_item_count > 0)
+    }
+}
+
+/// How existing work items follow an update to their type.
+///
+/// Every removed status or field that is still in use must either be mapped to one the
+/// updated type keeps, or explicitly orphaned.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WorkItemTypeMigration {
+    /// Removed status id -> status id in the updated type
+    #[serde(default)]
+    pub status_mapping: HashMap<String, String>,
+    /// Removed field id -> field id in the updated type. A work item that already has a value
+    /// for the target field keeps it, and the mapped value is deactivated.
+    #[serde(default)]
+    pub field_mapping: HashMap<String, String>,
+    /// Removed assignment field id -> assignment field id in the updated type
+    #[serde(default)]
+    pub assignment_field_mapping: HashMap<String, String>,
+    /// Removed statuses whose work items keep the old status id
+    #[serde(default)]
+    pub orphaned_statuses: Vec<String>,
+    /// Removed fields whose values are deactivated
+    #[serde(default)]
+    pub orphaned_fields: Vec<String>,
+    /// Removed assignment fields whose values are deactivated
+    #[serde(default)]
+    pub orphaned_assignment_fields: Vec<String>,
+}
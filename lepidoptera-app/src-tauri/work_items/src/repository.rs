@@ -1,8 +1,46 @@
 use crate::entities::WorkItem;
 use anyhow::Result;
+use db::Connection;
+
+/// A work item reached by walking the parent/child hierarchy
+pub struct WorkItemHierarchyRow {
+    pub work_item: WorkItem,
+    /// The work item this one was reached from: its child when walking up, its parent when walking down
+    pub via_work_item_id: String,
+    /// Number of parent/child steps from the starting work item
+    pub depth: u32,
+}
 
 pub trait WorkItemsRepository: Send + Sync {
-    fn find_by_id(&self, id: &str) -> Result<Option<WorkItem>>;
+    fn find_by_id(&self, id: &str, conn: Option<&mut Connection>) -> Result<Option<WorkItem>>;
+
+    fn create(&self, work_item: WorkItem, conn: Option<&mut Connection>) -> Result<WorkItem>;
+
+    fn update(&self, work_item: WorkItem, conn: Option<&mut Connection>) -> Result<WorkItem>;
+
+    /// Delete a work item row (hard delete). Its field values, relationships and history
+    /// must be deleted first.
+    fn delete(&self, id: &str, conn: Option<&mut Connection>) -> Result<()>;
+
+    /// Delete the tag assignments and the conversations (with their messages and read
+    /// records) scoped to a work item
+    fn delete_tags_and_conversations(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<()>;
+
+    /// Move a work item to another project under a new sequential number. Its field values
+    /// and history move with it; tag assignments to the old project's tags are dropped.
+    fn move_to_project(&self, work_item_id: &str, project_id: &str, sequential_number: &str, conn: Option<&mut Connection>) -> Result<()>;
+
+    /// Find the work items that currently have a sequential number, in any project
+    fn find_by_sequential_number(&self, sequential_number: &str, conn: Option<&mut Connection>) -> Result<Vec<WorkItem>>;
+
+    /// Find all work items of a type
+    fn find_by_type_id(&self, type_id: &str, conn: Option<&mut Connection>) -> Result<Vec<WorkItem>>;
+
+    /// Find the ancestors of a work item up to `max_depth` levels, nearest first.
+    /// Archived ancestors, and anything above them, are left out unless `include_archived` is set.
+    fn find_ancestors(&self, work_item_id: &str, max_depth: u32, include_archived: bool) -> Result<Vec<WorkItemHierarchyRow>>;
 
-    fn create(&self, work_item: WorkItem) -> Result<WorkItem>;
+    /// Find the descendants of a work item up to `max_depth` levels, ordered by depth.
+    /// Archived descendants, and anything below them, are left out unless `include_archived` is set.
+    fn find_descendants(&self, work_item_id: &str, max_depth: u32, include_archived: bool) -> Result<Vec<WorkItemHierarchyRow>>;
 }
\ No newline at end of file
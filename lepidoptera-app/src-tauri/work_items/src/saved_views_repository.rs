@@ -0,0 +1,113 @@
+use std::sync::Arc;
+use db::{to_sql_vec, Connection};
+use db::connection_pool::ConnectionPool;
+use db::repository_base::{Entity, GenericRepository};
+use crate::entities::SavedView;
+use anyhow::Result;
+use rusqlite::ToSql;
+
+pub trait SavedViewsRepository: Send + Sync {
+    /// Find an active saved view by id
+    fn find_by_id(&self, id: &str) -> Result<Option<SavedView>>;
+
+    /// Find the active views of a project, ordered by name.
+    /// Returns the shared views plus, if `person_id` is given, that person's own views.
+    fn find_by_project_id(&self, project_id: &str, person_id: Option<&str>) -> Result<Vec<SavedView>>;
+
+    /// Create a new saved view
+    fn create(&self, saved_view: SavedView, conn: Option<&mut Connection>) -> Result<SavedView>;
+
+    /// Update a saved view's name, owner, query and columns
+    fn update(&self, saved_view: &SavedView, conn: Option<&mut Connection>) -> Result<()>;
+
+    /// Mark a saved view as inactive (soft delete)
+    fn mark_inactive(&self, id: &str, updated_by: &str, updated_at: &str) -> Result<()>;
+}
+
+pub struct SqliteSavedViewsRepository {
+    inner: GenericRepository<SavedView>,
+}
+
+impl SqliteSavedViewsRepository {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Self {
+            inner: GenericRepository::new(pool),
+        }
+    }
+}
+
+impl SavedViewsRepository for SqliteSavedViewsRepository {
+    fn find_by_id(&self, id: &str) -> Result<Option<SavedView>> {
+        Ok(self.inner.find_by_id(id, None)?.filter(|view| view.is_active))
+    }
+
+    fn find_by_project_id(&self, project_id: &str, person_id: Option<&str>) -> Result<Vec<SavedView>> {
+        self.inner.with_connection(|conn| {
+            let params: &[&dyn ToSql] = &[&project_id, &person_id];
+            let results = conn.query(
+                "SELECT id, project_id, person_id, name, query, include_fields,
+                        created_at, updated_at, created_by, updated_by, is_active
+                 FROM saved_views
+                 WHERE project_id = ?1 AND is_active = 1
+                   AND (person_id IS NULL OR person_id = ?2)
+                 ORDER BY name COLLATE NOCASE, created_at",
+                params,
+                |row| SavedView::from_row(row),
+            )?;
+
+            Ok(results)
+        })
+    }
+
+    fn create(&self, saved_view: SavedView, conn: Option<&mut Connection>) -> Result<SavedView> {
+        self.inner.create(saved_view, conn)
+    }
+
+    fn update(&self, saved_view: &SavedView, conn: Option<&mut Connection>) -> Result<()> {
+        let id = saved_view.id.as_ref().ok_or_else(|| anyhow::anyhow!("SavedView must have an id to update"))?;
+
+        let values = to_sql_vec![
+            saved_view.person_id.clone(),
+            saved_view.name.clone(),
+            saved_view.query.clone(),
+            saved_view.include_fields.clone(),
+            saved_view.updated_at.clone(),
+            saved_view.updated_by.clone(),
+            id.clone(),
+        ];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!(
+                    "UPDATE {} SET person_id = ?1, name = ?2, query = ?3, include_fields = ?4,
+                            updated_at = ?5, updated_by = ?6
+                     WHERE id = ?7 AND is_active = 1",
+                    SavedView::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )?;
+
+            Ok(())
+        })
+    }
+
+    fn mark_inactive(&self, id: &str, updated_by: &str, updated_at: &str) -> Result<()> {
+        let values = to_sql_vec![
+            updated_at.to_string(),
+            updated_by.to_string(),
+            id.to_string(),
+        ];
+
+        self.inner.with_connection(|conn| {
+            conn.execute(
+                &format!(
+                    "UPDATE {} SET is_active = 0, updated_at = ?1, updated_by = ?2 WHERE id = ?3",
+                    SavedView::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )?;
+
+            Ok(())
+        })
+    }
+}
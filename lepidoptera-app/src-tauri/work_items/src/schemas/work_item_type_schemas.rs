@@ -12,10 +12,55 @@ pub struct AllowedStatus {
     pub label: String,
     pub description: Option<String>,
     pub color: Option<String>,
+    /// Shared meaning of the status across templates. When not set, the category is
+    /// inferred from the status's position in the workflow (see `WorkItemTypeModel::status_category`).
+    #[serde(default)]
+    pub category: Option<StatusCategory>,
+}
+
+/// Category a status belongs to, giving statuses a common meaning across work item types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusCategory {
+    Todo,
+    InProgress,
+    Done,
+}
+
+impl StatusCategory {
+    /// Sort rank of the category (todo first, done last)
+    pub fn rank(&self) -> i32 {
+        match self {
+            StatusCategory::Todo => 0,
+            StatusCategory::InProgress => 1,
+            StatusCategory::Done => 2,
+        }
+    }
 }
 
 pub type AllowedStatuses = Vec<AllowedStatus>;
 
+/// Schema for `workflow` field
+/// Defines how work items of this type move between their allowed statuses.
+/// A type without a workflow allows any status to move to any other.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowDefinition {
+    /// Status new work items start in
+    pub initial_status: Option<String>,
+    /// Statuses that cannot be moved out of
+    #[serde(default)]
+    pub terminal_statuses: Vec<String>,
+    /// Allowed moves between statuses. When empty, any non-terminal status can move to any other.
+    #[serde(default)]
+    pub transitions: Vec<StatusTransition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusTransition {
+    pub from: String,
+    pub to: Vec<String>,
+}
+
 /// Schema for `allowed_priorities` field
 /// Represents the allowed priority values for work items of this type
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +69,9 @@ pub struct AllowedPriority {
     pub label: String,
     pub value: i32,
     pub color: Option<String>,
+    /// Priority new work items get when none is given
+    #[serde(default)]
+    pub is_default: bool,
 }
 
 pub type AllowedPriorities = Vec<AllowedPriority>;
@@ -59,14 +107,100 @@ pub struct WorkItemDetails {
 pub struct WorkItemField {
     pub id: String,
     pub label: String,
-    pub field_type: String, // e.g., "text", "number", "date", "select", etc.
+    /// e.g., "text", "number", "date", "select", "multi_select", "boolean", "url", "email",
+    /// "currency", "duration", "person", "team", "rollup", "formula"
+    /// (see `validate_field_value` for the value format of each)
+    pub field_type: String,
     pub required: bool,
     pub default_value: Option<serde_json::Value>,
     pub validation: Option<FieldValidation>,
-    pub options: Option<Vec<FieldOption>>, // For select/radio fields
+    pub options: Option<Vec<FieldOption>>, // For select/radio/multi_select fields
+    /// For "currency" fields: ISO 4217 code of the amounts, e.g. "EUR"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub currency_code: Option<String>,
+    /// For "rollup" fields: how the value is computed from child work items
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rollup: Option<RollupDefinition>,
+    /// For "formula" fields: an expression over the work item's other fields, e.g.
+    /// `impact * likelihood` (see `formula_fields` for the syntax)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formula: Option<String>,
+    /// The field is required while this condition holds, on top of `required`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub required_when: Option<FieldCondition>,
+    /// The field is shown only while this condition holds. A hidden field is never required
+    /// and can't be given a new value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visible_when: Option<FieldCondition>,
 }
 
+impl WorkItemField {
+    /// Whether the field's value is computed on read rather than entered and stored
+    pub fn is_computed(&self) -> bool {
+        self.rollup.is_some() || self.formula.is_some()
+    }
+}
+
+/// A condition on a work item's status or field values, used by conditional field rules
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum FieldCondition {
+    /// The status is one of `statuses`
+    Status { statuses: Vec<String> },
+    /// The status is in one of `categories`
+    StatusCategory { categories: Vec<StatusCategory> },
+    /// Another field's value compared with `values`
+    Field {
+        field_id: String,
+        #[serde(default)]
+        is_assignment_field: bool,
+        operator: ConditionOperator,
+        #[serde(default)]
+        values: Vec<String>,
+    },
+    /// Every condition holds
+    All { conditions: Vec<FieldCondition> },
+    /// At least one condition holds
+    Any { conditions: Vec<FieldCondition> },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConditionOperator {
+    /// The value is one of `values`. A multi-select value matches when any selected option is.
+    In,
+    /// The value is empty or none of `values`
+    NotIn,
+    IsEmpty,
+    IsNotEmpty,
+}
+
+/// Aggregates a value over a work item's children (through `Parent`/`Child` relationships).
+/// Rollup values are computed on read and never stored.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollupDefinition {
+    pub function: RollupFunction,
+    /// Number field of the children to aggregate. Required for sum, min and max. If a child's
+    /// type defines this field as a rollup too, its computed value is used.
+    #[serde(default)]
+    pub child_field_id: Option<String>,
+    /// Only aggregate children of these types. Empty means all children.
+    #[serde(default)]
+    pub child_type_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RollupFunction {
+    Sum,
+    Count,
+    Min,
+    Max,
+    /// Percentage of children whose status is in the done category
+    PercentDone,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct FieldValidation {
     pub min: Option<f64>,
     pub max: Option<f64>,
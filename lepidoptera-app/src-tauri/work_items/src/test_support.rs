@@ -0,0 +1,122 @@
+//! Builders for the models unit tests work with. Each returns the plainest valid value;
+//! tests fill in what they need with struct update syntax.
+
+use crate::models::{WorkItemTypeModel, WorkItemFieldValueModel};
+use crate::schemas::{
+    AllowedStatus, AllowedPriority, AssignmentFieldDefinition, WorkItemDetails, WorkItemField,
+    RollupDefinition, RollupFunction,
+};
+
+/// An active type of project `project` named after its id, without statuses or fields
+pub fn work_item_type(id: &str) -> WorkItemTypeModel {
+    WorkItemTypeModel {
+        id: Some(id.to_string()),
+        project_id: "project".to_string(),
+        created_at: "2024-01-01T00:00:00Z".to_string(),
+        updated_at: None,
+        is_active: true,
+        name: id.to_string(),
+        display_name: id.to_uppercase(),
+        allowed_children_type_ids: Vec::new(),
+        allowed_statuses: Vec::new(),
+        allowed_priorities: Vec::new(),
+        assignment_field_definitions: Vec::new(),
+        work_item_details: WorkItemDetails {
+            icon: None,
+            color: None,
+            description: None,
+            default_fields: None,
+            custom: serde_json::Value::Object(Default::default()),
+        },
+        work_item_fields: Vec::new(),
+        workflow: None,
+    }
+}
+
+/// An optional field without a default, validation or options
+pub fn field(id: &str, label: &str, field_type: &str) -> WorkItemField {
+    WorkItemField {
+        id: id.to_string(),
+        label: label.to_string(),
+        field_type: field_type.to_string(),
+        required: false,
+        default_value: None,
+        validation: None,
+        options: None,
+        currency_code: None,
+        rollup: None,
+        formula: None,
+        required_when: None,
+        visible_when: None,
+    }
+}
+
+/// A rollup field applying `function` to the children's `child_field_id`
+pub fn rollup_field(id: &str, function: RollupFunction, child_field_id: Option<&str>) -> WorkItemField {
+    WorkItemField {
+        rollup: Some(RollupDefinition {
+            function,
+            child_field_id: child_field_id.map(str::to_string),
+            child_type_ids: Vec::new(),
+        }),
+        ..field(id, id, "rollup")
+    }
+}
+
+/// A formula field computing `formula`
+pub fn formula_field(id: &str, formula: &str) -> WorkItemField {
+    WorkItemField {
+        formula: Some(formula.to_string()),
+        ..field(id, id, "formula")
+    }
+}
+
+/// A status whose category is inferred from its place in the workflow
+pub fn status(id: &str, label: &str) -> AllowedStatus {
+    AllowedStatus {
+        id: id.to_string(),
+        label: label.to_string(),
+        description: None,
+        color: None,
+        category: None,
+    }
+}
+
+pub fn priority(id: &str, label: &str, value: i32) -> AllowedPriority {
+    AllowedPriority {
+        id: id.to_string(),
+        label: label.to_string(),
+        value,
+        color: None,
+        is_default: false,
+    }
+}
+
+/// An optional person assignment field
+pub fn assignment_field(id: &str, label: &str) -> AssignmentFieldDefinition {
+    AssignmentFieldDefinition {
+        id: id.to_string(),
+        label: label.to_string(),
+        field_type: "person".to_string(),
+        required: false,
+        default_value: None,
+    }
+}
+
+/// An active value of a custom field of work item `item`
+pub fn field_value(field_id: &str, value: &str) -> WorkItemFieldValueModel {
+    WorkItemFieldValueModel {
+        id: None,
+        project_id: "project".to_string(),
+        work_item_id: "item".to_string(),
+        field_id: field_id.to_string(),
+        is_assignment_field: false,
+        value: value.to_string(),
+        created_at: String::new(),
+        updated_at: None,
+        created_by: String::new(),
+        updated_by: None,
+        is_active: true,
+        field_definition: None,
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::Arc;
+use db::{to_sql_vec, Connection};
+use db::connection_pool::ConnectionPool;
+use db::repository_base::{Entity, GenericRepository};
+use crate::entities::WorkItemFieldValue;
+use anyhow::Result;
+use rusqlite::ToSql;
+
+pub trait WorkItemFieldValuesRepository: Send + Sync {
+    /// Find all active field values for a work item
+    fn find_active_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<Vec<WorkItemFieldValue>>;
+
+    /// Find all active field values of the work items of a type
+    fn find_active_by_type_id(&self, type_id: &str, conn: Option<&mut Connection>) -> Result<Vec<WorkItemFieldValue>>;
+
+    /// Create a new field value
+    fn create(&self, field_value: WorkItemFieldValue, conn: Option<&mut Connection>) -> Result<WorkItemFieldValue>;
+
+    /// Update the value of an active field value
+    fn update_value(&self, field_value: &WorkItemFieldValue, conn: Option<&mut Connection>) -> Result<()>;
+
+    /// Mark an active field value as inactive (soft delete)
+    fn mark_inactive(&self, field_value: &WorkItemFieldValue, conn: Option<&mut Connection>) -> Result<()>;
+
+    /// Delete every field value of a work item, active or not (hard delete)
+    fn delete_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<usize>;
+}
+
+pub struct SqliteWorkItemFieldValuesRepository {
+    inner: GenericRepository<WorkItemFieldValue>,
+}
+
+impl SqliteWorkItemFieldValuesRepository {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Self {
+            inner: GenericRepository::new(pool),
+        }
+    }
+}
+
+impl WorkItemFieldValuesRepository for SqliteWorkItemFieldValuesRepository {
+    fn find_active_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<Vec<WorkItemFieldValue>> {
+        self.inner.with_optional_connection(conn, |conn| {
+            let params: &[&dyn ToSql] = &[&work_item_id];
+            let results = conn.query(
+                "SELECT id, project_id, work_item_id, field_id, is_assignment_field, value,
+                        created_at, updated_at, created_by, updated_by, is_active, value_number, value_date
+                 FROM work_item_field_values
+                 WHERE work_item_id = ?1 AND is_active = 1",
+                params,
+                |row| WorkItemFieldValue::from_row(row),
+            )?;
+
+            Ok(results)
+        })
+    }
+
+    fn find_active_by_type_id(&self, type_id: &str, conn: Option<&mut Connection>) -> Result<Vec<WorkItemFieldValue>> {
+        self.inner.with_optional_connection(conn, |conn| {
+            let params: &[&dyn ToSql] = &[&type_id];
+            let results = conn.query(
+                "SELECT fv.id, fv.project_id, fv.work_item_id, fv.field_id, fv.is_assignment_field, fv.value,
+                        fv.created_at, fv.updated_at, fv.created_by, fv.updated_by, fv.is_active, fv.value_number, fv.value_date
+                 FROM work_item_field_values fv
+                 JOIN work_items ON work_items.id = fv.work_item_id
+                 WHERE work_items.type_id = ?1 AND fv.is_active = 1",
+                params,
+                |row| WorkItemFieldValue::from_row(row),
+            )?;
+
+            Ok(results)
+        })
+    }
+
+    fn create(&self, field_value: WorkItemFieldValue, conn: Option<&mut Connection>) -> Result<WorkItemFieldValue> {
+        self.inner.create(field_value, conn)
+    }
+
+    fn update_value(&self, field_value: &WorkItemFieldValue, conn: Option<&mut Connection>) -> Result<()> {
+        // Rows written before field values were given ids have a NULL id, so the
+        // active row is addressed by its natural key instead
+        let values = to_sql_vec![
+            field_value.value.clone(),
+            field_value.value_number,
+            field_value.value_date.clone(),
+            field_value.updated_at.clone(),
+            field_value.updated_by.clone(),
+            field_value.work_item_id.clone(),
+            field_value.field_id.clone(),
+            field_value.is_assignment_field,
+        ];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!(
+                    "UPDATE {} SET value = ?1, value_number = ?2, value_date = ?3, updated_at = ?4, updated_by = ?5
+                     WHERE work_item_id = ?6 AND field_id = ?7 AND is_assignment_field = ?8 AND is_active = 1",
+                    WorkItemFieldValue::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )?;
+
+            Ok(())
+        })
+    }
+
+    fn mark_inactive(&self, field_value: &WorkItemFieldValue, conn: Option<&mut Connection>) -> Result<()> {
+        let values = to_sql_vec![
+            field_value.updated_at.clone(),
+            field_value.updated_by.clone(),
+            field_value.work_item_id.clone(),
+            field_value.field_id.clone(),
+            field_value.is_assignment_field,
+        ];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!(
+                    "UPDATE {} SET is_active = 0, updated_at = ?1, updated_by = ?2
+                     WHERE work_item_id = ?3 AND field_id = ?4 AND is_assignment_field = ?5 AND is_active = 1",
+                    WorkItemFieldValue::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )?;
+
+            Ok(())
+        })
+    }
+
+    fn delete_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<usize> {
+        let values = to_sql_vec![work_item_id.to_string()];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!("DELETE FROM {} WHERE work_item_id = ?1", WorkItemFieldValue::table_name()),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )
+        })
+    }
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+use db::{to_sql_vec, Connection};
+use db::connection_pool::ConnectionPool;
+use db::repository_base::{Entity, GenericRepository};
+use crate::entities::WorkItemHistoryEntry;
+use anyhow::Result;
+use rusqlite::ToSql;
+
+pub trait WorkItemHistoryRepository: Send + Sync {
+    /// Find all history entries for a work item, oldest first
+    fn find_by_work_item_id(&self, work_item_id: &str) -> Result<Vec<WorkItemHistoryEntry>>;
+
+    /// Record a history entry
+    fn create(&self, entry: WorkItemHistoryEntry, conn: Option<&mut Connection>) -> Result<WorkItemHistoryEntry>;
+
+    /// Delete the whole history of a work item (hard delete)
+    fn delete_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<usize>;
+}
+
+pub struct SqliteWorkItemHistoryRepository {
+    inner: GenericRepository<WorkItemHistoryEntry>,
+}
+
+impl SqliteWorkItemHistoryRepository {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Self {
+            inner: GenericRepository::new(pool),
+        }
+    }
+}
+
+impl WorkItemHistoryRepository for SqliteWorkItemHistoryRepository {
+    fn find_by_work_item_id(&self, work_item_id: &str) -> Result<Vec<WorkItemHistoryEntry>> {
+        self.inner.with_connection(|conn| {
+            let params: &[&dyn ToSql] = &[&work_item_id];
+            let results = conn.query(
+                // rowid keeps insertion order for entries recorded in the same unit of work
+                "SELECT id, project_id, work_item_id, change_type, field, old_value, new_value,
+                        changed_by, changed_at
+                 FROM work_item_history
+                 WHERE work_item_id = ?1
+                 ORDER BY changed_at, rowid",
+                params,
+                |row| WorkItemHistoryEntry::from_row(row),
+            )?;
+
+            Ok(results)
+        })
+    }
+
+    fn create(&self, entry: WorkItemHistoryEntry, conn: Option<&mut Connection>) -> Result<WorkItemHistoryEntry> {
+        self.inner.create(entry, conn)
+    }
+
+    fn delete_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<usize> {
+        let values = to_sql_vec![work_item_id.to_string()];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!("DELETE FROM {} WHERE work_item_id = ?1", WorkItemHistoryEntry::table_name()),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )
+        })
+    }
+}
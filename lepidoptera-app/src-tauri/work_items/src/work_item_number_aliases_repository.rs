@@ -0,0 +1,62 @@
+use std::sync::Arc;
+use db::{to_sql_vec, Connection};
+use db::connection_pool::ConnectionPool;
+use db::repository_base::{Entity, GenericRepository};
+use crate::entities::WorkItemNumberAlias;
+use anyhow::Result;
+use rusqlite::ToSql;
+
+pub trait WorkItemNumberAliasesRepository: Send + Sync {
+    /// Find the aliases with a sequential number, oldest first
+    fn find_by_sequential_number(&self, sequential_number: &str, conn: Option<&mut Connection>) -> Result<Vec<WorkItemNumberAlias>>;
+
+    /// Create a new alias
+    fn create(&self, alias: WorkItemNumberAlias, conn: Option<&mut Connection>) -> Result<WorkItemNumberAlias>;
+
+    /// Delete all aliases of a work item
+    fn delete_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<usize>;
+}
+
+pub struct SqliteWorkItemNumberAliasesRepository {
+    inner: GenericRepository<WorkItemNumberAlias>,
+}
+
+impl SqliteWorkItemNumberAliasesRepository {
+    pub fn new(pool: Arc<ConnectionPool>) -> Self {
+        Self {
+            inner: GenericRepository::new(pool),
+        }
+    }
+}
+
+impl WorkItemNumberAliasesRepository for SqliteWorkItemNumberAliasesRepository {
+    fn find_by_sequential_number(&self, sequential_number: &str, conn: Option<&mut Connection>) -> Result<Vec<WorkItemNumberAlias>> {
+        self.inner.with_optional_connection(conn, |conn| {
+            let params: &[&dyn ToSql] = &[&sequential_number];
+            let results = conn.query(
+                "SELECT * FROM work_item_number_aliases
+                 WHERE sequential_number = ?1
+                 ORDER BY created_at ASC",
+                params,
+                |row| WorkItemNumberAlias::from_row(row),
+            )?;
+
+            Ok(results)
+        })
+    }
+
+    fn create(&self, alias: WorkItemNumberAlias, conn: Option<&mut Connection>) -> Result<WorkItemNumberAlias> {
+        self.inner.create(alias, conn)
+    }
+
+    fn delete_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<usize> {
+        let values = to_sql_vec![work_item_id.to_string()];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!("DELETE FROM {} WHERE work_item_id = ?1", WorkItemNumberAlias::table_name()),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )
+        })
+    }
+}
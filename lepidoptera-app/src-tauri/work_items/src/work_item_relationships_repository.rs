@@ -1,5 +1,6 @@
 use crate::entities::WorkItemRelationship;
 use anyhow::Result;
+use db::Connection;
 
 pub trait WorkItemRelationshipsRepository: Send + Sync {
     /// Find a relationship by ID
@@ -20,19 +21,25 @@ pub trait WorkItemRelationshipsRepository: Send + Sync {
     /// Find all relationships for a work item (both as source and target)
     fn find_by_work_item_id(&self, work_item_id: &str) -> Result<Vec<WorkItemRelationship>>;
     
-    /// Find relationships by project
-    fn find_by_project_id(&self, project_id: &str) -> Result<Vec<WorkItemRelationship>>;
+    /// Find active relationships by project
+    fn find_by_project_id(&self, project_id: &str, conn: Option<&mut Connection>) -> Result<Vec<WorkItemRelationship>>;
     
     /// Create a new relationship
-    fn create(&self, relationship: WorkItemRelationship) -> Result<WorkItemRelationship>;
+    fn create(&self, relationship: WorkItemRelationship, conn: Option<&mut Connection>) -> Result<WorkItemRelationship>;
     
     /// Update an existing relationship
     fn update(&self, relationship: WorkItemRelationship) -> Result<WorkItemRelationship>;
     
     /// Mark a relationship as inactive (soft delete)
-    fn mark_inactive(&self, id: &str) -> Result<()>;
+    fn mark_inactive(&self, id: &str, conn: Option<&mut Connection>) -> Result<()>;
+    
+    /// Move a relationship to another project, along with the work items it connects
+    fn move_to_project(&self, id: &str, project_id: &str, conn: Option<&mut Connection>) -> Result<()>;
     
     /// Delete a relationship (hard delete)
     fn delete(&self, id: &str) -> Result<()>;
+
+    /// Delete every relationship from or to a work item, active or not (hard delete)
+    fn delete_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> Result<usize>;
 }
 
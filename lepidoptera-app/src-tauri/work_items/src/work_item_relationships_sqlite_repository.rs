@@ -1,6 +1,6 @@
 use crate::entities::WorkItemRelationship;
 use db::repository_base::{Entity, GenericRepository};
-use db::{to_sql_vec, ToSql};
+use db::{to_sql_vec, ToSql, Connection};
 use std::sync::Arc;
 use db::connection_pool::ConnectionPool;
 use crate::work_item_relationships_repository::WorkItemRelationshipsRepository;
@@ -122,28 +122,27 @@ impl WorkItemRelationshipsRepository for SqliteWorkItemRelationshipsRepository {
         Ok(relationships)
     }
 
-    fn find_by_project_id(&self, project_id: &str) -> anyhow::Result<Vec<WorkItemRelationship>> {
-        let pooled_conn = self.pool.get()?;
-        let conn = pooled_conn.get();
-        
-        let param: &dyn db::ToSql = &project_id;
-        let params = &[param];
-        
-        let relationships = conn.query(
-            "SELECT id, project_id, source_work_item_id, target_work_item_id, 
-                    relationship_type, created_at, updated_at, created_by, updated_by, is_active
-             FROM work_item_relationships 
-             WHERE project_id = ?1 AND is_active = 1
-             ORDER BY created_at",
-            params,
-            |row| WorkItemRelationship::from_row(row),
-        )?;
-        
-        Ok(relationships)
+    fn find_by_project_id(&self, project_id: &str, conn: Option<&mut Connection>) -> anyhow::Result<Vec<WorkItemRelationship>> {
+        self.inner.with_optional_connection(conn, |conn| {
+            let param: &dyn db::ToSql = &project_id;
+            let params = &[param];
+            
+            let relationships = conn.query(
+                "SELECT id, project_id, source_work_item_id, target_work_item_id, 
+                        relationship_type, created_at, updated_at, created_by, updated_by, is_active
+                 FROM work_item_relationships 
+                 WHERE project_id = ?1 AND is_active = 1
+                 ORDER BY created_at",
+                params,
+                |row| WorkItemRelationship::from_row(row),
+            )?;
+            
+            Ok(relationships)
+        })
     }
 
-    fn create(&self, relationship: WorkItemRelationship) -> anyhow::Result<WorkItemRelationship> {
-        self.inner.create(relationship, None)
+    fn create(&self, relationship: WorkItemRelationship, conn: Option<&mut Connection>) -> anyhow::Result<WorkItemRelationship> {
+        self.inner.create(relationship, conn)
     }
 
     fn update(&self, relationship: WorkItemRelationship) -> anyhow::Result<WorkItemRelationship> {
@@ -177,9 +176,7 @@ impl WorkItemRelationshipsRepository for SqliteWorkItemRelationshipsRepository {
             .and_then(|opt| opt.ok_or_else(|| anyhow::anyhow!("WorkItemRelationship not found after update")))
     }
 
-    fn mark_inactive(&self, id: &str) -> anyhow::Result<()> {
-        let pooled_conn = self.pool.get()?;
-        let conn = pooled_conn.get();
+    fn mark_inactive(&self, id: &str, conn: Option<&mut Connection>) -> anyhow::Result<()> {
         let now = chrono::Utc::now().to_rfc3339();
         
         let values: Vec<Box<dyn ToSql>> = vec![
@@ -187,15 +184,37 @@ impl WorkItemRelationshipsRepository for SqliteWorkItemRelationshipsRepository {
             Box::new(id.to_string()),
         ];
         
-        conn.execute(
-            &format!(
-                "UPDATE {} SET is_active = 0, updated_at = ?1 WHERE id = ?2",
-                WorkItemRelationship::table_name()
-            ),
-            rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
-        )?;
-        
-        Ok(())
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!(
+                    "UPDATE {} SET is_active = 0, updated_at = ?1 WHERE id = ?2",
+                    WorkItemRelationship::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )?;
+            
+            Ok(())
+        })
+    }
+
+    fn move_to_project(&self, id: &str, project_id: &str, conn: Option<&mut Connection>) -> anyhow::Result<()> {
+        let values = to_sql_vec![
+            project_id.to_string(),
+            chrono::Utc::now().to_rfc3339(),
+            id.to_string(),
+        ];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!(
+                    "UPDATE {} SET project_id = ?1, updated_at = ?2 WHERE id = ?3",
+                    WorkItemRelationship::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )?;
+
+            Ok(())
+        })
     }
 
     fn delete(&self, id: &str) -> anyhow::Result<()> {
@@ -213,5 +232,19 @@ impl WorkItemRelationshipsRepository for SqliteWorkItemRelationshipsRepository {
         
         Ok(())
     }
+
+    fn delete_by_work_item_id(&self, work_item_id: &str, conn: Option<&mut Connection>) -> anyhow::Result<usize> {
+        let values = to_sql_vec![work_item_id.to_string()];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!(
+                    "DELETE FROM {} WHERE source_work_item_id = ?1 OR target_work_item_id = ?1",
+                    WorkItemRelationship::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )
+        })
+    }
 }
 
@@ -1,11 +1,15 @@
 use crate::entities::WorkItemType;
 use anyhow::Result;
+use db::Connection;
 
 pub trait WorkItemTypesRepository: Send + Sync {
     fn find_by_id(&self, id: &str) -> Result<Option<WorkItemType>>;
     fn find_by_project_id(&self, project_id: &str) -> Result<Vec<WorkItemType>>;
-    fn create(&self, work_item_type: WorkItemType) -> Result<WorkItemType>;
-    fn update(&self, work_item_type: WorkItemType) -> Result<WorkItemType>;
-    fn mark_inactive(&self, id: &str) -> Result<()>;
+    fn create(&self, work_item_type: WorkItemType, conn: Option<&mut Connection>) -> Result<WorkItemType>;
+    fn update(&self, work_item_type: WorkItemType, conn: Option<&mut Connection>) -> Result<WorkItemType>;
+    fn mark_inactive(&self, id: &str, updated_at: &str, conn: Option<&mut Connection>) -> Result<()>;
+    /// Record an active work item type in the trash, so it can be restored or purged from there.
+    /// Returns false if there is no active type with this id.
+    fn record_in_trash(&self, id: &str, deleted_by: &str, deleted_at: &str, conn: Option<&mut Connection>) -> Result<bool>;
 }
 
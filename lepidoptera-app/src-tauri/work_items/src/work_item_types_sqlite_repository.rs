@@ -1,6 +1,6 @@
 use crate::entities::WorkItemType;
 use db::repository_base::{Entity, GenericRepository};
-use db::{ToSql, to_sql_vec};
+use db::{ToSql, to_sql_vec, Connection};
 use std::sync::Arc;
 use db::connection_pool::ConnectionPool;
 use crate::work_item_types_repository::WorkItemTypesRepository;
@@ -35,7 +35,7 @@ impl WorkItemTypesRepository for SqliteWorkItemTypesRepository {
             "SELECT id, project_id, created_at, updated_at, is_active, 
                     allowed_children_type_ids, allowed_statuses, allowed_priorities,
                     assignment_field_definitions, work_item_details, work_item_fields,
-                    name, display_name
+                    name, display_name, workflow
              FROM work_item_types 
              WHERE project_id = ?1 AND is_active = 1
              ORDER BY created_at",
@@ -46,13 +46,11 @@ impl WorkItemTypesRepository for SqliteWorkItemTypesRepository {
         Ok(work_item_types)
     }
 
-    fn create(&self, work_item_type: WorkItemType) -> anyhow::Result<WorkItemType> {
-        self.inner.create(work_item_type, None)
+    fn create(&self, work_item_type: WorkItemType, conn: Option<&mut Connection>) -> anyhow::Result<WorkItemType> {
+        self.inner.create(work_item_type, conn)
     }
 
-    fn update(&self, work_item_type: WorkItemType) -> anyhow::Result<WorkItemType> {
-        let pooled_conn = self.pool.get()?;
-        let conn = pooled_conn.get();
+    fn update(&self, work_item_type: WorkItemType, conn: Option<&mut Connection>) -> anyhow::Result<WorkItemType> {
         let id = work_item_type.id.as_ref().ok_or_else(|| anyhow::anyhow!("WorkItemType must have an id to update"))?;
         
         let values = to_sql_vec![
@@ -67,44 +65,69 @@ impl WorkItemTypesRepository for SqliteWorkItemTypesRepository {
             work_item_type.work_item_fields.clone(),
             work_item_type.name.clone(),
             work_item_type.display_name.clone(),
+            work_item_type.workflow.clone(),
             id.clone(),
         ];
         
-        conn.execute(
-            &format!(
-                "UPDATE {} SET project_id = ?1, updated_at = ?2, is_active = ?3, 
-                        allowed_children_type_ids = ?4, allowed_statuses = ?5, allowed_priorities = ?6,
-                        assignment_field_definitions = ?7, work_item_details = ?8, work_item_fields = ?9,
-                        name = ?10, display_name = ?11
-                 WHERE id = ?12",
-                WorkItemType::table_name()
-            ),
-            rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
-        )?;
-        
-        self.find_by_id(id)
-            .and_then(|opt| opt.ok_or_else(|| anyhow::anyhow!("WorkItemType not found after update")))
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!(
+                    "UPDATE {} SET project_id = ?1, updated_at = ?2, is_active = ?3, 
+                            allowed_children_type_ids = ?4, allowed_statuses = ?5, allowed_priorities = ?6,
+                            assignment_field_definitions = ?7, work_item_details = ?8, work_item_fields = ?9,
+                            name = ?10, display_name = ?11, workflow = ?12
+                     WHERE id = ?13",
+                    WorkItemType::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )?;
+            
+            // Re-read on the same connection so uncommitted changes are visible
+            self.inner.find_by_id(id, Some(conn))
+                .and_then(|opt| opt.ok_or_else(|| anyhow::anyhow!("WorkItemType not found after update")))
+        })
     }
 
-    fn mark_inactive(&self, id: &str) -> anyhow::Result<()> {
-        let pooled_conn = self.pool.get()?;
-        let conn = pooled_conn.get();
-        let now = chrono::Utc::now().to_rfc3339();
-        
+    fn mark_inactive(&self, id: &str, updated_at: &str, conn: Option<&mut Connection>) -> anyhow::Result<()> {
         let values = to_sql_vec![
-            now,
+            updated_at.to_string(),
             id.to_string(),
         ];
-        
-        conn.execute(
-            &format!(
-                "UPDATE {} SET is_active = 0, updated_at = ?1 WHERE id = ?2",
-                WorkItemType::table_name()
-            ),
-            rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
-        )?;
-        
-        Ok(())
+
+        self.inner.with_optional_connection(conn, |conn| {
+            conn.execute(
+                &format!(
+                    "UPDATE {} SET is_active = 0, updated_at = ?1 WHERE id = ?2",
+                    WorkItemType::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )?;
+
+            Ok(())
+        })
+    }
+
+    fn record_in_trash(&self, id: &str, deleted_by: &str, deleted_at: &str, conn: Option<&mut Connection>) -> anyhow::Result<bool> {
+        let values = to_sql_vec![
+            ulid::Ulid::new().to_string(),
+            deleted_at.to_string(),
+            deleted_by.to_string(),
+            id.to_string(),
+        ];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            let inserted = conn.execute(
+                &format!(
+                    "INSERT INTO trash_entries (id, project_id, entity_type, entity_id, display_name, deleted_at, deleted_by)
+                     SELECT ?1, project_id, 'work_item_type', id, display_name, ?2, ?3
+                     FROM {} WHERE id = ?4 AND is_active = 1",
+                    WorkItemType::table_name()
+                ),
+                rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())),
+            )?;
+
+            Ok(inserted > 0)
+        })
     }
 }
 
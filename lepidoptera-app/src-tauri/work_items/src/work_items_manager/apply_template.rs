@@ -1,6 +1,9 @@
 use std::sync::Arc;
 use std::collections::HashMap;
+use db::connection_pool::ConnectionPool;
+use db::Connection;
 use crate::models::{WorkItemTypeModel, WorkItemTypeTemplate};
+use crate::errors::HierarchyError;
 use crate::work_item_types_repository::WorkItemTypesRepository;
 use anyhow::{Result, Context};
 
@@ -9,8 +12,24 @@ use anyhow::{Result, Context};
 /// This function:
 /// 1. Creates all work item types from the template
 /// 2. Maps name references to actual IDs for allowed_children_type_ids
+///
+/// A child type name that doesn't match any type in the template is rejected.
+///
+/// Both passes run in a single transaction, so a template is either applied in full or not at all.
 pub fn apply_template(
     repository: &Arc<dyn WorkItemTypesRepository>,
+    pool: &Arc<ConnectionPool>,
+    project_id: String,
+    work_item_types: Vec<WorkItemTypeTemplate>,
+) -> Result<Vec<WorkItemTypeModel>> {
+    pool.get()?.get_mut().transaction(|conn| {
+        apply_template_in_transaction(repository, conn, project_id, work_item_types)
+    })
+}
+
+fn apply_template_in_transaction(
+    repository: &Arc<dyn WorkItemTypesRepository>,
+    conn: &mut Connection,
     project_id: String,
     work_item_types: Vec<WorkItemTypeTemplate>,
 ) -> Result<Vec<WorkItemTypeModel>> {
@@ -39,11 +58,16 @@ pub fn apply_template(
                 .context("Failed to parse work_item_details")?,
             work_item_fields: serde_json::from_value(serde_json::Value::Array(template_type.work_item_fields.clone()))
                 .context("Failed to parse work_item_fields")?,
+            workflow: template_type.workflow.clone()
+                .map(serde_json::from_value)
+                .transpose()
+                .context("Failed to parse workflow")?,
         };
 
         let created = crate::work_items_manager::create_work_item_type::create_work_item_type(
             repository,
             work_item_type,
+            Some(conn),
         )?;
 
         // Store the mapping
@@ -60,8 +84,13 @@ pub fn apply_template(
         let child_ids: Vec<String> = template_type
             .allowed_children_type_names
             .iter()
-            .filter_map(|name| name_to_id.get(name).cloned())
-            .collect();
+            .map(|name| {
+                name_to_id.get(name).cloned().ok_or_else(|| HierarchyError::UnknownChildType {
+                    type_name: template_type.name.clone(),
+                    child_type_name: name.clone(),
+                })
+            })
+            .collect::<Result<_, _>>()?;
 
         // Update the created type with resolved child IDs
         let mut work_item_type = created_types[index].clone();
@@ -71,6 +100,7 @@ pub fn apply_template(
         let updated = crate::work_items_manager::update_work_item_type::update_work_item_type(
             repository,
             work_item_type,
+            Some(conn),
         )?;
 
         created_types[index] = updated;
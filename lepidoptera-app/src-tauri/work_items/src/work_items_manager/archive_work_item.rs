@@ -0,0 +1,185 @@
+use std::sync::Arc;
+use db::Connection;
+use db::connection_pool::ConnectionPool;
+use crate::entities::WorkItem;
+use crate::models::{WorkItemChangeType, WorkItemModel};
+use crate::repository::WorkItemsRepository;
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_item_field_values_repository::WorkItemFieldValuesRepository;
+use crate::work_item_relationships_repository::WorkItemRelationshipsRepository;
+use crate::work_item_history_repository::WorkItemHistoryRepository;
+use crate::work_item_number_aliases_repository::WorkItemNumberAliasesRepository;
+use crate::work_items_manager::{get_work_item, work_item_history::HistoryRecorder};
+use anyhow::Result;
+use chrono::Utc;
+
+/// Archive a work item. It keeps its field values and relationships, but is left out of
+/// lists and can't be edited until it is restored.
+pub fn archive_work_item(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    history_repository: &Arc<dyn WorkItemHistoryRepository>,
+    pool: &Arc<ConnectionPool>,
+    work_item_id: &str,
+    archived_by: &str,
+) -> Result<WorkItemModel> {
+    pool.get()?.get_mut().transaction(|conn| {
+        let existing = find_work_item(repository, conn, work_item_id)?;
+        if existing.archived_at.is_some() {
+            return Err(anyhow::anyhow!("Work item {} is already archived", display_name(&existing)));
+        }
+        write_archived(repository, history_repository, conn, existing, archived_by)
+    })?;
+
+    get_work_item::get_work_item(repository, work_item_types_repository, pool, work_item_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found after archiving: {}", work_item_id))
+}
+
+/// Archive a work item as part of the caller's unit of work on `conn`, for callers that
+/// record the archiving elsewhere in the same transaction. A work item that is already
+/// archived is left as it is.
+pub fn ensure_work_item_archived(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    history_repository: &Arc<dyn WorkItemHistoryRepository>,
+    conn: &mut Connection,
+    work_item_id: &str,
+    archived_by: &str,
+) -> Result<WorkItemModel> {
+    let existing = find_work_item(repository, conn, work_item_id)?;
+    if existing.archived_at.is_none() {
+        write_archived(repository, history_repository, conn, existing, archived_by)?;
+    }
+
+    get_work_item::get_stored_work_item(repository, work_item_types_repository, conn, work_item_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found after archiving: {}", work_item_id))
+}
+
+/// Restore an archived work item
+pub fn restore_work_item(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    history_repository: &Arc<dyn WorkItemHistoryRepository>,
+    pool: &Arc<ConnectionPool>,
+    work_item_id: &str,
+    restored_by: &str,
+) -> Result<WorkItemModel> {
+    pool.get()?.get_mut().transaction(|conn| {
+        let existing = find_work_item(repository, conn, work_item_id)?;
+        if existing.archived_at.is_none() {
+            return Err(anyhow::anyhow!("Work item {} is not archived", display_name(&existing)));
+        }
+        write_restored(repository, history_repository, conn, existing, restored_by)
+    })?;
+
+    get_work_item::get_work_item(repository, work_item_types_repository, pool, work_item_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found after restoring: {}", work_item_id))
+}
+
+/// Restore a work item as part of the caller's unit of work on `conn`. A work item that
+/// is not archived, e.g. because it was already restored from the archive, is left as it is.
+pub fn ensure_work_item_restored(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    history_repository: &Arc<dyn WorkItemHistoryRepository>,
+    conn: &mut Connection,
+    work_item_id: &str,
+    restored_by: &str,
+) -> Result<WorkItemModel> {
+    let existing = find_work_item(repository, conn, work_item_id)?;
+    if existing.archived_at.is_some() {
+        write_restored(repository, history_repository, conn, existing, restored_by)?;
+    }
+
+    get_work_item::get_stored_work_item(repository, work_item_types_repository, conn, work_item_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found after restoring: {}", work_item_id))
+}
+
+fn write_archived(
+    repository: &Arc<dyn WorkItemsRepository>,
+    history_repository: &Arc<dyn WorkItemHistoryRepository>,
+    conn: &mut Connection,
+    existing: WorkItem,
+    archived_by: &str,
+) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    let work_item_id = existing.id.clone().unwrap_or_default();
+    let project_id = existing.project_id.clone();
+    repository.update(WorkItem {
+        archived_at: Some(now.clone()),
+        archived_by: Some(archived_by.to_string()),
+        ..existing
+    }, Some(conn))?;
+    HistoryRecorder::new(history_repository, &project_id, &work_item_id, archived_by, &now)
+        .record(conn, WorkItemChangeType::Archived, None, None, None)
+}
+
+fn write_restored(
+    repository: &Arc<dyn WorkItemsRepository>,
+    history_repository: &Arc<dyn WorkItemHistoryRepository>,
+    conn: &mut Connection,
+    existing: WorkItem,
+    restored_by: &str,
+) -> Result<()> {
+    let now = Utc::now().to_rfc3339();
+    let work_item_id = existing.id.clone().unwrap_or_default();
+    let project_id = existing.project_id.clone();
+    repository.update(WorkItem {
+        archived_at: None,
+        archived_by: None,
+        ..existing
+    }, Some(conn))?;
+    HistoryRecorder::new(history_repository, &project_id, &work_item_id, restored_by, &now)
+        .record(conn, WorkItemChangeType::Restored, None, None, None)
+}
+
+/// Permanently delete an archived work item.
+///
+/// Its field values, its relationships in both directions, its history, its number aliases,
+/// its tag assignments and the conversations scoped to it are deleted with it, in one unit
+/// of work. Other work items it was related to are kept.
+pub fn purge_work_item(
+    repository: &Arc<dyn WorkItemsRepository>,
+    field_values_repository: &Arc<dyn WorkItemFieldValuesRepository>,
+    relationships_repository: &Arc<dyn WorkItemRelationshipsRepository>,
+    history_repository: &Arc<dyn WorkItemHistoryRepository>,
+    number_aliases_repository: &Arc<dyn WorkItemNumberAliasesRepository>,
+    pool: &Arc<ConnectionPool>,
+    work_item_id: &str,
+) -> Result<()> {
+    pool.get()?.get_mut().transaction(|conn| {
+        let existing = find_work_item(repository, conn, work_item_id)?;
+        if existing.archived_at.is_none() {
+            return Err(anyhow::anyhow!(
+                "Work item {} must be archived before it can be purged",
+                display_name(&existing)
+            ));
+        }
+
+        field_values_repository.delete_by_work_item_id(work_item_id, Some(conn))?;
+        relationships_repository.delete_by_work_item_id(work_item_id, Some(conn))?;
+        history_repository.delete_by_work_item_id(work_item_id, Some(conn))?;
+        number_aliases_repository.delete_by_work_item_id(work_item_id, Some(conn))?;
+        repository.delete_tags_and_conversations(work_item_id, Some(conn))?;
+        repository.delete(work_item_id, Some(conn))
+    })
+}
+
+/// Fails if the work item is archived; archived work items have to be restored before
+/// they can be changed
+pub fn ensure_not_archived(work_item: &WorkItemModel) -> Result<()> {
+    if work_item.archived_at.is_some() {
+        let name = work_item.sequential_number.as_deref().or(work_item.id.as_deref()).unwrap_or_default();
+        return Err(anyhow::anyhow!("Work item {} is archived and can't be changed", name));
+    }
+    Ok(())
+}
+
+fn find_work_item(repository: &Arc<dyn WorkItemsRepository>, conn: &mut Connection, work_item_id: &str) -> Result<WorkItem> {
+    repository.find_by_id(work_item_id, Some(conn))?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", work_item_id))
+}
+
+fn display_name(work_item: &WorkItem) -> &str {
+    work_item.sequential_number.as_deref().or(work_item.id.as_deref()).unwrap_or_default()
+}
@@ -0,0 +1,244 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
+use crate::models::{
+    WorkItemModel, WorkItemTypeModel, WorkItemFieldValueModel, WorkItemListRequest,
+    WorkItemSelection, WorkItemPatch, BulkItemStatus, BulkItemResult, BulkUpdateResult,
+};
+use crate::entities::WorkItem;
+use crate::repository::WorkItemsRepository;
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::assignees_repository::AssigneesRepository;
+use crate::work_items_manager::{
+    get_work_item, list_work_items, validate_field_value::validate_field_values_for_status,
+    status_workflow::validate_status_transition, update_work_item::write_work_item_update,
+    validate_assignment::validate_assignments, archive_work_item::ensure_not_archived,
+    work_item_repositories::WorkItemRepositories,
+};
+use anyhow::{Result, Context};
+use chrono::Utc;
+
+/// A selected work item with the patch applied, ready to be written
+struct PlannedUpdate {
+    work_item_type_id: String,
+    existing: WorkItem,
+    patched: WorkItemModel,
+}
+
+/// Apply one patch to many work items.
+///
+/// Every selected work item is patched and validated against its type (workflow and field
+/// rules) before anything is written. If all items are valid the changes are written in the
+/// same transaction; if any item fails, nothing is written and the result says why.
+pub fn bulk_update_work_items(
+    repositories: WorkItemRepositories,
+    selection: WorkItemSelection,
+    patch: WorkItemPatch,
+    updated_by: &str,
+) -> Result<BulkUpdateResult> {
+    if is_empty_patch(&patch) {
+        return Err(anyhow::anyhow!("Bulk update patch has no changes"));
+    }
+    let WorkItemRepositories { repository, work_item_types_repository, assignees_repository, pool, .. } = repositories;
+
+    let work_item_ids = resolve_selection(repository, work_item_types_repository, pool, selection)?;
+
+    let now = Utc::now().to_rfc3339();
+
+    // The items are loaded, validated and written in one transaction, so nothing can change
+    // them between the checks and the writes
+    pool.get()?.get_mut().transaction(|conn| {
+        let mut work_item_types: HashMap<String, WorkItemTypeModel> = HashMap::new();
+        let mut results = Vec::with_capacity(work_item_ids.len());
+        let mut planned = Vec::new();
+
+        for work_item_id in work_item_ids {
+            let current = match get_work_item::get_stored_work_item(repository, work_item_types_repository, conn, &work_item_id)? {
+                Some(current) => current,
+                None => {
+                    results.push(failed(work_item_id.clone(), None, format!("Work item not found: {}", work_item_id)));
+                    continue;
+                }
+            };
+            let sequential_number = current.sequential_number.clone();
+
+            match plan_update(work_item_types_repository, assignees_repository, &mut work_item_types, current, &patch) {
+                Ok(Some(update)) => {
+                    results.push(BulkItemResult {
+                        work_item_id,
+                        sequential_number,
+                        status: BulkItemStatus::Updated,
+                        error: None,
+                    });
+                    planned.push(update);
+                }
+                Ok(None) => results.push(BulkItemResult {
+                    work_item_id,
+                    sequential_number,
+                    status: BulkItemStatus::Unchanged,
+                    error: None,
+                }),
+                Err(e) => results.push(failed(work_item_id, sequential_number, e.to_string())),
+            }
+        }
+
+        let failed_count = results.iter().filter(|r| r.status == BulkItemStatus::Failed).count();
+        if failed_count > 0 {
+            // All-or-nothing: valid items are reported but not written
+            for result in results.iter_mut().filter(|r| r.status == BulkItemStatus::Updated) {
+                result.status = BulkItemStatus::Skipped;
+            }
+            return Ok(BulkUpdateResult { results, updated: 0, failed: failed_count });
+        }
+
+        for update in &planned {
+            write_work_item_update(
+                repositories,
+                conn,
+                &work_item_types[&update.work_item_type_id],
+                &update.existing,
+                &update.patched,
+                updated_by,
+                &now,
+            )
+            .with_context(|| format!("Failed to update work item {}", update.patched.id.as_deref().unwrap_or_default()))?;
+        }
+
+        Ok(BulkUpdateResult { results, updated: planned.len(), failed: 0 })
+    })
+}
+
+/// Resolve a selection to work item ids, in selection order and without duplicates
+fn resolve_selection(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    pool: &Arc<ConnectionPool>,
+    selection: WorkItemSelection,
+) -> Result<Vec<String>> {
+    let ids = match selection {
+        WorkItemSelection::Ids { ids } => ids,
+        WorkItemSelection::Query { mut query } => {
+            // A query selects every match, not just one page
+            query.page = None;
+            query.page_size = None;
+            query.limit = None;
+            query.offset = None;
+            let request = WorkItemListRequest { query: *query, include_fields: None, include_category_counts: None };
+            list_work_items::list_work_items(repository, work_item_types_repository, pool, request)?
+                .items
+                .into_iter()
+                .filter_map(|item| item.id)
+                .collect()
+        }
+    };
+
+    let mut seen = HashSet::new();
+    Ok(ids.into_iter().filter(|id| seen.insert(id.clone())).collect())
+}
+
+/// Apply the patch to a work item and validate the result against its type.
+/// Returns `None` if the work item already matches the patch.
+fn plan_update(
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    assignees_repository: &Arc<dyn AssigneesRepository>,
+    work_item_types: &mut HashMap<String, WorkItemTypeModel>,
+    current: WorkItemModel,
+    patch: &WorkItemPatch,
+) -> Result<Option<PlannedUpdate>> {
+    ensure_not_archived(&current)?;
+    if !work_item_types.contains_key(&current.type_id) {
+        let type_entity = work_item_types_repository
+            .find_by_id(&current.type_id)
+            .context("Failed to find work item type")?
+            .ok_or_else(|| anyhow::anyhow!("Work item type not found: {}", current.type_id))?;
+        work_item_types.insert(current.type_id.clone(), WorkItemTypeModel::from_entity(type_entity)?);
+    }
+    let work_item_type = &work_item_types[&current.type_id];
+
+    let patched = apply_patch(&current, patch);
+    if !has_changes(&current, &patched) {
+        return Ok(None);
+    }
+
+    validate_status_transition(work_item_type, &current.status, &patched.status)?;
+    validate_field_values_for_status(work_item_type, &patched.status, &patched.field_values, Some(&current.field_values))?;
+    validate_assignments(assignees_repository, work_item_type, &patched, Some(&current))?;
+
+    Ok(Some(PlannedUpdate {
+        work_item_type_id: current.type_id.clone(),
+        existing: current.to_entity(),
+        patched,
+    }))
+}
+
+fn apply_patch(current: &WorkItemModel, patch: &WorkItemPatch) -> WorkItemModel {
+    let mut patched = current.clone();
+    if let Some(status) = &patch.status {
+        patched.status = status.clone();
+    }
+    if let Some(priority) = patch.priority {
+        patched.priority = priority;
+    }
+    if let Some(assigned_to) = &patch.assigned_to {
+        patched.assigned_to = assigned_to.clone();
+    }
+
+    for field_patch in &patch.field_values {
+        let position = patched.field_values.iter().position(|fv| {
+            fv.field_id == field_patch.field_id && fv.is_assignment_field == field_patch.is_assignment_field
+        });
+        match (&field_patch.value, position) {
+            (Some(value), Some(index)) => patched.field_values[index].value = value.clone(),
+            (Some(value), None) => patched.field_values.push(WorkItemFieldValueModel {
+                id: None,
+                project_id: current.project_id.clone(),
+                work_item_id: current.id.clone().unwrap_or_default(),
+                field_id: field_patch.field_id.clone(),
+                is_assignment_field: field_patch.is_assignment_field,
+                value: value.clone(),
+                created_at: String::new(),
+                updated_at: None,
+                created_by: String::new(),
+                updated_by: None,
+                is_active: true,
+                field_definition: None,
+            }),
+            (None, Some(index)) => {
+                patched.field_values.remove(index);
+            }
+            (None, None) => {}
+        }
+    }
+
+    patched
+}
+
+fn has_changes(current: &WorkItemModel, patched: &WorkItemModel) -> bool {
+    let values = |work_item: &WorkItemModel| -> HashMap<(String, bool), String> {
+        work_item.field_values
+            .iter()
+            .map(|fv| ((fv.field_id.clone(), fv.is_assignment_field), fv.value.clone()))
+            .collect()
+    };
+
+    current.status != patched.status
+        || current.priority != patched.priority
+        || current.assigned_to != patched.assigned_to
+        || values(current) != values(patched)
+}
+
+fn is_empty_patch(patch: &WorkItemPatch) -> bool {
+    patch.status.is_none()
+        && patch.priority.is_none()
+        && patch.assigned_to.is_none()
+        && patch.field_values.is_empty()
+}
+
+fn failed(work_item_id: String, sequential_number: Option<String>, error: String) -> BulkItemResult {
+    BulkItemResult {
+        work_item_id,
+        sequential_number,
+        status: BulkItemStatus::Failed,
+        error: Some(error),
+    }
+}
@@ -0,0 +1,157 @@
+use std::collections::{HashMap, HashSet};
+use crate::models::{WorkItemModel, WorkItemTypeModel, WorkItemTypeChange};
+use crate::work_items_manager::{
+    get_work_item, update_work_item::write_work_item_update, status_workflow::validate_status,
+    validate_field_value::validate_field_values_for_status, validate_assignment::validate_assignments,
+    validate_hierarchy::validate_hierarchy_for_type, work_item_defaults::apply_defaults, archive_work_item::ensure_not_archived,
+    work_item_repositories::WorkItemRepositories,
+};
+use anyhow::{Result, Context};
+use chrono::Utc;
+
+/// Change a work item's type, carrying its status and field values over to the new type.
+///
+/// Statuses and field ids are translated through the mappings in `change`. Field values the
+/// new type has no field for are marked inactive rather than deleted. The result is validated
+/// against the new type, including the parent/child rules of the item's relationships, and
+/// written as a single unit of work.
+pub fn change_work_item_type(
+    repositories: WorkItemRepositories,
+    work_item_id: &str,
+    change: WorkItemTypeChange,
+    updated_by: &str,
+) -> Result<WorkItemModel> {
+    let WorkItemRepositories {
+        repository,
+        work_item_types_repository,
+        relationships_repository,
+        assignees_repository,
+        pool,
+        ..
+    } = repositories;
+    let previous = get_work_item::get_stored_work_item(repository, work_item_types_repository, pool.get()?.get_mut(), work_item_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", work_item_id))?;
+    ensure_not_archived(&previous)?;
+
+    if previous.type_id == change.new_type_id {
+        return Err(anyhow::anyhow!("Work item {} is already of type {}", work_item_id, change.new_type_id));
+    }
+
+    let new_type = match work_item_types_repository
+        .find_by_id(&change.new_type_id)
+        .context("Failed to find work item type")?
+    {
+        Some(entity) => WorkItemTypeModel::from_entity(entity)
+            .context("Failed to convert work item type entity to model")?,
+        None => return Err(anyhow::anyhow!("Work item type not found: {}", change.new_type_id)),
+    };
+    if !new_type.is_active || new_type.project_id != previous.project_id {
+        return Err(anyhow::anyhow!(
+            "Work item type '{}' is not available in project {}",
+            new_type.name,
+            previous.project_id
+        ));
+    }
+
+    let work_item = map_to_type(&previous, &new_type, &change)?;
+
+    validate_status(&new_type, &work_item.status)?;
+    // Carried over values stay even where the new type hides them
+    validate_field_values_for_status(&new_type, &work_item.status, &work_item.field_values, Some(&work_item.field_values))?;
+    validate_assignments(assignees_repository, &new_type, &work_item, Some(&previous))?;
+    validate_hierarchy_for_type(
+        repository,
+        work_item_types_repository,
+        relationships_repository,
+        work_item_id,
+        &new_type,
+    )?;
+
+    let existing = previous.to_entity();
+    let now = Utc::now().to_rfc3339();
+
+    pool.get()?.get_mut().transaction(|conn| {
+        write_work_item_update(repositories, conn, &new_type, &existing, &work_item, updated_by, &now)
+    })?;
+
+    get_work_item::get_work_item(repository, work_item_types_repository, pool, work_item_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found after type change: {}", work_item_id))
+}
+
+/// Build the work item as it will look under `new_type`
+pub fn map_to_type(
+    previous: &WorkItemModel,
+    new_type: &WorkItemTypeModel,
+    change: &WorkItemTypeChange,
+) -> Result<WorkItemModel> {
+    let field_ids: HashSet<&str> = new_type.work_item_fields.iter().map(|f| f.id.as_str()).collect();
+    let assignment_field_ids: HashSet<&str> = new_type.assignment_field_definitions.iter().map(|f| f.id.as_str()).collect();
+
+    check_mapping_targets(&change.field_mapping, &field_ids, new_type, "field")?;
+    check_mapping_targets(&change.assignment_field_mapping, &assignment_field_ids, new_type, "assignment field")?;
+
+    let mut work_item = previous.clone();
+    work_item.type_id = change.new_type_id.clone();
+    work_item.status = change.status_mapping.get(&previous.status)
+        .cloned()
+        .unwrap_or_else(|| previous.status.clone());
+
+    // Keep the priority if the new type knows it, otherwise fall back to its default
+    if !new_type.allowed_priorities.is_empty()
+        && !new_type.allowed_priorities.iter().any(|p| p.value == previous.priority)
+    {
+        if let Some(priority) = new_type.allowed_priorities.iter().find(|p| p.is_default) {
+            work_item.priority = priority.value;
+        }
+    }
+
+    let mut seen = HashSet::new();
+    work_item.field_values = Vec::new();
+    for field_value in &previous.field_values {
+        let (mapping, defined) = if field_value.is_assignment_field {
+            (&change.assignment_field_mapping, &assignment_field_ids)
+        } else {
+            (&change.field_mapping, &field_ids)
+        };
+        let field_id = mapping.get(&field_value.field_id).unwrap_or(&field_value.field_id);
+
+        // No place for this value in the new type: it is left behind as an inactive row
+        if !defined.contains(field_id.as_str()) {
+            continue;
+        }
+        if !seen.insert((field_id.clone(), field_value.is_assignment_field)) {
+            return Err(anyhow::anyhow!(
+                "More than one value maps to field '{}' of work item type '{}'",
+                field_id,
+                new_type.name
+            ));
+        }
+
+        let mut mapped = field_value.clone();
+        mapped.field_id = field_id.clone();
+        mapped.field_definition = None;
+        work_item.field_values.push(mapped);
+    }
+
+    // Fields the new type adds start from their defaults, as on create
+    apply_defaults(new_type, &mut work_item, Utc::now());
+
+    Ok(work_item)
+}
+
+fn check_mapping_targets(
+    mapping: &HashMap<String, String>,
+    defined: &HashSet<&str>,
+    new_type: &WorkItemTypeModel,
+    kind: &str,
+) -> Result<()> {
+    match mapping.values().find(|target| !defined.contains(target.as_str())) {
+        Some(target) => Err(anyhow::anyhow!(
+            "Work item type '{}' has no {} '{}'",
+            new_type.name,
+            kind,
+            target
+        )),
+        None => Ok(()),
+    }
+}
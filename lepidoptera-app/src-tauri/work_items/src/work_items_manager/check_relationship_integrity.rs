@@ -0,0 +1,14 @@
+use std::sync::Arc;
+use crate::models::RelationshipCycle;
+use crate::work_item_relationships_repository::WorkItemRelationshipsRepository;
+use crate::work_items_manager::relationship_cycles::find_cycles;
+use anyhow::Result;
+
+/// List the cycles among a project's active parent/child and blocks relationships
+pub fn check_relationship_integrity(
+    repository: &Arc<dyn WorkItemRelationshipsRepository>,
+    project_id: &str,
+) -> Result<Vec<RelationshipCycle>> {
+    let relationships = repository.find_by_project_id(project_id, None)?;
+    Ok(find_cycles(&relationships))
+}
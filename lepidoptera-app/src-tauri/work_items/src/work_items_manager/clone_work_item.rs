@@ -0,0 +1,225 @@
+use std::collections::{HashMap, HashSet};
+use crate::entities::WorkItemRelationship;
+use crate::models::{
+    WorkItemModel, WorkItemTypeModel, WorkItemFieldValueModel, WorkItemChangeType, RelationshipType,
+    WorkItemCloneOptions, ClonedWorkItem, WorkItemCloneResult,
+};
+use crate::work_items_manager::{
+    get_work_item, create_work_item::insert_work_item, work_item_repositories::WorkItemRepositories,
+    get_work_item_hierarchy::MAX_HIERARCHY_DEPTH, status_workflow::{resolve_initial_status, validate_status},
+    validate_field_value::validate_field_values_for_status, validate_assignment::validate_assignments,
+    work_item_history::record_relationship_change,
+};
+use anyhow::{Result, Context};
+use chrono::Utc;
+use ulid::Ulid;
+
+/// Clone a work item, and with `options.recursive` its descendants as well.
+///
+/// Each copy gets a new sequential number and the source's stored field values; computed
+/// fields are left to be computed for the copy. Archived descendants are not cloned, and
+/// neither is anything only reachable through them. Parent/child links between the cloned
+/// items are recreated between their copies, but the root copy is not linked to the
+/// source's parent. Everything is written as a single unit of work.
+pub fn clone_work_item(
+    repositories: WorkItemRepositories,
+    work_item_id: &str,
+    options: WorkItemCloneOptions,
+    cloned_by: &str,
+    sequence_prefix: &str,
+    machine_id: &str,
+) -> Result<WorkItemCloneResult> {
+    let WorkItemRepositories {
+        repository,
+        work_item_types_repository,
+        number_ranges_repository,
+        relationships_repository,
+        field_values_repository,
+        history_repository,
+        assignees_repository,
+        pool,
+        ..
+    } = repositories;
+    let root = get_work_item::get_stored_work_item(repository, work_item_types_repository, pool.get()?.get_mut(), work_item_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", work_item_id))?;
+    if root.archived_at.is_some() {
+        let name = root.sequential_number.as_deref().unwrap_or(work_item_id);
+        return Err(anyhow::anyhow!("Work item {} is archived and has to be restored before it can be cloned", name));
+    }
+
+    // Walk the subtree by depth so every parent is seen before its children. An item with
+    // several parents is cloned once and linked under each cloned parent. Older data can
+    // hold parent cycles; a link that would close one among the copies is left out.
+    let mut source_ids = vec![work_item_id.to_string()];
+    let mut links: Vec<(String, String)> = Vec::new();
+    if options.recursive {
+        let mut included: HashSet<String> = HashSet::from([work_item_id.to_string()]);
+        for row in repository.find_descendants(work_item_id, MAX_HIERARCHY_DEPTH, false)? {
+            let child_id = row.work_item.id.clone().unwrap_or_default();
+            if !included.contains(&row.via_work_item_id) {
+                continue;
+            }
+            if reaches(&links, &child_id, &row.via_work_item_id) {
+                continue;
+            }
+            let link = (row.via_work_item_id, child_id.clone());
+            if !links.contains(&link) {
+                links.push(link);
+            }
+            if included.insert(child_id.clone()) {
+                source_ids.push(child_id);
+            }
+        }
+    }
+
+    let now = Utc::now().to_rfc3339();
+    let mut types: HashMap<String, WorkItemTypeModel> = HashMap::new();
+    let mut copies: Vec<(String, WorkItemTypeModel, WorkItemModel)> = Vec::new();
+    for source_id in &source_ids {
+        let source = if source_id == work_item_id {
+            root.clone()
+        } else {
+            get_work_item::get_stored_work_item(repository, work_item_types_repository, pool.get()?.get_mut(), source_id)?
+                .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", source_id))?
+        };
+
+        if !types.contains_key(&source.type_id) {
+            let work_item_type = match work_item_types_repository
+                .find_by_id(&source.type_id)
+                .context("Failed to find work item type")?
+            {
+                Some(entity) => WorkItemTypeModel::from_entity(entity)
+                    .context("Failed to convert work item type entity to model")?,
+                None => return Err(anyhow::anyhow!("Work item type not found: {}", source.type_id)),
+            };
+            types.insert(source.type_id.clone(), work_item_type);
+        }
+        let work_item_type = types[&source.type_id].clone();
+
+        let copy = copy_work_item(&work_item_type, &source, &options, cloned_by, &now)?;
+        validate_field_values_for_status(&work_item_type, &copy.status, &copy.field_values, None)?;
+        validate_assignments(assignees_repository, &work_item_type, &copy, None)?;
+        copies.push((source_id.clone(), work_item_type, copy));
+    }
+
+    let cloned = pool.get()?.get_mut().transaction(|conn| {
+        let mut cloned = Vec::new();
+        let mut copy_ids: HashMap<String, String> = HashMap::new();
+        for (source_id, work_item_type, copy) in copies.iter_mut() {
+            let (created, _) = insert_work_item(repositories, conn, work_item_type, copy, sequence_prefix, machine_id)?;
+            let copy_id = created.id.clone()
+                .ok_or_else(|| anyhow::anyhow!("Work item was created but has no ID"))?;
+            copy_ids.insert(source_id.clone(), copy_id.clone());
+            cloned.push(ClonedWorkItem {
+                source_work_item_id: source_id.clone(),
+                work_item_id: copy_id,
+                sequential_number: created.sequential_number,
+            });
+        }
+
+        // The copies form a fresh tree of the same types, so the hierarchy rules and
+        // cycle checks the sources passed hold for them as well
+        for (parent_id, child_id) in &links {
+            let relationship = relationships_repository.create(
+                WorkItemRelationship {
+                    id: Some(Ulid::new().to_string()),
+                    project_id: root.project_id.clone(),
+                    source_work_item_id: copy_ids[parent_id].clone(),
+                    target_work_item_id: copy_ids[child_id].clone(),
+                    relationship_type: RelationshipType::Parent.as_str().to_string(),
+                    created_at: now.clone(),
+                    updated_at: None,
+                    created_by: cloned_by.to_string(),
+                    updated_by: None,
+                    is_active: true,
+                },
+                Some(conn),
+            )?;
+            record_relationship_change(
+                history_repository,
+                conn,
+                &relationship,
+                WorkItemChangeType::RelationshipAdded,
+                cloned_by,
+                &now,
+            )?;
+        }
+
+        Ok(cloned)
+    })?;
+
+    let root_copy_id = &cloned[0].work_item_id;
+    let work_item = get_work_item::get_work_item(repository, work_item_types_repository, pool, root_copy_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found after cloning: {}", root_copy_id))?;
+
+    Ok(WorkItemCloneResult { work_item, cloned })
+}
+
+/// Whether `to` can be reached from `from` by following `links` from parent to child
+fn reaches(links: &[(String, String)], from: &str, to: &str) -> bool {
+    let mut pending = vec![from];
+    let mut visited: HashSet<&str> = HashSet::new();
+    while let Some(id) = pending.pop() {
+        if id == to {
+            return true;
+        }
+        if visited.insert(id) {
+            pending.extend(links.iter().filter(|(parent, _)| parent == id).map(|(_, child)| child.as_str()));
+        }
+    }
+    false
+}
+
+/// Build the new work item for one source, ready to be inserted
+fn copy_work_item(
+    work_item_type: &WorkItemTypeModel,
+    source: &WorkItemModel,
+    options: &WorkItemCloneOptions,
+    cloned_by: &str,
+    now: &str,
+) -> Result<WorkItemModel> {
+    let status = if options.reset_status {
+        resolve_initial_status(work_item_type, "")?
+    } else {
+        validate_status(work_item_type, &source.status)?;
+        source.status.clone()
+    };
+
+    let field_values = source.field_values.iter()
+        .filter(|fv| fv.is_assignment_field || !work_item_type.is_computed_field(&fv.field_id))
+        .filter(|fv| !(options.clear_assignees && fv.is_assignment_field))
+        .map(|fv| WorkItemFieldValueModel {
+            id: None,
+            project_id: source.project_id.clone(),
+            work_item_id: String::new(),
+            field_id: fv.field_id.clone(),
+            is_assignment_field: fv.is_assignment_field,
+            value: fv.value.clone(),
+            created_at: now.to_string(),
+            updated_at: None,
+            created_by: cloned_by.to_string(),
+            updated_by: None,
+            is_active: true,
+            field_definition: None,
+        })
+        .collect();
+
+    Ok(WorkItemModel {
+        id: Some(Ulid::new().to_string()),
+        title: source.title.clone(),
+        description: source.description.clone(),
+        status,
+        created_at: now.to_string(),
+        updated_at: None,
+        priority: source.priority,
+        created_by: cloned_by.to_string(),
+        assigned_to: if options.clear_assignees { None } else { source.assigned_to.clone() },
+        project_id: source.project_id.clone(),
+        type_id: source.type_id.clone(),
+        sequential_number: None,
+        updated_by: None,
+        archived_at: None,
+        archived_by: None,
+        field_values,
+    })
+}
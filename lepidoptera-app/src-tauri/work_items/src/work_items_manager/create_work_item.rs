@@ -1,27 +1,25 @@
-use crate::models::{WorkItemModel, WorkItemTypeModel};
-use crate::repository::WorkItemsRepository;
-use crate::work_item_types_repository::WorkItemTypesRepository;
-use crate::entities::WorkItemFieldValue;
-use crate::work_items_manager::{validate_field_value::validate_field_value, number_range_manager::NumberRangeManager};
-use crate::work_item_number_ranges_repository::WorkItemNumberRangesRepository;
-use db::repository_base::GenericRepository;
-use std::sync::{Arc};
+use crate::entities::WorkItem;
+use crate::models::{WorkItemModel, WorkItemTypeModel, WorkItemChangeType, WorkItemFieldValueModel};
+use crate::work_items_manager::{
+    work_item_repositories::WorkItemRepositories,
+    validate_field_value::validate_field_values_for_status, number_range_manager::NumberRangeManager,
+    status_workflow::resolve_initial_status, work_item_history::HistoryRecorder,
+    field_value_projection::apply_projection, validate_assignment::validate_assignments,
+    work_item_defaults::apply_defaults,
+};
 use anyhow::{Result, Context};
 use ulid::Ulid;
 use chrono::Utc;
-use db::connection_pool::ConnectionPool;
+use db::Connection;
 
 pub fn create_work_item(
-    repository: &Arc<dyn WorkItemsRepository>,
-    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
-    number_ranges_repository: &Arc<dyn WorkItemNumberRangesRepository>,
-    pool: &Arc<ConnectionPool>,
+    repositories: WorkItemRepositories,
     mut work_item: WorkItemModel,
     sequence_prefix: &str,
     machine_id: &str,
 ) -> Result<WorkItemModel> {
     // Get the work item type to access field definitions
-    let work_item_type_entity = work_item_types_repository
+    let work_item_type_entity = repositories.work_item_types_repository
         .find_by_id(&work_item.type_id)
         .context("Failed to find work item type")?;
     
@@ -31,35 +29,14 @@ pub fn create_work_item(
         None => return Err(anyhow::anyhow!("Work item type not found: {}", work_item.type_id)),
     };
 
-    // Validate all field values before creating the work item
-    for field_value in &work_item.field_values {
-        if field_value.is_assignment_field {
-            // Assignment fields don't have validation rules in the same way
-            // They're validated against the assignment_field_definitions
-            // For now, we'll skip validation for assignment fields
-            // TODO: Add validation for assignment fields if needed
-            continue;
-        }
-
-        // Find the matching field definition
-        let field_def = work_item_type.work_item_fields
-            .iter()
-            .find(|field| field.id == field_value.field_id);
+    // Start new work items in the workflow's initial status, then fill in omitted priority
+    // and field values from the type's defaults
+    work_item.status = resolve_initial_status(&work_item_type, &work_item.status)?;
+    apply_defaults(&work_item_type, &mut work_item, Utc::now());
 
-        match field_def {
-            Some(field) => {
-                validate_field_value(field, &field_value.value)
-                    .map_err(|e| anyhow::anyhow!("{}", e))?;
-            }
-            None => {
-                return Err(anyhow::anyhow!(
-                    "Field '{}' is not defined in work item type '{}'",
-                    field_value.field_id,
-                    work_item.type_id
-                ));
-            }
-        }
-    }
+    // Validate all field values before creating the work item
+    validate_field_values_for_status(&work_item_type, &work_item.status, &work_item.field_values, None)?;
+    validate_assignments(repositories.assignees_repository, &work_item_type, &work_item, None)?;
 
     // Generate ULID and timestamp for work item if not provided
     if work_item.id.is_none() {
@@ -69,15 +46,36 @@ pub fn create_work_item(
         work_item.created_at = Utc::now().to_rfc3339();
     }
 
+    // Claim the number, insert the work item and insert its field values as a single
+    // unit of work so a failure part-way through leaves nothing behind
+    let (created_entity, created_field_values) = repositories.pool.get()?.get_mut().transaction(|conn| {
+        insert_work_item(repositories, conn, &work_item_type, &mut work_item, sequence_prefix, machine_id)
+    })?;
+
+    // Create the hydrated work item model with field values
+    Ok(WorkItemModel::from_entity_with_field_values(
+        created_entity,
+        created_field_values,
+    ))
+}
+
+/// Insert a prepared work item with its sequential number, creation history and field
+/// values on the caller's connection
+pub fn insert_work_item(
+    repositories: WorkItemRepositories,
+    conn: &mut Connection,
+    work_item_type: &WorkItemTypeModel,
+    work_item: &mut WorkItemModel,
+    sequence_prefix: &str,
+    machine_id: &str,
+) -> Result<(WorkItem, Vec<WorkItemFieldValueModel>)> {
     // Generate sequential number if not provided
     if work_item.sequential_number.is_none() {
-        let number_range_manager = NumberRangeManager::new(
-            number_ranges_repository.clone(),
-            pool.clone(),
-        );
+        let number_range_manager = NumberRangeManager::new(repositories.number_ranges_repository.clone());
         let sequential_number_raw = number_range_manager.get_next_number(
             &work_item.project_id,
             machine_id,
+            conn,
         )?;
         // Format as <sequence_prefix>-<number> where number has at least four digits
         let formatted_number = format!("{}-{:04}", sequence_prefix, sequential_number_raw);
@@ -88,14 +86,22 @@ pub fn create_work_item(
     let entity = work_item.to_entity();
     
     // Create in repository
-    let created_entity = repository.create(entity)?;
+    let created_entity = repositories.repository.create(entity, Some(conn))?;
     
     // Get the created work item ID
-    let work_item_id = created_entity.id.as_ref()
+    let work_item_id = created_entity.id.clone()
         .ok_or_else(|| anyhow::anyhow!("Work item was created but has no ID"))?;
 
+    HistoryRecorder::new(
+        repositories.history_repository,
+        &work_item.project_id,
+        &work_item_id,
+        &work_item.created_by,
+        &work_item.created_at,
+    )
+    .record(conn, WorkItemChangeType::Created, None, None, created_entity.sequential_number.clone())?;
+
     // Create field values
-    let field_value_repository = GenericRepository::<WorkItemFieldValue>::new(pool.clone());
     let mut created_field_values = Vec::new();
 
     for field_value_model in &work_item.field_values {
@@ -115,21 +121,17 @@ pub fn create_work_item(
             field_value_entity.created_by = work_item.created_by.clone();
         }
         field_value_entity.is_active = true;
+        apply_projection(work_item_type, &mut field_value_entity);
 
         // Create the field value entity
-        let created_field_value_entity = field_value_repository.create(field_value_entity, None)
+        let created_field_value_entity = repositories.field_values_repository.create(field_value_entity, Some(conn))
             .context("Failed to create work item field value")?;
         
         // Convert back to model (we'll need to add the field definition later if needed)
-        created_field_values.push(crate::models::WorkItemFieldValueModel::from_entity(
+        created_field_values.push(WorkItemFieldValueModel::from_entity(
             created_field_value_entity
         ));
     }
 
-    // Create the hydrated work item model with field values
-    Ok(WorkItemModel::from_entity_with_field_values(
-        created_entity,
-        created_field_values,
-    ))
+    Ok((created_entity, created_field_values))
 }
-
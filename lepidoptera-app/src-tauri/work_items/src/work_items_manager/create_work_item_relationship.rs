@@ -1,16 +1,33 @@
 use std::sync::Arc;
-use crate::models::WorkItemRelationshipModel;
+use db::connection_pool::ConnectionPool;
+use crate::models::{WorkItemRelationshipModel, WorkItemChangeType};
+use crate::repository::WorkItemsRepository;
+use crate::work_item_types_repository::WorkItemTypesRepository;
 use crate::work_item_relationships_repository::WorkItemRelationshipsRepository;
+use crate::work_item_history_repository::WorkItemHistoryRepository;
+use crate::work_items_manager::{
+    validate_hierarchy::validate_hierarchy, relationship_cycles::validate_no_cycle,
+    work_item_history::record_relationship_change,
+};
 use anyhow::Result;
 use chrono::Utc;
 use ulid::Ulid;
 
 /// Create a new work item relationship
+///
+/// Parent/child relationships must be allowed by the parent's work item type, and
+/// parent/child and blocks relationships may not close a cycle.
 pub fn create_work_item_relationship(
     repository: &Arc<dyn WorkItemRelationshipsRepository>,
+    work_items_repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    history_repository: &Arc<dyn WorkItemHistoryRepository>,
+    pool: &Arc<ConnectionPool>,
     relationship: WorkItemRelationshipModel,
     created_by: &str,
 ) -> Result<WorkItemRelationshipModel> {
+    validate_hierarchy(work_items_repository, work_item_types_repository, &relationship)?;
+
     let mut entity = relationship.to_entity();
     
     // Generate ID if not provided
@@ -20,12 +37,27 @@ pub fn create_work_item_relationship(
     
     // Set timestamps
     let now = Utc::now().to_rfc3339();
-    entity.created_at = now;
+    entity.created_at = now.clone();
     entity.created_by = created_by.to_string();
     entity.updated_by = None; // New relationships don't have an updated_by
     entity.is_active = true;
     
-    let created = repository.create(entity)?;
+    let created = pool.get()?.get_mut().transaction(|conn| {
+        // Checked inside the transaction so concurrent writers can't close a cycle together
+        let existing = repository.find_by_project_id(&entity.project_id, Some(conn))?;
+        validate_no_cycle(&existing, &entity)?;
+
+        let created = repository.create(entity, Some(conn))?;
+        record_relationship_change(
+            history_repository,
+            conn,
+            &created,
+            WorkItemChangeType::RelationshipAdded,
+            created_by,
+            &now,
+        )?;
+        Ok(created)
+    })?;
     WorkItemRelationshipModel::from_entity(created)
 }
 
@@ -1,13 +1,16 @@
 use std::sync::Arc;
 use crate::models::WorkItemTypeModel;
 use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_items_manager::validate_work_item_type::validate_work_item_type;
 use anyhow::Result;
+use db::Connection;
 use ulid::Ulid;
 use chrono::Utc;
 
 pub fn create_work_item_type(
     repository: &Arc<dyn WorkItemTypesRepository>,
     mut work_item_type: WorkItemTypeModel,
+    conn: Option<&mut Connection>,
 ) -> Result<WorkItemTypeModel> {
     // Generate ULID if not provided
     if work_item_type.id.is_none() {
@@ -19,11 +22,13 @@ pub fn create_work_item_type(
         work_item_type.created_at = Utc::now().to_rfc3339();
     }
     
+    validate_work_item_type(&work_item_type)?;
+
     // Convert model to entity (this validates JSON fields during serialization)
     let entity = work_item_type.to_entity()?;
     
     // Create in repository
-    let created_entity = repository.create(entity)?;
+    let created_entity = repository.create(entity, conn)?;
     
     // Convert back to model
     WorkItemTypeModel::from_entity(created_entity)
@@ -1,13 +1,38 @@
 use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
+use crate::models::WorkItemChangeType;
 use crate::work_item_relationships_repository::WorkItemRelationshipsRepository;
+use crate::work_item_history_repository::WorkItemHistoryRepository;
+use crate::work_items_manager::work_item_history::record_relationship_change;
 use anyhow::Result;
+use chrono::Utc;
 
 /// Delete a work item relationship (soft delete - marks as inactive)
 pub fn delete_work_item_relationship(
     repository: &Arc<dyn WorkItemRelationshipsRepository>,
+    history_repository: &Arc<dyn WorkItemHistoryRepository>,
+    pool: &Arc<ConnectionPool>,
     relationship_id: &str,
+    deleted_by: &str,
 ) -> Result<()> {
-    repository.mark_inactive(relationship_id)
+    let relationship = repository.find_by_id(relationship_id)?
+        .ok_or_else(|| anyhow::anyhow!("Relationship not found: {}", relationship_id))?;
+    let now = Utc::now().to_rfc3339();
+
+    pool.get()?.get_mut().transaction(|conn| {
+        repository.mark_inactive(relationship_id, Some(conn))?;
+        if relationship.is_active {
+            record_relationship_change(
+                history_repository,
+                conn,
+                &relationship,
+                WorkItemChangeType::RelationshipRemoved,
+                deleted_by,
+                &now,
+            )?;
+        }
+        Ok(())
+    })
 }
 
 /// Hard delete a work item relationship
@@ -17,4 +42,3 @@ pub fn hard_delete_work_item_relationship(
 ) -> Result<()> {
     repository.delete(relationship_id)
 }
-
@@ -0,0 +1,307 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use db::Connection;
+use db::connection_pool::ConnectionPool;
+use crate::entities::{WorkItem, WorkItemFieldValue};
+use crate::models::{
+    WorkItemModel, WorkItemTypeModel, WorkItemFieldValueModel, WorkItemTypeImpact, WorkItemTypeMigration,
+    RemovedDefinitionUsage,
+};
+use crate::repository::WorkItemsRepository;
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_item_field_values_repository::WorkItemFieldValuesRepository;
+use crate::work_items_manager::{
+    update_work_item_type::update_work_item_type, update_work_item::write_work_item_update,
+    validate_field_value::validate_field_values, work_item_repositories::WorkItemRepositories,
+};
+use anyhow::{Result, Context};
+use chrono::Utc;
+
+/// Work out which work items an update to their type would leave pointing at removed
+/// statuses or fields
+pub fn analyze_work_item_type_update(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    field_values_repository: &Arc<dyn WorkItemFieldValuesRepository>,
+    pool: &Arc<ConnectionPool>,
+    work_item_type: &WorkItemTypeModel,
+) -> Result<WorkItemTypeImpact> {
+    let current = load_current(work_item_types_repository, work_item_type)?;
+    let mut pooled_conn = pool.get()?;
+    let usage = TypeUsage::load(repository, field_values_repository, pooled_conn.get_mut(), &current)?;
+    Ok(usage.impact(&current, work_item_type))
+}
+
+/// Update a work item type and migrate its work items in a single transaction.
+///
+/// Work items using a removed status or field are moved to the mapped status or field in
+/// `migration`, or left as they are if it is orphaned. The update is rejected if a removed
+/// status or field that is still in use is neither mapped nor orphaned.
+pub fn update_work_item_type_with_migration(
+    repositories: WorkItemRepositories,
+    work_item_type: WorkItemTypeModel,
+    migration: WorkItemTypeMigration,
+    updated_by: &str,
+) -> Result<WorkItemTypeModel> {
+    let WorkItemRepositories { repository, work_item_types_repository, field_values_repository, pool, .. } = repositories;
+    let current = load_current(work_item_types_repository, &work_item_type)?;
+    validate_migration_targets(&work_item_type, &migration)?;
+
+    let now = Utc::now().to_rfc3339();
+
+    pool.get()?.get_mut().transaction(|conn| {
+        let usage = TypeUsage::load(repository, field_values_repository, conn, &current)?;
+        let impact = usage.impact(&current, &work_item_type);
+        check_migration_covers(&work_item_type, &impact, &migration)?;
+
+        let removed = RemovedIds::from_impact(&impact);
+        for (entity, values) in &usage.work_items {
+            let (migrated, moved) = match migrate_work_item(entity, values, &removed, &migration) {
+                Some(migrated) => migrated,
+                None => continue,
+            };
+
+            // Moved values must suit the field they now belong to
+            let label = entity.sequential_number.as_deref()
+                .or(entity.id.as_deref())
+                .unwrap_or_default();
+            validate_field_values(&work_item_type, &moved)
+                .with_context(|| format!("Work item {} can't be migrated", label))?;
+
+            write_work_item_update(repositories, conn, &work_item_type, entity, &migrated, updated_by, &now)?;
+        }
+
+        // Validates the type itself, rolling the migration back if it is invalid
+        update_work_item_type(work_item_types_repository, work_item_type.clone(), Some(conn))
+    })
+}
+
+fn load_current(
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    work_item_type: &WorkItemTypeModel,
+) -> Result<WorkItemTypeModel> {
+    let id = work_item_type.id.as_ref()
+        .ok_or_else(|| anyhow::anyhow!("WorkItemType must have an id to update"))?;
+    match work_item_types_repository
+        .find_by_id(id)
+        .context("Failed to find work item type")?
+    {
+        Some(entity) => WorkItemTypeModel::from_entity(entity)
+            .context("Failed to convert work item type entity to model"),
+        None => Err(anyhow::anyhow!("Work item type not found: {}", id)),
+    }
+}
+
+/// The work items of a type with their active field values
+struct TypeUsage {
+    work_items: Vec<(WorkItem, Vec<WorkItemFieldValue>)>,
+}
+
+impl TypeUsage {
+    fn load(
+        repository: &Arc<dyn WorkItemsRepository>,
+        field_values_repository: &Arc<dyn WorkItemFieldValuesRepository>,
+        conn: &mut Connection,
+        work_item_type: &WorkItemTypeModel,
+    ) -> Result<Self> {
+        let type_id = work_item_type.id.as_deref().unwrap_or_default();
+        let mut values_by_item: HashMap<String, Vec<WorkItemFieldValue>> = HashMap::new();
+        for value in field_values_repository.find_active_by_type_id(type_id, Some(conn))? {
+            values_by_item.entry(value.work_item_id.clone()).or_default().push(value);
+        }
+
+        let work_items = repository.find_by_type_id(type_id, Some(conn))?
+            .into_iter()
+            .map(|entity| {
+                let values = entity.id.as_ref()
+                    .and_then(|id| values_by_item.remove(id))
+                    .unwrap_or_default();
+                (entity, values)
+            })
+            .collect();
+
+        Ok(Self { work_items })
+    }
+
+    fn impact(&self, current: &WorkItemTypeModel, updated: &WorkItemTypeModel) -> WorkItemTypeImpact {
+        let kept_statuses: HashSet<&str> = updated.allowed_statuses.iter().map(|s| s.id.as_str()).collect();
+        let kept_fields: HashSet<&str> = updated.work_item_fields.iter().map(|f| f.id.as_str()).collect();
+        let kept_assignment_fields: HashSet<&str> = updated.assignment_field_definitions.iter().map(|f| f.id.as_str()).collect();
+
+        let removed_statuses = current.allowed_statuses.iter()
+            .filter(|status| !kept_statuses.contains(status.id.as_str()))
+            .map(|status| RemovedDefinitionUsage {
+                id: status.id.clone(),
+                label: status.label.clone(),
+                work_item_count: self.work_items.iter().filter(|(item, _)| item.status == status.id).count(),
+            })
+            .collect();
+        let removed_fields = current.work_item_fields.iter()
+            .filter(|field| !kept_fields.contains(field.id.as_str()))
+            .map(|field| RemovedDefinitionUsage {
+                id: field.id.clone(),
+                label: field.label.clone(),
+                work_item_count: self.count_with_field(&field.id, false),
+            })
+            .collect();
+        let removed_assignment_fields = current.assignment_field_definitions.iter()
+            .filter(|field| !kept_assignment_fields.contains(field.id.as_str()))
+            .map(|field| RemovedDefinitionUsage {
+                id: field.id.clone(),
+                label: field.label.clone(),
+                work_item_count: self.count_with_field(&field.id, true),
+            })
+            .collect();
+
+        WorkItemTypeImpact {
+            work_item_type_id: current.id.clone().unwrap_or_default(),
+            work_item_count: self.work_items.len(),
+            removed_statuses,
+            removed_fields,
+            removed_assignment_fields,
+        }
+    }
+
+    fn count_with_field(&self, field_id: &str, is_assignment_field: bool) -> usize {
+        self.work_items.iter()
+            .filter(|(_, values)| values.iter()
+                .any(|v| v.field_id == field_id && v.is_assignment_field == is_assignment_field))
+            .count()
+    }
+}
+
+/// Removed statuses and fields that are still in use
+struct RemovedIds {
+    statuses: HashSet<String>,
+    fields: HashSet<String>,
+    assignment_fields: HashSet<String>,
+}
+
+impl RemovedIds {
+    fn from_impact(impact: &WorkItemTypeImpact) -> Self {
+        let in_use = |usages: &[RemovedDefinitionUsage]| usages.iter()
+            .filter(|usage| usage.work_item_count > 0)
+            .map(|usage| usage.id.clone())
+            .collect();
+        Self {
+            statuses: in_use(&impact.removed_statuses),
+            fields: in_use(&impact.removed_fields),
+            assignment_fields: in_use(&impact.removed_assignment_fields),
+        }
+    }
+}
+
+/// Checks that mapping targets exist in the updated type
+fn validate_migration_targets(work_item_type: &WorkItemTypeModel, migration: &WorkItemTypeMigration) -> Result<()> {
+    if let Some(status) = migration.status_mapping.values()
+        .find(|status| !work_item_type.allowed_statuses.iter().any(|s| &s.id == *status))
+    {
+        return Err(anyhow::anyhow!("Work item type '{}' has no status '{}'", work_item_type.name, status));
+    }
+    if let Some(field) = migration.field_mapping.values()
+        .find(|field| !work_item_type.work_item_fields.iter().any(|f| &f.id == *field))
+    {
+        return Err(anyhow::anyhow!("Work item type '{}' has no field '{}'", work_item_type.name, field));
+    }
+    if let Some(field) = migration.assignment_field_mapping.values()
+        .find(|field| !work_item_type.assignment_field_definitions.iter().any(|f| &f.id == *field))
+    {
+        return Err(anyhow::anyhow!("Work item type '{}' has no assignment field '{}'", work_item_type.name, field));
+    }
+
+    for mapping in [&migration.field_mapping, &migration.assignment_field_mapping] {
+        let mut targets = HashSet::new();
+        if let Some(field) = mapping.values().find(|field| !targets.insert(*field)) {
+            return Err(anyhow::anyhow!("More than one removed field maps to field '{}'", field));
+        }
+    }
+    Ok(())
+}
+
+/// Rejects the update if a removed status or field still in use is neither mapped nor orphaned
+fn check_migration_covers(
+    work_item_type: &WorkItemTypeModel,
+    impact: &WorkItemTypeImpact,
+    migration: &WorkItemTypeMigration,
+) -> Result<()> {
+    let mut uncovered = Vec::new();
+    let mut collect = |kind: &str, usages: &[RemovedDefinitionUsage], mapping: &HashMap<String, String>, orphaned: &[String]| {
+        for usage in usages.iter().filter(|usage| usage.work_item_count > 0) {
+            if !mapping.contains_key(&usage.id) && !orphaned.contains(&usage.id) {
+                uncovered.push(format!("{} '{}' ({} work items)", kind, usage.id, usage.work_item_count));
+            }
+        }
+    };
+    collect("status", &impact.removed_statuses, &migration.status_mapping, &migration.orphaned_statuses);
+    collect("field", &impact.removed_fields, &migration.field_mapping, &migration.orphaned_fields);
+    collect(
+        "assignment field",
+        &impact.removed_assignment_fields,
+        &migration.assignment_field_mapping,
+        &migration.orphaned_assignment_fields,
+    );
+
+    if uncovered.is_empty() {
+        return Ok(());
+    }
+    Err(anyhow::anyhow!(
+        "Updating work item type '{}' removes {} that are still in use; map them to the updated type or orphan them",
+        work_item_type.name,
+        uncovered.join(", ")
+    ))
+}
+
+/// Apply the migration to one work item.
+///
+/// Returns the migrated model and the values moved to another field, or `None` if the
+/// work item doesn't need to change.
+fn migrate_work_item(
+    entity: &WorkItem,
+    values: &[WorkItemFieldValue],
+    removed: &RemovedIds,
+    migration: &WorkItemTypeMigration,
+) -> Option<(WorkItemModel, Vec<WorkItemFieldValueModel>)> {
+    let mut changed = false;
+    let mut work_item = WorkItemModel::from_entity(entity.clone());
+    let mut moved = Vec::new();
+
+    if removed.statuses.contains(&work_item.status) {
+        if let Some(status) = migration.status_mapping.get(&work_item.status) {
+            work_item.status = status.clone();
+            changed = true;
+        }
+    }
+
+    let mut taken: HashSet<(String, bool)> = values.iter()
+        .map(|v| (v.field_id.clone(), v.is_assignment_field))
+        .collect();
+    for value in values {
+        let (removed_ids, mapping) = if value.is_assignment_field {
+            (&removed.assignment_fields, &migration.assignment_field_mapping)
+        } else {
+            (&removed.fields, &migration.field_mapping)
+        };
+
+        if !removed_ids.contains(&value.field_id) {
+            work_item.field_values.push(WorkItemFieldValueModel::from_entity(value.clone()));
+            continue;
+        }
+
+        // Orphaned values, and mapped values whose target is already set, are deactivated
+        changed = true;
+        if let Some(target) = mapping.get(&value.field_id) {
+            if taken.insert((target.clone(), value.is_assignment_field)) {
+                let mut moved_value = WorkItemFieldValueModel::from_entity(value.clone());
+                moved_value.id = None;
+                moved_value.field_id = target.clone();
+                moved.push(moved_value);
+            }
+        }
+    }
+
+    if !changed {
+        return None;
+    }
+    work_item.field_values.extend(moved.iter().cloned());
+    Some((work_item, moved))
+}
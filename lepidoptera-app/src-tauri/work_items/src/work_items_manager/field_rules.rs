@@ -0,0 +1,132 @@
+use crate::models::{WorkItemTypeModel, WorkItemFieldValueModel, FieldState};
+use crate::schemas::{FieldCondition, ConditionOperator, WorkItemField};
+use crate::work_items_manager::validate_field_value::parse_multi_select;
+use anyhow::Result;
+
+/// Validates the conditional rules of a work item type's fields: the statuses and fields
+/// they refer to must exist and a field's rules can't depend on the field itself
+pub fn validate_field_conditions(work_item_type: &WorkItemTypeModel) -> Result<()> {
+    for field in &work_item_type.work_item_fields {
+        if field.is_computed() && field.required_when.is_some() {
+            return Err(anyhow::anyhow!("Computed field '{}' can't be required", field.label));
+        }
+        if let Some(condition) = &field.required_when {
+            check_condition(work_item_type, field, condition)?;
+        }
+        if let Some(condition) = &field.visible_when {
+            check_condition(work_item_type, field, condition)?;
+        }
+    }
+    Ok(())
+}
+
+fn check_condition(work_item_type: &WorkItemTypeModel, field: &WorkItemField, condition: &FieldCondition) -> Result<()> {
+    match condition {
+        FieldCondition::Status { statuses } => {
+            if let Some(status) = statuses.iter().find(|s| !work_item_type.allowed_statuses.iter().any(|a| &a.id == *s)) {
+                return Err(anyhow::anyhow!(
+                    "Rule of field '{}' refers to unknown status '{}'",
+                    field.label,
+                    status
+                ));
+            }
+        }
+        FieldCondition::StatusCategory { .. } => {}
+        FieldCondition::Field { field_id, is_assignment_field, operator, values } => {
+            let defined = if *is_assignment_field {
+                work_item_type.assignment_field_definitions.iter().any(|f| &f.id == field_id)
+            } else {
+                work_item_type.work_item_fields.iter().any(|f| &f.id == field_id)
+            };
+            if !defined {
+                return Err(anyhow::anyhow!("Rule of field '{}' refers to unknown field '{}'", field.label, field_id));
+            }
+            if !*is_assignment_field && field_id == &field.id {
+                return Err(anyhow::anyhow!("Rule of field '{}' can't depend on the field itself", field.label));
+            }
+            if matches!(operator, ConditionOperator::In | ConditionOperator::NotIn) && values.is_empty() {
+                return Err(anyhow::anyhow!("Rule of field '{}' compares '{}' with no values", field.label, field_id));
+            }
+        }
+        FieldCondition::All { conditions } | FieldCondition::Any { conditions } => {
+            for condition in conditions {
+                check_condition(work_item_type, field, condition)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Whether `condition` holds for a work item with this status and these field values
+pub fn condition_holds(
+    work_item_type: &WorkItemTypeModel,
+    condition: &FieldCondition,
+    status: &str,
+    field_values: &[WorkItemFieldValueModel],
+) -> bool {
+    match condition {
+        FieldCondition::Status { statuses } => statuses.iter().any(|s| s == status),
+        FieldCondition::StatusCategory { categories } => work_item_type
+            .status_category(status)
+            .is_some_and(|category| categories.contains(&category)),
+        FieldCondition::Field { field_id, is_assignment_field, operator, values } => {
+            let value = field_values.iter()
+                .find(|fv| &fv.field_id == field_id && fv.is_assignment_field == *is_assignment_field)
+                .map(|fv| fv.value.trim())
+                .unwrap_or_default();
+            // A multi-select value matches when any of its options does
+            let multi_select = !*is_assignment_field && work_item_type.work_item_fields.iter()
+                .any(|f| &f.id == field_id && f.field_type == "multi_select");
+            let selected = if multi_select {
+                parse_multi_select(value).unwrap_or_default()
+            } else if value.is_empty() {
+                Vec::new()
+            } else {
+                vec![value.to_string()]
+            };
+            let matches = selected.iter().any(|v| values.contains(v));
+            match operator {
+                ConditionOperator::In => matches,
+                ConditionOperator::NotIn => !matches,
+                ConditionOperator::IsEmpty => selected.is_empty(),
+                ConditionOperator::IsNotEmpty => !selected.is_empty(),
+            }
+        }
+        FieldCondition::All { conditions } => conditions.iter()
+            .all(|c| condition_holds(work_item_type, c, status, field_values)),
+        FieldCondition::Any { conditions } => conditions.iter()
+            .any(|c| condition_holds(work_item_type, c, status, field_values)),
+    }
+}
+
+/// Whether a field is shown for a work item with this status and these field values
+pub fn is_visible(
+    work_item_type: &WorkItemTypeModel,
+    field: &WorkItemField,
+    status: &str,
+    field_values: &[WorkItemFieldValueModel],
+) -> bool {
+    field.visible_when.as_ref()
+        .is_none_or(|condition| condition_holds(work_item_type, condition, status, field_values))
+}
+
+/// The visibility and required-ness of every custom field of `work_item_type` for a work
+/// item with this status and these field values
+pub fn evaluate_field_states(
+    work_item_type: &WorkItemTypeModel,
+    status: &str,
+    field_values: &[WorkItemFieldValueModel],
+) -> Vec<FieldState> {
+    work_item_type.work_item_fields.iter()
+        .map(|field| {
+            let visible = is_visible(work_item_type, field, status, field_values);
+            let required_when = field.required_when.as_ref()
+                .is_some_and(|condition| condition_holds(work_item_type, condition, status, field_values));
+            FieldState {
+                field_id: field.id.clone(),
+                visible,
+                required: visible && (field.required || required_when),
+            }
+        })
+        .collect()
+}
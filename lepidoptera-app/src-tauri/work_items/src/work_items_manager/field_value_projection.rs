@@ -0,0 +1,71 @@
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use crate::entities::WorkItemFieldValue;
+use crate::models::WorkItemTypeModel;
+
+/// Format of the `value_date` projection. It sorts and compares correctly as text.
+const DATE_PROJECTION_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+/// How a field's values are stored, compared and sorted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldValueKind {
+    Number,
+    Date,
+    Text,
+}
+
+impl FieldValueKind {
+    pub fn from_field_type(field_type: &str) -> Self {
+        match field_type {
+            "number" | "integer" | "currency" | "duration" | "rollup" => FieldValueKind::Number,
+            "date" | "datetime" => FieldValueKind::Date,
+            _ => FieldValueKind::Text,
+        }
+    }
+
+    /// The kind of a field in a work item type. Assignment fields and unknown fields are text.
+    pub fn of_field(work_item_type: &WorkItemTypeModel, field_id: &str, is_assignment_field: bool) -> Self {
+        if is_assignment_field {
+            return FieldValueKind::Text;
+        }
+        work_item_type.work_item_fields
+            .iter()
+            .find(|field| field.id == field_id)
+            .map(|field| FieldValueKind::from_field_type(&field.field_type))
+            .unwrap_or(FieldValueKind::Text)
+    }
+}
+
+/// Sets the typed projections of a field value from its field's type
+pub fn apply_projection(work_item_type: &WorkItemTypeModel, field_value: &mut WorkItemFieldValue) {
+    let kind = FieldValueKind::of_field(work_item_type, &field_value.field_id, field_value.is_assignment_field);
+    field_value.value_number = match kind {
+        FieldValueKind::Number => parse_number(&field_value.value),
+        _ => None,
+    };
+    field_value.value_date = match kind {
+        FieldValueKind::Date => normalize_date(&field_value.value),
+        _ => None,
+    };
+}
+
+/// Parses a number field value
+pub fn parse_number(value: &str) -> Option<f64> {
+    value.trim().parse::<f64>().ok().filter(|number| number.is_finite())
+}
+
+/// Normalizes a date or datetime field value to UTC `YYYY-MM-DDTHH:MM:SSZ`.
+///
+/// Accepts the same formats as date field validation: RFC 3339, `YYYY-MM-DD HH:MM:SS`
+/// and `YYYY-MM-DD`. Values without an offset are taken as UTC.
+pub fn normalize_date(value: &str) -> Option<String> {
+    let value = value.trim();
+    let date_time = DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").map(|dt| dt.and_utc()))
+        .or_else(|_| {
+            NaiveDate::parse_from_str(value, "%Y-%m-%d")
+                .map(|d| d.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc())
+        })
+        .ok()?;
+    Some(date_time.format(DATE_PROJECTION_FORMAT).to_string())
+}
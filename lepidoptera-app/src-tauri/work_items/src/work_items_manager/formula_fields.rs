@@ -0,0 +1,822 @@
+//! Formula fields: a small expression language over a work item's own values.
+//!
+//! A formula combines numbers, the item's number and date fields, `now`, `today`,
+//! `created_at`, `updated_at` and `priority` using `+ - * /`, parentheses and the functions
+//! `round(x)`, `round(x, digits)`, `abs(x)`, `min(a, b, ...)` and `max(a, b, ...)`, e.g.
+//! `impact * likelihood` or `round(now - created_at)`. Fields are referenced by id; ids that
+//! aren't plain identifiers are written in brackets, e.g. `[estimated-hours] * 1.2`.
+//!
+//! Subtracting two dates gives the days between them and adding days to a date gives a date.
+//! Formulas are type-checked when their work item type is saved and evaluated on read. A
+//! formula that refers to an empty value, or divides by zero, has no value.
+
+use std::collections::{HashMap, HashSet};
+use chrono::{DateTime, Duration, SecondsFormat, Timelike, Utc};
+use crate::errors::FormulaError;
+use crate::models::{WorkItemTypeModel, WorkItemFieldValueModel, FieldDefinition};
+use crate::schemas::WorkItemField;
+use crate::work_items_manager::field_value_projection::{normalize_date, parse_number};
+use crate::work_items_manager::rollup_fields::format_computed_number;
+
+/// The type of a formula or of one of its parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FormulaType {
+    Number,
+    Date,
+}
+
+impl FormulaType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            FormulaType::Number => "a number",
+            FormulaType::Date => "a date",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+}
+
+impl Operator {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Operator::Add => "+",
+            Operator::Subtract => "-",
+            Operator::Multiply => "*",
+            Operator::Divide => "/",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Builtin {
+    Now,
+    Today,
+    CreatedAt,
+    UpdatedAt,
+    Priority,
+}
+
+impl Builtin {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "now" => Some(Builtin::Now),
+            "today" => Some(Builtin::Today),
+            "created_at" => Some(Builtin::CreatedAt),
+            "updated_at" => Some(Builtin::UpdatedAt),
+            "priority" => Some(Builtin::Priority),
+            _ => None,
+        }
+    }
+
+    fn value_type(&self) -> FormulaType {
+        match self {
+            Builtin::Priority => FormulaType::Number,
+            _ => FormulaType::Date,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum ExprKind {
+    Number(f64),
+    Field(String),
+    Builtin(Builtin),
+    Negate(Box<Expr>),
+    Binary(Operator, Box<Expr>, Box<Expr>),
+    Call(String, Vec<Expr>),
+}
+
+/// A parsed formula, with the character position each part starts at for error reporting
+#[derive(Debug, Clone)]
+struct Expr {
+    kind: ExprKind,
+    position: usize,
+    length: usize,
+}
+
+/// An error at a position in the formula, before it's tied to a field
+struct Located {
+    message: String,
+    position: usize,
+    length: usize,
+}
+
+impl Located {
+    fn at(expr: &Expr, message: String) -> Self {
+        Self { message, position: expr.position, length: expr.length }
+    }
+
+    fn for_field(self, field: &WorkItemField) -> FormulaError {
+        FormulaError {
+            field_id: field.id.clone(),
+            field_label: field.label.clone(),
+            message: self.message,
+            position: self.position,
+            length: self.length,
+        }
+    }
+}
+
+/// Validates the formula fields of a work item type: each must parse, refer only to number,
+/// date, rollup or other formula fields of the type, type-check, and not depend on itself
+pub fn validate_formula_fields(work_item_type: &WorkItemTypeModel) -> anyhow::Result<()> {
+    for field in &work_item_type.work_item_fields {
+        match (field.field_type == "formula", field.formula.as_deref()) {
+            (true, None) => {
+                return Err(anyhow::anyhow!("Formula field '{}' has no formula", field.label));
+            }
+            (false, Some(_)) => {
+                return Err(anyhow::anyhow!(
+                    "Field '{}' has a formula but its type is '{}'",
+                    field.label,
+                    field.field_type
+                ));
+            }
+            (true, Some(_)) => {
+                formula_type(work_item_type, field, &mut Vec::new())?;
+            }
+            (false, None) => {}
+        }
+    }
+    Ok(())
+}
+
+/// The type of a formula field's result, checking every formula it depends on on the way
+fn formula_type(
+    work_item_type: &WorkItemTypeModel,
+    field: &WorkItemField,
+    visiting: &mut Vec<String>,
+) -> Result<FormulaType, FormulaError> {
+    if visiting.contains(&field.id) {
+        visiting.push(field.id.clone());
+        return Err(FormulaError {
+            field_id: field.id.clone(),
+            field_label: field.label.clone(),
+            message: format!("Formula depends on itself: {}", visiting.join(" -> ")),
+            position: 0,
+            length: 0,
+        });
+    }
+
+    let expr = parse_formula(field.formula.as_deref().unwrap_or_default())
+        .map_err(|e| e.for_field(field))?;
+
+    visiting.push(field.id.clone());
+    let result = check(work_item_type, &expr, visiting).map_err(|e| e.for_field(field));
+    visiting.pop();
+    result
+}
+
+fn check(
+    work_item_type: &WorkItemTypeModel,
+    expr: &Expr,
+    visiting: &mut Vec<String>,
+) -> Result<FormulaType, Located> {
+    match &expr.kind {
+        ExprKind::Number(_) => Ok(FormulaType::Number),
+        ExprKind::Builtin(builtin) => Ok(builtin.value_type()),
+        ExprKind::Field(field_id) => {
+            let field = work_item_type.work_item_fields.iter()
+                .find(|f| &f.id == field_id)
+                .ok_or_else(|| Located::at(expr, format!("Unknown field '{}'", field_id)))?;
+            match field.field_type.as_str() {
+                "number" | "integer" | "currency" | "duration" | "rollup" => Ok(FormulaType::Number),
+                "date" | "datetime" => Ok(FormulaType::Date),
+                "formula" => formula_type(work_item_type, field, visiting)
+                    .map_err(|e| Located::at(expr, e.message)),
+                other => Err(Located::at(
+                    expr,
+                    format!("Field '{}' is a {} field; formulas can only use number and date fields", field_id, other),
+                )),
+            }
+        }
+        ExprKind::Negate(operand) => match check(work_item_type, operand, visiting)? {
+            FormulaType::Number => Ok(FormulaType::Number),
+            FormulaType::Date => Err(Located::at(expr, "A date can't be negated".to_string())),
+        },
+        ExprKind::Binary(operator, left, right) => {
+            let left_type = check(work_item_type, left, visiting)?;
+            let right_type = check(work_item_type, right, visiting)?;
+            binary_type(*operator, left_type, right_type).ok_or_else(|| {
+                Located::at(
+                    expr,
+                    format!(
+                        "Can't apply '{}' to {} and {}",
+                        operator.as_str(),
+                        left_type.as_str(),
+                        right_type.as_str()
+                    ),
+                )
+            })
+        }
+        ExprKind::Call(function, arguments) => {
+            let arity_ok = match function.as_str() {
+                "round" => (1..=2).contains(&arguments.len()),
+                "abs" => arguments.len() == 1,
+                "min" | "max" => !arguments.is_empty(),
+                other => return Err(Located::at(expr, format!("Unknown function '{}'", other))),
+            };
+            if !arity_ok {
+                return Err(Located::at(
+                    expr,
+                    format!("Wrong number of arguments for '{}'", function),
+                ));
+            }
+            for argument in arguments {
+                if check(work_item_type, argument, visiting)? != FormulaType::Number {
+                    return Err(Located::at(argument, format!("'{}' takes numbers", function)));
+                }
+            }
+            Ok(FormulaType::Number)
+        }
+    }
+}
+
+fn binary_type(operator: Operator, left: FormulaType, right: FormulaType) -> Option<FormulaType> {
+    use FormulaType::{Date, Number};
+    match (operator, left, right) {
+        (_, Number, Number) => Some(Number),
+        (Operator::Subtract, Date, Date) => Some(Number),
+        (Operator::Add | Operator::Subtract, Date, Number) => Some(Date),
+        (Operator::Add, Number, Date) => Some(Date),
+        _ => None,
+    }
+}
+
+/// The field ids a formula refers to, directly or through other formula fields
+pub fn formula_dependencies(work_item_type: &WorkItemTypeModel, field: &WorkItemField) -> HashSet<String> {
+    let mut dependencies = HashSet::new();
+    let mut pending = vec![field.clone()];
+    while let Some(field) = pending.pop() {
+        let expr = match field.formula.as_deref().map(parse_formula) {
+            Some(Ok(expr)) => expr,
+            _ => continue,
+        };
+        let mut referenced = Vec::new();
+        collect_fields(&expr, &mut referenced);
+        for field_id in referenced {
+            if dependencies.insert(field_id.clone()) {
+                if let Some(dependency) = work_item_type.work_item_fields.iter().find(|f| f.id == field_id) {
+                    pending.push(dependency.clone());
+                }
+            }
+        }
+    }
+    dependencies
+}
+
+fn collect_fields(expr: &Expr, fields: &mut Vec<String>) {
+    match &expr.kind {
+        ExprKind::Field(field_id) => fields.push(field_id.clone()),
+        ExprKind::Negate(operand) => collect_fields(operand, fields),
+        ExprKind::Binary(_, left, right) => {
+            collect_fields(left, fields);
+            collect_fields(right, fields);
+        }
+        ExprKind::Call(_, arguments) => arguments.iter().for_each(|a| collect_fields(a, fields)),
+        ExprKind::Number(_) | ExprKind::Builtin(_) => {}
+    }
+}
+
+/// What formulas are evaluated against: the work item's core columns and field values
+pub struct FormulaInputs<'a> {
+    pub project_id: &'a str,
+    pub work_item_id: &'a str,
+    pub created_at: &'a str,
+    pub updated_at: Option<&'a str>,
+    pub priority: i32,
+    pub field_values: &'a [WorkItemFieldValueModel],
+    pub now: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Value {
+    Number(f64),
+    Date(DateTime<Utc>),
+}
+
+/// Evaluates the formula fields of `work_item_type` for one work item. Only fields in
+/// `field_ids` are returned when it is given; formulas without a value are left out.
+pub fn compute_formulas(
+    work_item_type: &WorkItemTypeModel,
+    inputs: &FormulaInputs,
+    field_ids: Option<&HashSet<String>>,
+) -> Vec<WorkItemFieldValueModel> {
+    let mut evaluator = Evaluator { work_item_type, inputs, results: HashMap::new(), visiting: HashSet::new() };
+
+    work_item_type.work_item_fields.iter()
+        .filter(|field| field.formula.is_some())
+        .filter(|field| field_ids.is_none_or(|ids| ids.contains(&field.id)))
+        .filter_map(|field| {
+            let value = evaluator.field(field)?;
+            Some(WorkItemFieldValueModel {
+                id: None,
+                project_id: inputs.project_id.to_string(),
+                work_item_id: inputs.work_item_id.to_string(),
+                field_id: field.id.clone(),
+                is_assignment_field: false,
+                value: format_value(value),
+                created_at: String::new(),
+                updated_at: None,
+                created_by: String::new(),
+                updated_by: None,
+                is_active: true,
+                field_definition: Some(FieldDefinition::WorkItemField(field.clone())),
+            })
+        })
+        .collect()
+}
+
+struct Evaluator<'a> {
+    work_item_type: &'a WorkItemTypeModel,
+    inputs: &'a FormulaInputs<'a>,
+    results: HashMap<String, Option<Value>>,
+    visiting: HashSet<String>,
+}
+
+impl Evaluator<'_> {
+    fn field(&mut self, field: &WorkItemField) -> Option<Value> {
+        if let Some(result) = self.results.get(&field.id) {
+            return *result;
+        }
+        // Cycles are rejected when the type is saved; this only guards older definitions
+        if !self.visiting.insert(field.id.clone()) {
+            return None;
+        }
+        let result = field.formula.as_deref()
+            .and_then(|formula| parse_formula(formula).ok())
+            .and_then(|expr| self.eval(&expr));
+        self.visiting.remove(&field.id);
+        self.results.insert(field.id.clone(), result);
+        result
+    }
+
+    fn eval(&mut self, expr: &Expr) -> Option<Value> {
+        match &expr.kind {
+            ExprKind::Number(number) => Some(Value::Number(*number)),
+            ExprKind::Builtin(builtin) => self.builtin(*builtin),
+            ExprKind::Field(field_id) => {
+                let field = self.work_item_type.work_item_fields.iter().find(|f| &f.id == field_id)?;
+                if field.formula.is_some() {
+                    return self.field(field);
+                }
+                let value = self.inputs.field_values.iter()
+                    .find(|fv| !fv.is_assignment_field && &fv.field_id == field_id)?;
+                match field.field_type.as_str() {
+                    "date" | "datetime" => parse_date(&value.value).map(Value::Date),
+                    _ => parse_number(&value.value).map(Value::Number),
+                }
+            }
+            ExprKind::Negate(operand) => match self.eval(operand)? {
+                Value::Number(number) => Some(Value::Number(-number)),
+                Value::Date(_) => None,
+            },
+            ExprKind::Binary(operator, left, right) => {
+                let left = self.eval(left)?;
+                let right = self.eval(right)?;
+                binary(*operator, left, right)
+            }
+            ExprKind::Call(function, arguments) => {
+                let numbers = arguments.iter()
+                    .map(|argument| match self.eval(argument)? {
+                        Value::Number(number) => Some(number),
+                        Value::Date(_) => None,
+                    })
+                    .collect::<Option<Vec<f64>>>()?;
+                let result = match function.as_str() {
+                    "round" => round(numbers[0], numbers.get(1).copied().unwrap_or(0.0)),
+                    "abs" => numbers[0].abs(),
+                    "min" => numbers.into_iter().fold(f64::INFINITY, f64::min),
+                    "max" => numbers.into_iter().fold(f64::NEG_INFINITY, f64::max),
+                    _ => return None,
+                };
+                result.is_finite().then_some(Value::Number(result))
+            }
+        }
+    }
+
+    fn builtin(&self, builtin: Builtin) -> Option<Value> {
+        match builtin {
+            Builtin::Now => Some(Value::Date(self.inputs.now)),
+            Builtin::Today => {
+                let midnight = self.inputs.now.date_naive().and_hms_opt(0, 0, 0)?.and_utc();
+                Some(Value::Date(midnight))
+            }
+            Builtin::CreatedAt => parse_date(self.inputs.created_at).map(Value::Date),
+            Builtin::UpdatedAt => parse_date(self.inputs.updated_at?).map(Value::Date),
+            Builtin::Priority => Some(Value::Number(self.inputs.priority as f64)),
+        }
+    }
+}
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+
+/// More digits than an f64 holds make no difference to rounding
+const MAX_ROUND_DIGITS: f64 = 15.0;
+
+fn round(number: f64, digits: f64) -> f64 {
+    let factor = 10f64.powi(digits.clamp(-MAX_ROUND_DIGITS, MAX_ROUND_DIGITS) as i32);
+    let scaled = number * factor;
+    // A number too large to scale has no digits left to round at that precision
+    if scaled.is_finite() { scaled.round() / factor } else { number }
+}
+
+fn binary(operator: Operator, left: Value, right: Value) -> Option<Value> {
+    let result = match (operator, left, right) {
+        (Operator::Add, Value::Number(a), Value::Number(b)) => Value::Number(a + b),
+        (Operator::Subtract, Value::Number(a), Value::Number(b)) => Value::Number(a - b),
+        (Operator::Multiply, Value::Number(a), Value::Number(b)) => Value::Number(a * b),
+        // Dividing by zero gives infinity or NaN, which has no value below
+        (Operator::Divide, Value::Number(a), Value::Number(b)) => Value::Number(a / b),
+        (Operator::Subtract, Value::Date(a), Value::Date(b)) => {
+            Value::Number((a - b).num_seconds() as f64 / SECONDS_PER_DAY)
+        }
+        (Operator::Add, Value::Date(date), Value::Number(days))
+        | (Operator::Add, Value::Number(days), Value::Date(date)) => Value::Date(add_days(date, days)?),
+        (Operator::Subtract, Value::Date(date), Value::Number(days)) => Value::Date(add_days(date, -days)?),
+        _ => return None,
+    };
+    match result {
+        Value::Number(number) if !number.is_finite() => None,
+        result => Some(result),
+    }
+}
+
+fn add_days(date: DateTime<Utc>, days: f64) -> Option<DateTime<Utc>> {
+    date.checked_add_signed(Duration::try_seconds((days * SECONDS_PER_DAY).round() as i64)?)
+}
+
+fn parse_date(value: &str) -> Option<DateTime<Utc>> {
+    let normalized = normalize_date(value)?;
+    DateTime::parse_from_rfc3339(&normalized).ok().map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Dates at midnight are shown as plain dates, other dates as timestamps
+fn format_value(value: Value) -> String {
+    match value {
+        Value::Number(number) => format_computed_number(number),
+        Value::Date(date) if date.num_seconds_from_midnight() == 0 => date.format("%Y-%m-%d").to_string(),
+        Value::Date(date) => date.to_rfc3339_opts(SecondsFormat::Secs, true),
+    }
+}
+
+/// How deeply parentheses, negations and function calls may nest, so that parsing and
+/// evaluating a formula can't run out of stack. Each operator in a chain such as
+/// `a + b + c` nests the chain one level deeper as well.
+const MAX_NESTING_DEPTH: usize = 100;
+
+/// Parses a formula into an expression tree
+fn parse_formula(input: &str) -> Result<Expr, Located> {
+    if input.trim().is_empty() {
+        return Err(Located { message: "Formula is empty".to_string(), position: 0, length: 0 });
+    }
+    let mut parser = Parser { chars: input.chars().collect(), position: 0, depth: 0 };
+    let expr = parser.expression()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        None => Ok(expr),
+        Some(c) => Err(parser.error_here(format!("Unexpected '{}'", c))),
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+    depth: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn error_here(&self, message: String) -> Located {
+        Located { message, position: self.position, length: 1 }
+    }
+
+    fn node(&self, kind: ExprKind, start: usize) -> Expr {
+        Expr { kind, position: start, length: self.position - start }
+    }
+
+    /// Go one level deeper, failing at the current position past `MAX_NESTING_DEPTH`
+    fn deeper(&mut self) -> Result<(), Located> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(self.error_here(format!("Formula is nested more than {} levels deep", MAX_NESTING_DEPTH)));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Parse one level deeper
+    fn nested<T>(&mut self, parse: impl FnOnce(&mut Self) -> Result<T, Located>) -> Result<T, Located> {
+        self.deeper()?;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    /// `term (('+' | '-') term)*`
+    fn expression(&mut self) -> Result<Expr, Located> {
+        self.skip_whitespace();
+        let start = self.position;
+        let mut left = self.term()?;
+        let depth = self.depth;
+        loop {
+            self.skip_whitespace();
+            let operator = match self.peek() {
+                Some('+') => Operator::Add,
+                Some('-') => Operator::Subtract,
+                _ => {
+                    self.depth = depth;
+                    return Ok(left);
+                }
+            };
+            self.deeper()?;
+            self.position += 1;
+            let right = self.term()?;
+            left = self.node(ExprKind::Binary(operator, Box::new(left), Box::new(right)), start);
+        }
+    }
+
+    /// `unary (('*' | '/') unary)*`
+    fn term(&mut self) -> Result<Expr, Located> {
+        self.skip_whitespace();
+        let start = self.position;
+        let mut left = self.unary()?;
+        let depth = self.depth;
+        loop {
+            self.skip_whitespace();
+            let operator = match self.peek() {
+                Some('*') => Operator::Multiply,
+                Some('/') => Operator::Divide,
+                _ => {
+                    self.depth = depth;
+                    return Ok(left);
+                }
+            };
+            self.deeper()?;
+            self.position += 1;
+            let right = self.unary()?;
+            left = self.node(ExprKind::Binary(operator, Box::new(left), Box::new(right)), start);
+        }
+    }
+
+    /// `'-' unary | primary`
+    fn unary(&mut self) -> Result<Expr, Located> {
+        self.skip_whitespace();
+        let start = self.position;
+        if self.peek() == Some('-') {
+            let operand = self.nested(|parser| {
+                parser.position += 1;
+                parser.unary()
+            })?;
+            return Ok(self.node(ExprKind::Negate(Box::new(operand)), start));
+        }
+        self.primary()
+    }
+
+    /// A number, `(expression)`, `[field-id]`, a function call or a name
+    fn primary(&mut self) -> Result<Expr, Located> {
+        self.skip_whitespace();
+        let start = self.position;
+        match self.peek() {
+            Some(c) if c.is_ascii_digit() || c == '.' => {
+                while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+                    self.position += 1;
+                }
+                let text: String = self.chars[start..self.position].iter().collect();
+                let number = text.parse::<f64>()
+                    .map_err(|_| Located { message: format!("Invalid number '{}'", text), position: start, length: text.chars().count() })?;
+                Ok(self.node(ExprKind::Number(number), start))
+            }
+            Some('(') => self.nested(|parser| {
+                parser.position += 1;
+                let inner = parser.expression()?;
+                parser.expect(')')?;
+                Ok(inner)
+            }),
+            Some('[') => {
+                self.position += 1;
+                let name_start = self.position;
+                while self.peek().is_some_and(|c| c != ']') {
+                    self.position += 1;
+                }
+                let field_id: String = self.chars[name_start..self.position].iter().collect::<String>().trim().to_string();
+                self.expect(']')?;
+                if field_id.is_empty() {
+                    return Err(Located { message: "Expected a field id".to_string(), position: start, length: 2 });
+                }
+                Ok(self.node(ExprKind::Field(field_id), start))
+            }
+            Some(c) if c.is_alphabetic() || c == '_' => {
+                while self.peek().is_some_and(|c| c.is_alphanumeric() || c == '_') {
+                    self.position += 1;
+                }
+                let name: String = self.chars[start..self.position].iter().collect();
+                let name_node = self.node(ExprKind::Field(name.clone()), start);
+                self.skip_whitespace();
+                if self.peek() == Some('(') {
+                    let arguments = self.nested(|parser| {
+                        parser.position += 1;
+                        parser.arguments()
+                    })?;
+                    return Ok(self.node(ExprKind::Call(name.to_lowercase(), arguments), start));
+                }
+                match Builtin::from_name(&name) {
+                    Some(builtin) => Ok(Expr { kind: ExprKind::Builtin(builtin), ..name_node }),
+                    None => Ok(name_node),
+                }
+            }
+            Some(c) => Err(self.error_here(format!("Unexpected '{}'", c))),
+            None => Err(Located { message: "Unexpected end of formula".to_string(), position: self.position, length: 0 }),
+        }
+    }
+
+    /// Comma separated arguments up to the closing `)`
+    fn arguments(&mut self) -> Result<Vec<Expr>, Located> {
+        let mut arguments = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(')') {
+            self.position += 1;
+            return Ok(arguments);
+        }
+        loop {
+            arguments.push(self.expression()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(')') => {
+                    self.position += 1;
+                    return Ok(arguments);
+                }
+                _ => return Err(self.error_here("Expected ',' or ')'".to_string())),
+            }
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Located> {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            Ok(())
+        } else {
+            Err(self.error_here(format!("Expected '{}'", expected)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use crate::test_support::{field, field_value, formula_field};
+
+    fn work_item_type(formula: &str) -> WorkItemTypeModel {
+        WorkItemTypeModel {
+            work_item_fields: vec![
+                field("points", "Points", "number"),
+                field("start", "Start", "date"),
+                field("due", "Due", "date"),
+                formula_field("result", formula),
+            ],
+            ..crate::test_support::work_item_type("task")
+        }
+    }
+
+    /// The formatted result of `formula`, after checking that its type validates
+    fn evaluate(formula: &str) -> Option<String> {
+        let work_item_type = work_item_type(formula);
+        validate_formula_fields(&work_item_type).unwrap();
+        let field_values = [
+            field_value("points", "5"),
+            field_value("start", "2024-03-01"),
+            field_value("due", "2024-03-15"),
+        ];
+        let inputs = FormulaInputs {
+            project_id: "project",
+            work_item_id: "item",
+            created_at: "2024-02-28T12:00:00Z",
+            updated_at: None,
+            priority: 2,
+            field_values: &field_values,
+            now: Utc.with_ymd_and_hms(2024, 3, 10, 8, 30, 0).unwrap(),
+        };
+        compute_formulas(&work_item_type, &inputs, None).pop().map(|fv| fv.value)
+    }
+
+    fn validation_error(formula: &str) -> FormulaError {
+        validate_formula_fields(&work_item_type(formula))
+            .unwrap_err()
+            .downcast::<FormulaError>()
+            .unwrap()
+    }
+
+    #[test]
+    fn operators_follow_precedence() {
+        let cases = [
+            ("1 + 2 * 3", "7"),
+            ("(1 + 2) * 3", "9"),
+            ("10 - 4 - 3", "3"),
+            ("12 / 3 / 2", "2"),
+            ("-2 * 3 + 1", "-5"),
+            ("2 * -points", "-10"),
+            ("points / 4", "1.25"),
+            ("max(1, points, priority) - min(3, abs(-1))", "4"),
+        ];
+        for (formula, expected) in cases {
+            assert_eq!(evaluate(formula).as_deref(), Some(expected), "{}", formula);
+        }
+    }
+
+    #[test]
+    fn dates_can_only_be_combined_with_numbers_or_subtracted() {
+        let cases = [
+            ("start + due", "Can't apply '+' to a date and a date", 0, 11),
+            ("points - start", "Can't apply '-' to a number and a date", 0, 14),
+            ("2 * today", "Can't apply '*' to a number and a date", 0, 9),
+            ("-start", "A date can't be negated", 0, 6),
+            ("round(start)", "'round' takes numbers", 6, 5),
+        ];
+        for (formula, message, position, length) in cases {
+            let error = validation_error(formula);
+            assert_eq!(
+                (error.message.as_str(), error.position, error.length),
+                (message, position, length),
+                "{}",
+                formula
+            );
+        }
+    }
+
+    #[test]
+    fn date_arithmetic_works_in_days() {
+        let cases = [
+            ("due - start", "14"),
+            ("start + 7", "2024-03-08"),
+            ("7 + start", "2024-03-08"),
+            ("due - 1.5", "2024-03-13T12:00:00Z"),
+            ("today - start", "9"),
+            ("round(now - created_at, 1)", "10.9"),
+        ];
+        for (formula, expected) in cases {
+            assert_eq!(evaluate(formula).as_deref(), Some(expected), "{}", formula);
+        }
+    }
+
+    #[test]
+    fn non_finite_results_have_no_value() {
+        assert_eq!(evaluate("points / 0"), None);
+        assert_eq!(evaluate("round(points / 0)"), None);
+        assert_eq!(evaluate("0 / (points - 5)"), None);
+    }
+
+    #[test]
+    fn round_clamps_its_digits() {
+        let cases = [
+            ("round(points / 3, 2)", "1.67"),
+            ("round(1234, -2)", "1200"),
+            ("round(points / 4, 400)", "1.25"),
+            ("round(points, -400)", "0"),
+        ];
+        for (formula, expected) in cases {
+            assert_eq!(evaluate(formula).as_deref(), Some(expected), "{}", formula);
+        }
+        assert_eq!(round(1e300, 15.0), 1e300);
+    }
+
+    #[test]
+    fn nesting_is_limited() {
+        let nested = |open: &str, close: &str, depth: usize| format!("{}1{}", open.repeat(depth), close.repeat(depth));
+
+        assert_eq!(evaluate(&nested("(", ")", MAX_NESTING_DEPTH)).as_deref(), Some("1"));
+        assert_eq!(evaluate(&nested("-", "", MAX_NESTING_DEPTH)).as_deref(), Some("1"));
+        assert_eq!(evaluate(&nested("abs(", ")", MAX_NESTING_DEPTH)).as_deref(), Some("1"));
+        assert_eq!(evaluate(&vec!["1"; MAX_NESTING_DEPTH + 1].join(" + ")), Some((MAX_NESTING_DEPTH + 1).to_string()));
+
+        let cases = [
+            (nested("(", ")", 10_000), MAX_NESTING_DEPTH),
+            (nested("-", "", 10_000), MAX_NESTING_DEPTH),
+            (nested("abs(", ")", 10_000), MAX_NESTING_DEPTH * 4 + 3),
+            (nested("-(", ")", 10_000), MAX_NESTING_DEPTH),
+            (vec!["1"; 10_000].join(" + "), MAX_NESTING_DEPTH * 4 + 2),
+            (vec!["points"; 10_000].join("*"), MAX_NESTING_DEPTH * 7 + 6),
+        ];
+        for (formula, position) in cases {
+            let error = validation_error(&formula);
+            assert_eq!(error.message, format!("Formula is nested more than {} levels deep", MAX_NESTING_DEPTH));
+            assert_eq!(error.position, position, "{}", &formula[..8]);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+use crate::models::{FieldState, WorkItemFieldValueModel, WorkItemTypeModel};
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_items_manager::field_rules::evaluate_field_states;
+use anyhow::{Result, Context};
+
+/// Get the visibility and required-ness of a work item type's custom fields for a work
+/// item with this status and these field values
+pub fn get_field_states(
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    work_item_type_id: &str,
+    status: &str,
+    field_values: &[WorkItemFieldValueModel],
+) -> Result<Vec<FieldState>> {
+    let work_item_type = match work_item_types_repository
+        .find_by_id(work_item_type_id)
+        .context("Failed to find work item type")?
+    {
+        Some(entity) => WorkItemTypeModel::from_entity(entity)
+            .context("Failed to convert work item type entity to model")?,
+        None => return Err(anyhow::anyhow!("Work item type not found: {}", work_item_type_id)),
+    };
+
+    Ok(evaluate_field_states(&work_item_type, status, field_values))
+}
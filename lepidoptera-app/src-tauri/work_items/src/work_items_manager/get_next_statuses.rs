@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use crate::models::WorkItemTypeModel;
+use crate::repository::WorkItemsRepository;
+use crate::schemas::AllowedStatus;
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_items_manager::status_workflow::next_statuses;
+use anyhow::{Result, Context};
+
+/// Get the statuses a work item can move to from its current status
+pub fn get_next_statuses(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    work_item_id: &str,
+) -> Result<Vec<AllowedStatus>> {
+    let work_item = repository.find_by_id(work_item_id, None)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", work_item_id))?;
+
+    let work_item_type = match work_item_types_repository
+        .find_by_id(&work_item.type_id)
+        .context("Failed to find work item type")?
+    {
+        Some(entity) => WorkItemTypeModel::from_entity(entity)
+            .context("Failed to convert work item type entity to model")?,
+        None => return Err(anyhow::anyhow!("Work item type not found: {}", work_item.type_id)),
+    };
+
+    Ok(next_statuses(&work_item_type, &work_item.status))
+}
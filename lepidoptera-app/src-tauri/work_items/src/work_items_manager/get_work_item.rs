@@ -1,20 +1,71 @@
 use std::sync::Arc;
+use db::Connection;
 use db::connection_pool::ConnectionPool;
 use crate::models::{WorkItemModel, WorkItemTypeModel, WorkItemFieldValueModel, FieldDefinition};
 use crate::repository::WorkItemsRepository;
 use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_item_number_aliases_repository::WorkItemNumberAliasesRepository;
 use crate::entities::WorkItemFieldValue;
+use crate::work_items_manager::rollup_fields::{RollupContext, compute_rollups};
+use crate::work_items_manager::formula_fields::{FormulaInputs, compute_formulas};
 use anyhow::{Result, Context};
+use chrono::Utc;
 use db::repository_base::Entity;
 
+/// Get a work item with its field values, including the computed ones
 pub fn get_work_item(
     repository: &Arc<dyn WorkItemsRepository>,
     work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
     pool: &Arc<ConnectionPool>,
     id: &str,
+) -> Result<Option<WorkItemModel>> {
+    load_work_item(repository, work_item_types_repository, pool.get()?.get_mut(), id, true)
+}
+
+/// Get a work item by its sequential number, or by a number it had before it moved to
+/// another project. A work item that currently has the number wins over an alias.
+pub fn resolve_work_item_number(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    number_aliases_repository: &Arc<dyn WorkItemNumberAliasesRepository>,
+    pool: &Arc<ConnectionPool>,
+    sequential_number: &str,
+) -> Result<Option<WorkItemModel>> {
+    let work_item_id = match repository.find_by_sequential_number(sequential_number, None)?.into_iter().next() {
+        Some(work_item) => work_item.id,
+        None => number_aliases_repository.find_by_sequential_number(sequential_number, None)?
+            .into_iter()
+            .next()
+            .map(|alias| alias.work_item_id),
+    };
+
+    match work_item_id {
+        Some(id) => get_work_item(repository, work_item_types_repository, pool, &id),
+        None => Ok(None),
+    }
+}
+
+/// Get a work item with only its stored field values, as the starting point for a write.
+/// Read it on the connection of the write's unit of work so it is validated against the
+/// state the write changes.
+pub fn get_stored_work_item(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    conn: &mut Connection,
+    id: &str,
+) -> Result<Option<WorkItemModel>> {
+    load_work_item(repository, work_item_types_repository, conn, id, false)
+}
+
+fn load_work_item(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    conn: &mut Connection,
+    id: &str,
+    include_computed: bool,
 ) -> Result<Option<WorkItemModel>> {
     // Get the work item entity
-    let work_item_entity = match repository.find_by_id(id)? {
+    let work_item_entity = match repository.find_by_id(id, Some(&mut *conn))? {
         Some(entity) => entity,
         None => return Ok(None),
     };
@@ -31,15 +82,12 @@ pub fn get_work_item(
     };
 
     // Query field values for this work item
-    let pooled_conn = pool.get()?;
-    let conn = pooled_conn.get();
-    
     let param: &dyn db::ToSql = &id;
     let params = &[param];
     
     let field_value_entities = conn.query(
         "SELECT id, project_id, work_item_id, field_id, is_assignment_field, value, 
-                created_at, updated_at, created_by, updated_by, is_active
+                created_at, updated_at, created_by, updated_by, is_active, value_number, value_date
          FROM work_item_field_values 
          WHERE work_item_id = ?1 AND is_active = 1",
         params,
@@ -48,7 +96,7 @@ pub fn get_work_item(
     .context("Failed to query work item field values")?;
 
     // Convert field value entities to models with field definitions
-    let field_values: Vec<WorkItemFieldValueModel> = field_value_entities
+    let mut field_values: Vec<WorkItemFieldValueModel> = field_value_entities
         .into_iter()
         .map(|entity| {
             // Find the matching field definition from the work item type
@@ -74,6 +122,32 @@ pub fn get_work_item(
         })
         .collect();
 
+    // Computed fields replace anything stored under their id before the field became computed
+    if include_computed && work_item_type.work_item_fields.iter().any(|field| field.is_computed()) {
+        field_values.retain(|fv| fv.is_assignment_field || !work_item_type.is_computed_field(&fv.field_id));
+        if work_item_type.work_item_fields.iter().any(|field| field.rollup.is_some()) {
+            let context = RollupContext::load(work_item_types_repository, &work_item_entity.project_id)?;
+            let mut rollups = compute_rollups(conn, &context, &work_item_type, &[id.to_string()], None)?;
+            field_values.extend(rollups.remove(id).unwrap_or_default());
+        }
+
+        // Formulas may use rollups, so they are evaluated last
+        let formulas = compute_formulas(
+            &work_item_type,
+            &FormulaInputs {
+                project_id: &work_item_entity.project_id,
+                work_item_id: id,
+                created_at: &work_item_entity.created_at,
+                updated_at: work_item_entity.updated_at.as_deref(),
+                priority: work_item_entity.priority,
+                field_values: &field_values,
+                now: Utc::now(),
+            },
+            None,
+        );
+        field_values.extend(formulas);
+    }
+
     // Create the hydrated work item model
     Ok(Some(WorkItemModel::from_entity_with_field_values(
         work_item_entity,
@@ -0,0 +1,93 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use crate::models::{WorkItemHierarchyEntry, WorkItemModel, WorkItemTreeNode};
+use crate::repository::{WorkItemsRepository, WorkItemHierarchyRow};
+use anyhow::Result;
+
+/// Upper bound on how far the hierarchy is walked when no depth limit is given.
+///
+/// Templates go at most 7 levels deep; the bound also stops traversal of cyclic legacy data.
+pub const MAX_HIERARCHY_DEPTH: u32 = 32;
+
+/// Get all ancestors of a work item that aren't archived, nearest first
+pub fn get_work_item_ancestors(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_id: &str,
+) -> Result<Vec<WorkItemHierarchyEntry>> {
+    let rows = repository.find_ancestors(work_item_id, MAX_HIERARCHY_DEPTH, false)?;
+    Ok(to_entries(rows))
+}
+
+/// Get all descendants of a work item that aren't archived down to `max_depth` levels, ordered by depth
+pub fn get_work_item_descendants(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_id: &str,
+    max_depth: Option<u32>,
+) -> Result<Vec<WorkItemHierarchyEntry>> {
+    let rows = repository.find_descendants(work_item_id, depth_limit(max_depth), false)?;
+    Ok(to_entries(rows))
+}
+
+/// Get a work item and its descendants that aren't archived down to `max_depth` levels as a nested tree
+pub fn get_work_item_subtree(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_id: &str,
+    max_depth: Option<u32>,
+) -> Result<Option<WorkItemTreeNode>> {
+    let root = match repository.find_by_id(work_item_id, None)? {
+        Some(entity) => WorkItemModel::from_entity(entity),
+        None => return Ok(None),
+    };
+
+    let max_depth = depth_limit(max_depth);
+    let rows = repository.find_descendants(work_item_id, max_depth, false)?;
+
+    // Group by parent, keeping one row per (parent, child) pair
+    let mut children_by_parent: HashMap<String, Vec<WorkItemModel>> = HashMap::new();
+    let mut seen: HashSet<(String, String)> = HashSet::new();
+    for row in rows {
+        let child_id = row.work_item.id.clone().unwrap_or_default();
+        if seen.insert((row.via_work_item_id.clone(), child_id)) {
+            children_by_parent
+                .entry(row.via_work_item_id)
+                .or_default()
+                .push(WorkItemModel::from_entity(row.work_item));
+        }
+    }
+
+    Ok(Some(build_node(root, 0, max_depth, &children_by_parent)))
+}
+
+fn depth_limit(max_depth: Option<u32>) -> u32 {
+    max_depth.unwrap_or(MAX_HIERARCHY_DEPTH).min(MAX_HIERARCHY_DEPTH)
+}
+
+/// Converts hierarchy rows to entries, keeping the first (shallowest) row for each work item
+fn to_entries(rows: Vec<WorkItemHierarchyRow>) -> Vec<WorkItemHierarchyEntry> {
+    let mut seen: HashSet<String> = HashSet::new();
+    rows.into_iter()
+        .filter(|row| seen.insert(row.work_item.id.clone().unwrap_or_default()))
+        .map(|row| WorkItemHierarchyEntry {
+            work_item: WorkItemModel::from_entity(row.work_item),
+            via_work_item_id: row.via_work_item_id,
+            depth: row.depth,
+        })
+        .collect()
+}
+
+fn build_node(
+    work_item: WorkItemModel,
+    depth: u32,
+    max_depth: u32,
+    children_by_parent: &HashMap<String, Vec<WorkItemModel>>,
+) -> WorkItemTreeNode {
+    let children = match work_item.id.as_ref().and_then(|id| children_by_parent.get(id)) {
+        Some(children) if depth < max_depth => children
+            .iter()
+            .map(|child| build_node(child.clone(), depth + 1, max_depth, children_by_parent))
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    WorkItemTreeNode { work_item, depth, children }
+}
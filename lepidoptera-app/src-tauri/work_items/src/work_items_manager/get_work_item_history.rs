@@ -0,0 +1,15 @@
+use std::sync::Arc;
+use crate::models::WorkItemHistoryEntryModel;
+use crate::work_item_history_repository::WorkItemHistoryRepository;
+use anyhow::Result;
+
+/// Get the change history of a work item, oldest change first
+pub fn get_work_item_history(
+    repository: &Arc<dyn WorkItemHistoryRepository>,
+    work_item_id: &str,
+) -> Result<Vec<WorkItemHistoryEntryModel>> {
+    let entities = repository.find_by_work_item_id(work_item_id)?;
+    entities.into_iter()
+        .map(|entity| WorkItemHistoryEntryModel::from_entity(entity))
+        .collect()
+}
@@ -3,13 +3,19 @@ use db::{ToSql, connection_pool::ConnectionPool, Connection};
 use crate::models::{
     WorkItemListRequest, WorkItemListResponse, WorkItemListItem, WorkItemQuery,
     WorkItemTypeModel, WorkItemFieldValueModel, FieldDefinition, SortField, SortDirection,
+    StatusCategoryCounts, FieldValueQuery, FieldValueOperator,
 };
+use crate::schemas::StatusCategory;
 use crate::repository::WorkItemsRepository;
 use crate::work_item_types_repository::WorkItemTypesRepository;
 use crate::entities::{WorkItem, WorkItemFieldValue};
 use anyhow::{Result, Context};
 use db::repository_base::Entity;
 use std::collections::{HashMap, HashSet};
+use crate::work_items_manager::field_value_projection::{FieldValueKind, normalize_date, parse_number};
+use crate::work_items_manager::rollup_fields::{RollupContext, RollupQuery, compute_rollups};
+use crate::work_items_manager::formula_fields::{FormulaInputs, compute_formulas, formula_dependencies};
+use chrono::Utc;
 
 /// Top-level function to list work items with flexible querying
 pub fn list_work_items(
@@ -21,23 +27,71 @@ pub fn list_work_items(
     let pooled_conn = pool.get()?;
     let conn = pooled_conn.get();
 
+    // Status categories are defined per type, so category filters, sorting and counts
+    // need the project's (type_id, status) -> category mapping
+    let status_categories = if needs_status_categories(&request) {
+        load_status_categories(work_item_types_repository, &request.query.project_id)?
+    } else {
+        HashMap::new()
+    };
+
+    // Field value comparisons and sorting depend on the field's type
+    let field_kinds = if needs_field_kinds(&request) {
+        load_field_kinds(work_item_types_repository, &request.query)?
+    } else {
+        HashMap::new()
+    };
+
+    // Rollup fields have no stored value to sort by, so the sort computes them in SQL
+    let rollup_context = if matches!(request.query.sort_by, Some(SortField::FieldValue { is_assignment_field: false, .. })) {
+        Some(RollupContext::load(work_item_types_repository, &request.query.project_id)?)
+    } else {
+        None
+    };
+
     // Build query components
     let mut params: Vec<Box<dyn ToSql>> = Vec::new();
     let mut param_index = 1;
     
-    let (where_clause, where_param_count) = build_where_clause(&request.query, &mut params, &mut param_index);
-    let (order_by, needs_sort_join) = build_order_by_clause(&request.query);
+    let (where_clause, where_param_count) = build_where_clause(&request.query, &status_categories, &field_kinds, &mut params, &mut param_index)?;
+    let mut rollup_query = rollup_context.as_ref().map(RollupQuery::new);
+    let (order_by, sort_join) = build_order_by_clause(&request.query, &status_categories, &field_kinds, rollup_query.as_mut(), &mut params, &mut param_index);
     let limit_clause = build_limit_clause(&request.query);
-    let join_clause = build_join_clause(&needs_sort_join, &mut params, &mut param_index);
+    let join_clause = build_join_clause(&sort_join, &mut params, &mut param_index);
+    let with_clause = rollup_query.map(|query| query.with_clause()).unwrap_or_default();
 
     // Execute queries
-    let work_item_entities = query_work_items(&conn, &join_clause, &where_clause, &order_by, &limit_clause, &params)?;
+    let work_item_entities = query_work_items(&conn, &with_clause, &join_clause, &where_clause, &order_by, &limit_clause, &params)?;
     let total = query_total_count(&conn, &where_clause, &params, where_param_count)?;
     let (page, page_size, total_pages) = calculate_pagination_info(&request.query, total);
+    let category_counts = if request.include_category_counts.unwrap_or(false) {
+        Some(query_category_counts(&conn, &where_clause, &params, where_param_count, &status_categories)?)
+    } else {
+        None
+    };
 
     // Load related data
     let work_item_types = load_work_item_types(work_item_types_repository, &work_item_entities)?;
-    let field_values_map = load_field_values(&conn, &work_item_entities, &request.include_fields)?;
+    let requested_fields: HashSet<String> = request.include_fields.iter().flatten().cloned().collect();
+    let loaded_fields = with_formula_dependencies(&work_item_types, &requested_fields);
+    let mut field_values_map = load_field_values(&conn, &work_item_entities, &Some(loaded_fields.iter().cloned().collect()))?;
+    load_rollup_values(
+        &conn,
+        work_item_types_repository,
+        &request.query.project_id,
+        rollup_context,
+        &work_item_types,
+        &work_item_entities,
+        &loaded_fields,
+        &mut field_values_map,
+    )?;
+    load_formula_values(&work_item_types, &work_item_entities, &requested_fields, &mut field_values_map);
+    if loaded_fields.len() > requested_fields.len() {
+        // Values loaded only as formula inputs aren't part of the response
+        for field_values in field_values_map.values_mut() {
+            field_values.retain(|fv| requested_fields.contains(&fv.field_id));
+        }
+    }
 
     // Build response
     let items = build_response_items(
@@ -52,51 +106,290 @@ pub fn list_work_items(
         page,
         page_size,
         total_pages,
+        category_counts,
     })
 }
 
 // Helper function to add a parameter and return its placeholder
-fn add_param(params: &mut Vec<Box<dyn ToSql>>, param_index: &mut usize, value: Box<dyn ToSql>) -> String {
+pub fn add_param(params: &mut Vec<Box<dyn ToSql>>, param_index: &mut usize, value: Box<dyn ToSql>) -> String {
     params.push(value);
     let placeholder = format!("?{}", *param_index);
     *param_index += 1;
     placeholder
 }
 
+/// Whether the request filters, sorts or aggregates by status category
+fn needs_status_categories(request: &WorkItemListRequest) -> bool {
+    request.query.status_categories.as_ref().is_some_and(|c| !c.is_empty())
+        || matches!(request.query.sort_by, Some(SortField::StatusCategory))
+        || request.include_category_counts.unwrap_or(false)
+}
+
+/// Whether the request filters or sorts by custom field values
+fn needs_field_kinds(request: &WorkItemListRequest) -> bool {
+    request.query.field_value_queries.as_ref().is_some_and(|q| !q.is_empty())
+        || matches!(request.query.sort_by, Some(SortField::FieldValue { .. }))
+}
+
+/// Loads the category of every (type_id, status) pair in the project
+fn load_status_categories(
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    project_id: &str,
+) -> Result<HashMap<(String, String), StatusCategory>> {
+    let mut status_categories = HashMap::new();
+    for type_entity in work_item_types_repository.find_by_project_id(project_id)? {
+        let type_model = WorkItemTypeModel::from_entity(type_entity)?;
+        let type_id = match &type_model.id {
+            Some(id) => id.clone(),
+            None => continue,
+        };
+        for status in &type_model.allowed_statuses {
+            if let Some(category) = type_model.status_category(&status.id) {
+                status_categories.insert((type_id.clone(), status.id.clone()), category);
+            }
+        }
+    }
+    Ok(status_categories)
+}
+
+/// Loads the value kind of every custom field of the types the query can match, keyed by
+/// (field_id, is_assignment_field). Assignment fields hold person or team ids and always
+/// compare as text. A field that is e.g. a number in one of those types and text in another
+/// has no single kind: it is left out, so it sorts as text, and filtering on it is rejected.
+fn load_field_kinds(
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    query: &WorkItemQuery,
+) -> Result<HashMap<(String, bool), FieldValueKind>> {
+    let in_scope = |type_id: &str| {
+        query.type_id.as_deref().is_none_or(|id| id == type_id)
+            && query.type_ids.as_ref().is_none_or(|ids| ids.is_empty() || ids.iter().any(|id| id == type_id))
+    };
+
+    let mut field_kinds = HashMap::new();
+    let mut conflicting = HashSet::new();
+    for type_entity in work_item_types_repository.find_by_project_id(&query.project_id)? {
+        let type_model = WorkItemTypeModel::from_entity(type_entity)?;
+        if !type_model.id.as_deref().is_some_and(in_scope) {
+            continue;
+        }
+        for field in &type_model.work_item_fields {
+            let kind = FieldValueKind::from_field_type(&field.field_type);
+            if *field_kinds.entry(field.id.clone()).or_insert(kind) != kind {
+                conflicting.insert(field.id.clone());
+            }
+        }
+    }
+
+    let ambiguous_filter = query.field_value_queries.iter()
+        .flatten()
+        .find(|q| !q.is_assignment_field && conflicting.contains(&q.field_id));
+    if let Some(fv_query) = ambiguous_filter {
+        return Err(anyhow::anyhow!(
+            "Field '{}' has a different type in some of the work item types being listed; filter by work item type to compare its values",
+            fv_query.field_id
+        ));
+    }
+
+    Ok(field_kinds.into_iter()
+        .filter(|(field_id, _)| !conflicting.contains(field_id))
+        .map(|(field_id, kind)| ((field_id, false), kind))
+        .collect())
+}
+
+/// Builds the condition for a single field value query
+fn build_field_value_condition(
+    fv_query: &FieldValueQuery,
+    kind: FieldValueKind,
+    params: &mut Vec<Box<dyn ToSql>>,
+    param_index: &mut usize,
+) -> Result<String> {
+    let field_id_param = add_param(params, param_index, Box::new(fv_query.field_id.clone()));
+    let is_assignment_param = add_param(params, param_index, Box::new(fv_query.is_assignment_field));
+    let base = format!(
+        "SELECT 1 FROM work_item_field_values wifv WHERE wifv.work_item_id = work_items.id AND wifv.field_id = {} AND wifv.is_assignment_field = {} AND wifv.is_active = 1",
+        field_id_param, is_assignment_param
+    );
+
+    // Number and date fields compare through their typed projection
+    let value_expr = field_value_column("wifv", kind);
+
+    let condition = match fv_query.operator {
+        FieldValueOperator::Eq
+        | FieldValueOperator::Ne
+        | FieldValueOperator::Gt
+        | FieldValueOperator::Gte
+        | FieldValueOperator::Lt
+        | FieldValueOperator::Lte => {
+            let comparison = match fv_query.operator {
+                FieldValueOperator::Gt => ">",
+                FieldValueOperator::Gte => ">=",
+                FieldValueOperator::Lt => "<",
+                FieldValueOperator::Lte => "<=",
+                _ => "=",
+            };
+            let value_param = add_comparison_param(fv_query, &fv_query.value, kind, params, param_index)?;
+            let exists = format!("EXISTS ({} AND wifv.value <> '' AND {} {} {})", base, value_expr, comparison, value_param);
+            if fv_query.operator == FieldValueOperator::Ne {
+                format!("NOT {}", exists)
+            } else {
+                exists
+            }
+        }
+        FieldValueOperator::Contains | FieldValueOperator::StartsWith => {
+            let escaped = escape_like(&fv_query.value);
+            let pattern = if fv_query.operator == FieldValueOperator::Contains {
+                format!("%{}%", escaped)
+            } else {
+                format!("{}%", escaped)
+            };
+            let pattern_param = add_param(params, param_index, Box::new(pattern));
+            format!("EXISTS ({} AND wifv.value LIKE {} ESCAPE '\\')", base, pattern_param)
+        }
+        FieldValueOperator::In => {
+            if fv_query.values.is_empty() {
+                return Ok("0 = 1".to_string());
+            }
+            let placeholders = fv_query.values.iter()
+                .map(|value| add_comparison_param(fv_query, value, kind, params, param_index))
+                .collect::<Result<Vec<_>>>()?;
+            format!("EXISTS ({} AND wifv.value <> '' AND {} IN ({}))", base, value_expr, placeholders.join(", "))
+        }
+        FieldValueOperator::IsEmpty => format!("NOT EXISTS ({} AND wifv.value <> '')", base),
+        FieldValueOperator::IsNotEmpty => format!("EXISTS ({} AND wifv.value <> '')", base),
+    };
+
+    Ok(condition)
+}
+
+/// Adds a value to compare a field against, converted like the stored value
+fn add_comparison_param(
+    fv_query: &FieldValueQuery,
+    value: &str,
+    kind: FieldValueKind,
+    params: &mut Vec<Box<dyn ToSql>>,
+    param_index: &mut usize,
+) -> Result<String> {
+    match kind {
+        FieldValueKind::Number => {
+            let number = parse_number(value).ok_or_else(|| {
+                anyhow::anyhow!("Field '{}' is a number field, but '{}' is not a number", fv_query.field_id, value)
+            })?;
+            Ok(add_param(params, param_index, Box::new(number)))
+        }
+        FieldValueKind::Date => {
+            let date = normalize_date(value).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Field '{}' is a date field, but '{}' is not a valid date or datetime",
+                    fv_query.field_id,
+                    value
+                )
+            })?;
+            Ok(add_param(params, param_index, Box::new(date)))
+        }
+        FieldValueKind::Text => Ok(add_param(params, param_index, Box::new(value.to_string()))),
+    }
+}
+
+/// The column a field's values compare and sort by
+fn field_value_column(table_alias: &str, kind: FieldValueKind) -> String {
+    match kind {
+        FieldValueKind::Number => format!("{}.value_number", table_alias),
+        FieldValueKind::Date => format!("{}.value_date", table_alias),
+        FieldValueKind::Text => format!("{}.value", table_alias),
+    }
+}
+
+/// Escapes LIKE wildcards so the value matches literally
+fn escape_like(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+}
+
+/// Builds a CASE expression that evaluates to the status category rank of each work item.
+/// Statuses without a category sort after all categorized ones.
+fn build_status_category_rank(
+    status_categories: &HashMap<(String, String), StatusCategory>,
+    params: &mut Vec<Box<dyn ToSql>>,
+    param_index: &mut usize,
+) -> String {
+    if status_categories.is_empty() {
+        return "3".to_string();
+    }
+
+    let when_clauses: Vec<String> = status_categories.iter()
+        .map(|((type_id, status), category)| {
+            format!(
+                "WHEN work_items.type_id = {} AND work_items.status = {} THEN {}",
+                add_param(params, param_index, Box::new(type_id.clone())),
+                add_param(params, param_index, Box::new(status.clone())),
+                category.rank()
+            )
+        })
+        .collect();
+    format!("CASE {} ELSE 3 END", when_clauses.join(" "))
+}
+
 /// Builds the WHERE clause and returns it along with the parameter count
 fn build_where_clause(
     query: &WorkItemQuery,
+    status_categories: &HashMap<(String, String), StatusCategory>,
+    field_kinds: &HashMap<(String, bool), FieldValueKind>,
     params: &mut Vec<Box<dyn ToSql>>,
     param_index: &mut usize,
-) -> (String, usize) {
+) -> Result<(String, usize)> {
     let mut where_clauses = Vec::new();
 
-    // project_id is always required - queries cannot span multiple projects
-    where_clauses.push(format!("project_id = {}", add_param(params, param_index, Box::new(query.project_id.clone()))));
+    // project_id is always required - queries cannot span multiple projects.
+    // Columns are qualified because field value sorting joins work_item_field_values.
+    where_clauses.push(format!("work_items.project_id = {}", add_param(params, param_index, Box::new(query.project_id.clone()))));
+
+    if !query.include_archived.unwrap_or(false) {
+        where_clauses.push("work_items.archived_at IS NULL".to_string());
+    }
 
     if let Some(statuses) = &query.statuses {
         if !statuses.is_empty() {
             let placeholders: Vec<String> = statuses.iter()
                 .map(|s| add_param(params, param_index, Box::new(s.clone())))
                 .collect();
-            where_clauses.push(format!("status IN ({})", placeholders.join(", ")));
+            where_clauses.push(format!("work_items.status IN ({})", placeholders.join(", ")));
+        }
+    }
+
+    if let Some(categories) = &query.status_categories {
+        if !categories.is_empty() {
+            let conditions: Vec<String> = status_categories.iter()
+                .filter(|(_, category)| categories.contains(category))
+                .map(|((type_id, status), _)| {
+                    format!(
+                        "(work_items.type_id = {} AND work_items.status = {})",
+                        add_param(params, param_index, Box::new(type_id.clone())),
+                        add_param(params, param_index, Box::new(status.clone()))
+                    )
+                })
+                .collect();
+            if conditions.is_empty() {
+                // No status in the project belongs to the requested categories
+                where_clauses.push("0 = 1".to_string());
+            } else {
+                where_clauses.push(format!("({})", conditions.join(" OR ")));
+            }
         }
     }
 
     if let Some(priority) = &query.priority {
-        where_clauses.push(format!("priority = {}", add_param(params, param_index, Box::new(*priority))));
+        where_clauses.push(format!("work_items.priority = {}", add_param(params, param_index, Box::new(*priority))));
     }
 
     if let Some(priority_min) = &query.priority_min {
-        where_clauses.push(format!("priority >= {}", add_param(params, param_index, Box::new(*priority_min))));
+        where_clauses.push(format!("work_items.priority >= {}", add_param(params, param_index, Box::new(*priority_min))));
     }
 
     if let Some(priority_max) = &query.priority_max {
-        where_clauses.push(format!("priority <= {}", add_param(params, param_index, Box::new(*priority_max))));
+        where_clauses.push(format!("work_items.priority <= {}", add_param(params, param_index, Box::new(*priority_max))));
     }
 
     if let Some(type_id) = &query.type_id {
-        where_clauses.push(format!("type_id = {}", add_param(params, param_index, Box::new(type_id.clone()))));
+        where_clauses.push(format!("work_items.type_id = {}", add_param(params, param_index, Box::new(type_id.clone()))));
     }
 
     if let Some(type_ids) = &query.type_ids {
@@ -104,20 +397,20 @@ fn build_where_clause(
             let placeholders: Vec<String> = type_ids.iter()
                 .map(|id| add_param(params, param_index, Box::new(id.clone())))
                 .collect();
-            where_clauses.push(format!("type_id IN ({})", placeholders.join(", ")));
+            where_clauses.push(format!("work_items.type_id IN ({})", placeholders.join(", ")));
         }
     }
 
     if let Some(assigned_to) = &query.assigned_to {
-        where_clauses.push(format!("assigned_to = {}", add_param(params, param_index, Box::new(assigned_to.clone()))));
+        where_clauses.push(format!("work_items.assigned_to = {}", add_param(params, param_index, Box::new(assigned_to.clone()))));
     }
 
     if let Some(created_by) = &query.created_by {
-        where_clauses.push(format!("created_by = {}", add_param(params, param_index, Box::new(created_by.clone()))));
+        where_clauses.push(format!("work_items.created_by = {}", add_param(params, param_index, Box::new(created_by.clone()))));
     }
 
     if let Some(title_contains) = &query.title_contains {
-        where_clauses.push(format!("title LIKE {}", add_param(params, param_index, Box::new(format!("%{}%", title_contains)))));
+        where_clauses.push(format!("work_items.title LIKE {}", add_param(params, param_index, Box::new(format!("%{}%", title_contains)))));
     }
 
     if let Some(sequence_numbers) = &query.sequence_numbers {
@@ -125,7 +418,12 @@ fn build_where_clause(
             let placeholders: Vec<String> = sequence_numbers.iter()
                 .map(|s| add_param(params, param_index, Box::new(s.clone())))
                 .collect();
-            where_clauses.push(format!("sequential_number IN ({})", placeholders.join(", ")));
+            // Numbers a work item had before it moved here still find it
+            let placeholders = placeholders.join(", ");
+            where_clauses.push(format!(
+                "(work_items.sequential_number IN ({0}) OR work_items.id IN (SELECT work_item_id FROM work_item_number_aliases WHERE sequential_number IN ({0})))",
+                placeholders
+            ));
         }
     }
 
@@ -133,13 +431,11 @@ fn build_where_clause(
     if let Some(field_value_queries) = &query.field_value_queries {
         if !field_value_queries.is_empty() {
             for fv_query in field_value_queries {
-                let field_id_param = add_param(params, param_index, Box::new(fv_query.field_id.clone()));
-                let is_assignment_param = add_param(params, param_index, Box::new(fv_query.is_assignment_field));
-                let value_param = add_param(params, param_index, Box::new(fv_query.value.clone()));
-                where_clauses.push(format!(
-                    "EXISTS (SELECT 1 FROM work_item_field_values wifv WHERE wifv.work_item_id = work_items.id AND wifv.field_id = {} AND wifv.is_assignment_field = {} AND wifv.value = {} AND wifv.is_active = 1)",
-                    field_id_param, is_assignment_param, value_param
-                ));
+                let kind = field_kinds
+                    .get(&(fv_query.field_id.clone(), fv_query.is_assignment_field))
+                    .copied()
+                    .unwrap_or(FieldValueKind::Text);
+                where_clauses.push(build_field_value_condition(fv_query, kind, params, param_index)?);
             }
         }
     }
@@ -151,28 +447,68 @@ fn build_where_clause(
     };
 
     let where_param_count = params.len();
-    (where_clause, where_param_count)
+    Ok((where_clause, where_param_count))
+}
+
+/// What the ORDER BY clause needs joined to the work items
+enum SortJoin {
+    None,
+    /// A stored field value, by field id and whether it's an assignment field
+    FieldValue(String, bool),
+    /// The rollup values of a `RollupQuery` expression
+    Rollup(String),
 }
 
 /// Builds the ORDER BY clause and returns it along with sort join info
-fn build_order_by_clause(query: &WorkItemQuery) -> (String, Option<(String, bool)>) {
+fn build_order_by_clause(
+    query: &WorkItemQuery,
+    status_categories: &HashMap<(String, String), StatusCategory>,
+    field_kinds: &HashMap<(String, bool), FieldValueKind>,
+    rollup_query: Option<&mut RollupQuery>,
+    params: &mut Vec<Box<dyn ToSql>>,
+    param_index: &mut usize,
+) -> (String, SortJoin) {
     match &query.sort_by {
+        Some(SortField::StatusCategory) => {
+            let direction = match query.sort_direction.unwrap_or(SortDirection::Asc) {
+                SortDirection::Asc => "ASC",
+                SortDirection::Desc => "DESC",
+            };
+            let rank = build_status_category_rank(status_categories, params, param_index);
+            // Within a category, keep the default newest-first order
+            (format!("ORDER BY {} {}, work_items.created_at DESC", rank, direction), SortJoin::None)
+        }
         Some(SortField::FieldValue { field_id, is_assignment_field }) => {
             let direction = match query.sort_direction.unwrap_or(SortDirection::Asc) {
                 SortDirection::Asc => "ASC",
                 SortDirection::Desc => "DESC",
             };
-            // When sorting by field value, we'll use a LEFT JOIN and sort by the value
+            // When sorting by field value, we'll use a LEFT JOIN and sort by the value.
+            // Number and date fields sort by their typed projection, so 10 sorts after 9.
             // In SQLite, NULL values sort first in ASC order and last in DESC order
             // To put NULLs last in ASC, we use: ORDER BY sort_fv.value IS NULL, sort_fv.value ASC
             // To put NULLs first in DESC, we use: ORDER BY sort_fv.value IS NULL DESC, sort_fv.value DESC
+            if let Some(rollup_query) = rollup_query.filter(|q| !*is_assignment_field && q.is_rollup(field_id)) {
+                let values = rollup_query.field_value(field_id, params, param_index);
+                let nulls_handling = match query.sort_direction.unwrap_or(SortDirection::Asc) {
+                    SortDirection::Asc => "sort_rollup.value IS NULL, sort_rollup.value",
+                    SortDirection::Desc => "sort_rollup.value IS NULL DESC, sort_rollup.value",
+                };
+                return (format!("ORDER BY {} {}", nulls_handling, direction), SortJoin::Rollup(values));
+            }
+
+            let kind = field_kinds
+                .get(&(field_id.clone(), *is_assignment_field))
+                .copied()
+                .unwrap_or(FieldValueKind::Text);
+            let column = field_value_column("sort_fv", kind);
             let nulls_handling = match query.sort_direction.unwrap_or(SortDirection::Asc) {
-                SortDirection::Asc => "sort_fv.value IS NULL, sort_fv.value",
-                SortDirection::Desc => "sort_fv.value IS NULL DESC, sort_fv.value",
+                SortDirection::Asc => format!("{} IS NULL, {}", column, column),
+                SortDirection::Desc => format!("{} IS NULL DESC, {}", column, column),
             };
             (
                 format!("ORDER BY {} {}", nulls_handling, direction),
-                Some((field_id.clone(), *is_assignment_field)),
+                SortJoin::FieldValue(field_id.clone(), *is_assignment_field),
             )
         }
         Some(field) => {
@@ -183,15 +519,15 @@ fn build_order_by_clause(query: &WorkItemQuery) -> (String, Option<(String, bool
                 SortField::Status => "status",
                 SortField::Priority => "priority",
                 SortField::TypeId => "type_id",
-                SortField::FieldValue { .. } => unreachable!(), // Already handled above
+                SortField::StatusCategory | SortField::FieldValue { .. } => unreachable!(), // Already handled above
             };
             let direction = match query.sort_direction.unwrap_or(SortDirection::Asc) {
                 SortDirection::Asc => "ASC",
                 SortDirection::Desc => "DESC",
             };
-            (format!("ORDER BY {} {}", field_name, direction), None)
+            (format!("ORDER BY {} {}", field_name, direction), SortJoin::None)
         }
-        None => ("ORDER BY created_at DESC".to_string(), None),
+        None => ("ORDER BY created_at DESC".to_string(), SortJoin::None),
     }
 }
 
@@ -209,25 +545,28 @@ fn build_limit_clause(query: &WorkItemQuery) -> String {
 
 /// Builds the JOIN clause if needed for field value sorting
 fn build_join_clause(
-    needs_sort_join: &Option<(String, bool)>,
+    sort_join: &SortJoin,
     params: &mut Vec<Box<dyn ToSql>>,
     param_index: &mut usize,
 ) -> String {
-    if let Some((field_id, is_assignment_field)) = needs_sort_join {
-        let field_id_param = add_param(params, param_index, Box::new(field_id.clone()));
-        let is_assignment_param = add_param(params, param_index, Box::new(*is_assignment_field));
-        format!(
-            "LEFT JOIN work_item_field_values sort_fv ON sort_fv.work_item_id = work_items.id AND sort_fv.field_id = {} AND sort_fv.is_assignment_field = {} AND sort_fv.is_active = 1",
-            field_id_param, is_assignment_param
-        )
-    } else {
-        String::new()
+    match sort_join {
+        SortJoin::FieldValue(field_id, is_assignment_field) => {
+            let field_id_param = add_param(params, param_index, Box::new(field_id.clone()));
+            let is_assignment_param = add_param(params, param_index, Box::new(*is_assignment_field));
+            format!(
+                "LEFT JOIN work_item_field_values sort_fv ON sort_fv.work_item_id = work_items.id AND sort_fv.field_id = {} AND sort_fv.is_assignment_field = {} AND sort_fv.is_active = 1",
+                field_id_param, is_assignment_param
+            )
+        }
+        SortJoin::Rollup(values) => format!("LEFT JOIN {} sort_rollup ON sort_rollup.id = work_items.id", values),
+        SortJoin::None => String::new(),
     }
 }
 
 /// Executes the main query to fetch work items
 fn query_work_items(
     conn: &Connection,
+    with_clause: &str,
     join_clause: &str,
     where_clause: &str,
     order_by: &str,
@@ -235,8 +574,8 @@ fn query_work_items(
     params: &[Box<dyn ToSql>],
 ) -> Result<Vec<WorkItem>> {
     let sql = format!(
-        "SELECT work_items.id, work_items.title, work_items.description, work_items.status, work_items.created_at, work_items.updated_at, work_items.priority, work_items.created_by, work_items.assigned_to, work_items.project_id, work_items.type_id, work_items.sequential_number FROM work_items {} {} {} {}",
-        join_clause, where_clause, order_by, limit_clause
+        "{} SELECT work_items.id, work_items.title, work_items.description, work_items.status, work_items.created_at, work_items.updated_at, work_items.priority, work_items.created_by, work_items.assigned_to, work_items.project_id, work_items.type_id, work_items.sequential_number, work_items.updated_by, work_items.archived_at, work_items.archived_by FROM work_items {} {} {} {}",
+        with_clause, join_clause, where_clause, order_by, limit_clause
     );
 
     let param_refs: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
@@ -269,6 +608,39 @@ fn query_total_count(
     Ok(result)
 }
 
+/// Counts the matching work items per status category
+fn query_category_counts(
+    conn: &Connection,
+    where_clause: &str,
+    params: &[Box<dyn ToSql>],
+    where_param_count: usize,
+    status_categories: &HashMap<(String, String), StatusCategory>,
+) -> Result<StatusCategoryCounts> {
+    let where_param_refs: Vec<&dyn ToSql> = params.iter().take(where_param_count).map(|p| p.as_ref()).collect();
+    let sql = format!(
+        "SELECT type_id, status, COUNT(*) FROM work_items {} GROUP BY type_id, status",
+        where_clause
+    );
+    let rows = conn.query(
+        &sql,
+        &where_param_refs,
+        |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, i64>(2)?)),
+    )
+    .context("Failed to count work items by status category")?;
+
+    let mut counts = StatusCategoryCounts::default();
+    for (type_id, status, count) in rows {
+        let count = count as usize;
+        match status_categories.get(&(type_id, status)) {
+            Some(StatusCategory::Todo) => counts.todo += count,
+            Some(StatusCategory::InProgress) => counts.in_progress += count,
+            Some(StatusCategory::Done) => counts.done += count,
+            None => counts.uncategorized += count,
+        }
+    }
+    Ok(counts)
+}
+
 /// Calculates pagination metadata
 fn calculate_pagination_info(query: &WorkItemQuery, total: usize) -> (Option<usize>, Option<usize>, Option<usize>) {
     if let (Some(p), Some(ps)) = (query.page, query.page_size) {
@@ -342,7 +714,7 @@ fn load_field_values(
         fv_params.extend(include_fields_set.iter().map(|f| Box::new(f.clone()) as Box<dyn ToSql>));
 
         let fv_sql = format!(
-            "SELECT id, project_id, work_item_id, field_id, is_assignment_field, value, created_at, updated_at, created_by, updated_by, is_active FROM work_item_field_values WHERE work_item_id IN ({}) AND field_id IN ({}) AND is_active = 1",
+            "SELECT id, project_id, work_item_id, field_id, is_assignment_field, value, created_at, updated_at, created_by, updated_by, is_active, value_number, value_date FROM work_item_field_values WHERE work_item_id IN ({}) AND field_id IN ({}) AND is_active = 1",
             id_placeholders.join(", "),
             field_id_placeholders.join(", ")
         );
@@ -367,6 +739,102 @@ fn load_field_values(
     Ok(field_values_map)
 }
 
+/// Adds the requested rollup fields to the loaded field values. Rollups are computed per
+/// type, for the work items of that type in the results.
+fn load_rollup_values(
+    conn: &Connection,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    project_id: &str,
+    rollup_context: Option<RollupContext>,
+    work_item_types: &HashMap<String, WorkItemTypeModel>,
+    work_item_entities: &[WorkItem],
+    include_fields: &HashSet<String>,
+    field_values_map: &mut HashMap<String, Vec<WorkItemFieldValueModel>>,
+) -> Result<()> {
+    let computing_types: Vec<&WorkItemTypeModel> = work_item_types.values()
+        .filter(|wit| wit.work_item_fields.iter().any(|f| f.rollup.is_some() && include_fields.contains(&f.id)))
+        .collect();
+    if computing_types.is_empty() {
+        return Ok(());
+    }
+
+    let context = match rollup_context {
+        Some(context) => context,
+        None => RollupContext::load(work_item_types_repository, project_id)?,
+    };
+    for work_item_type in computing_types {
+        let work_item_ids: Vec<String> = work_item_entities.iter()
+            .filter(|wi| Some(&wi.type_id) == work_item_type.id.as_ref())
+            .filter_map(|wi| wi.id.clone())
+            .collect();
+        let rollups = compute_rollups(conn, &context, work_item_type, &work_item_ids, Some(include_fields))?;
+
+        for work_item_id in &work_item_ids {
+            let field_values = field_values_map.entry(work_item_id.clone()).or_default();
+            // Anything stored under a computed field's id predates the field being computed
+            field_values.retain(|fv| fv.is_assignment_field || !work_item_type.is_computed_field(&fv.field_id));
+            field_values.extend(rollups.get(work_item_id).cloned().unwrap_or_default());
+        }
+    }
+    Ok(())
+}
+
+/// The requested fields plus the fields the requested formulas are computed from
+fn with_formula_dependencies(
+    work_item_types: &HashMap<String, WorkItemTypeModel>,
+    requested_fields: &HashSet<String>,
+) -> HashSet<String> {
+    let mut fields = requested_fields.clone();
+    for work_item_type in work_item_types.values() {
+        for field in &work_item_type.work_item_fields {
+            if field.formula.is_some() && requested_fields.contains(&field.id) {
+                fields.extend(formula_dependencies(work_item_type, field));
+            }
+        }
+    }
+    fields
+}
+
+/// Evaluates the requested formula fields from the loaded field values
+fn load_formula_values(
+    work_item_types: &HashMap<String, WorkItemTypeModel>,
+    work_item_entities: &[WorkItem],
+    requested_fields: &HashSet<String>,
+    field_values_map: &mut HashMap<String, Vec<WorkItemFieldValueModel>>,
+) {
+    let now = Utc::now();
+    for entity in work_item_entities {
+        let (work_item_type, work_item_id) = match (work_item_types.get(&entity.type_id), &entity.id) {
+            (Some(work_item_type), Some(id)) => (work_item_type, id),
+            _ => continue,
+        };
+        if !work_item_type.work_item_fields.iter().any(|f| f.formula.is_some() && requested_fields.contains(&f.id)) {
+            continue;
+        }
+
+        let field_values = field_values_map.entry(work_item_id.clone()).or_default();
+        // As for rollups, a formula replaces anything stored under its id
+        field_values.retain(|fv| {
+            fv.is_assignment_field
+                || !work_item_type.work_item_fields.iter().any(|f| f.id == fv.field_id && f.formula.is_some())
+        });
+        let formulas = compute_formulas(
+            work_item_type,
+            &FormulaInputs {
+                project_id: &entity.project_id,
+                work_item_id,
+                created_at: &entity.created_at,
+                updated_at: entity.updated_at.as_deref(),
+                priority: entity.priority,
+                field_values,
+                now,
+            },
+            Some(requested_fields),
+        );
+        field_values.extend(formulas);
+    }
+}
+
 /// Hydrates field values with their field definitions from work item types
 fn hydrate_field_values(
     field_values: &mut [WorkItemFieldValueModel],
@@ -412,6 +880,8 @@ fn build_response_items(
         let status_detail = work_item_type
             .and_then(|wit| wit.allowed_statuses.iter().find(|s| s.id == entity.status))
             .cloned();
+        let status_category = work_item_type
+            .and_then(|wit| wit.status_category(&entity.status));
 
         // Get priority detail
         let priority_detail = work_item_type
@@ -440,7 +910,10 @@ fn build_response_items(
             project_id: entity.project_id,
             type_id: entity.type_id,
             sequential_number: entity.sequential_number,
+            updated_by: entity.updated_by,
+            archived_at: entity.archived_at,
             status_detail,
+            status_category,
             priority_detail,
             field_values,
         });
@@ -1,29 +1,43 @@
 use std::sync::Arc;
+use db::Connection;
 use db::connection_pool::ConnectionPool;
-use crate::models::{WorkItemModel, WorkItemTypeModel, WorkItemListRequest, WorkItemListResponse, WorkItemRelationshipModel, RelationshipType};
+use crate::models::{WorkItemModel, WorkItemTypeModel, WorkItemQuery, WorkItemListRequest, WorkItemListResponse, WorkItemRelationshipModel, RelationshipType, RelationshipCycle, WorkItemHistoryEntryModel, WorkItemHierarchyEntry, WorkItemTreeNode, SavedViewModel, WorkItemSelection, WorkItemPatch, BulkUpdateResult, WorkItemTypeChange, WorkItemTypeImpact, WorkItemTypeMigration, WorkItemFieldValueModel, FieldState, WorkItemCloneOptions, WorkItemCloneResult, WorkItemMove, WorkItemMoveResult};
 use crate::repository::WorkItemsRepository;
 use crate::work_items_manager::{
-    create_work_item, get_work_item, list_work_items,
+    create_work_item, update_work_item, bulk_update_work_items, change_work_item_type, archive_work_item, clone_work_item, move_work_item, get_work_item, list_work_items, parse_work_item_query, get_next_statuses,
+    get_field_states,
     get_work_item_types_by_project, get_work_item_type,
-    create_work_item_type, update_work_item_type, mark_work_item_type_inactive,
+    create_work_item_type, evolve_work_item_type, mark_work_item_type_inactive,
     apply_template,
-    create_work_item_relationship, get_work_item_relationships,
-    delete_work_item_relationship,
+    create_work_item_relationship, get_work_item_relationships, get_work_item_hierarchy,
+    delete_work_item_relationship, check_relationship_integrity, get_work_item_history,
+    saved_views, work_item_repositories::WorkItemRepositories,
 };
 use crate::models::WorkItemTypeTemplate;
+use crate::schemas::AllowedStatus;
 use crate::work_items_port::WorkItemsManager;
 use crate::work_items_sqlite_repository::SqliteWorkItemsRepository;
 use crate::work_item_types_sqlite_repository::SqliteWorkItemTypesRepository;
 use crate::work_item_types_repository::WorkItemTypesRepository;
 use crate::work_item_number_ranges_repository::{WorkItemNumberRangesRepository, SqliteWorkItemNumberRangesRepository};
+use crate::work_item_number_aliases_repository::{WorkItemNumberAliasesRepository, SqliteWorkItemNumberAliasesRepository};
 use crate::work_item_relationships_repository::WorkItemRelationshipsRepository;
 use crate::work_item_relationships_sqlite_repository::SqliteWorkItemRelationshipsRepository;
+use crate::work_item_field_values_repository::{WorkItemFieldValuesRepository, SqliteWorkItemFieldValuesRepository};
+use crate::work_item_history_repository::{WorkItemHistoryRepository, SqliteWorkItemHistoryRepository};
+use crate::saved_views_repository::{SavedViewsRepository, SqliteSavedViewsRepository};
+use crate::assignees_repository::{AssigneesRepository, SqliteAssigneesRepository};
 
 pub struct SqliteWorkItemManager {
     repository: Arc<dyn WorkItemsRepository>,
     work_item_types_repository: Arc<dyn WorkItemTypesRepository>,
     number_ranges_repository: Arc<dyn WorkItemNumberRangesRepository>,
+    number_aliases_repository: Arc<dyn WorkItemNumberAliasesRepository>,
     relationships_repository: Arc<dyn WorkItemRelationshipsRepository>,
+    field_values_repository: Arc<dyn WorkItemFieldValuesRepository>,
+    history_repository: Arc<dyn WorkItemHistoryRepository>,
+    saved_views_repository: Arc<dyn SavedViewsRepository>,
+    assignees_repository: Arc<dyn AssigneesRepository>,
     pool: Arc<ConnectionPool>,
 }
 
@@ -35,16 +49,45 @@ impl SqliteWorkItemManager {
             Arc::new(SqliteWorkItemTypesRepository::new(pool.clone()));
         let number_ranges_repository: Arc<dyn WorkItemNumberRangesRepository> =
             Arc::new(SqliteWorkItemNumberRangesRepository::new(pool.clone()));
+        let number_aliases_repository: Arc<dyn WorkItemNumberAliasesRepository> =
+            Arc::new(SqliteWorkItemNumberAliasesRepository::new(pool.clone()));
         let relationships_repository: Arc<dyn WorkItemRelationshipsRepository> =
             Arc::new(SqliteWorkItemRelationshipsRepository::new(pool.clone()));
+        let field_values_repository: Arc<dyn WorkItemFieldValuesRepository> =
+            Arc::new(SqliteWorkItemFieldValuesRepository::new(pool.clone()));
+        let history_repository: Arc<dyn WorkItemHistoryRepository> =
+            Arc::new(SqliteWorkItemHistoryRepository::new(pool.clone()));
+        let saved_views_repository: Arc<dyn SavedViewsRepository> =
+            Arc::new(SqliteSavedViewsRepository::new(pool.clone()));
+        let assignees_repository: Arc<dyn AssigneesRepository> =
+            Arc::new(SqliteAssigneesRepository::new(pool.clone()));
         Self { 
             repository,
             work_item_types_repository,
             number_ranges_repository,
+            number_aliases_repository,
             relationships_repository,
+            field_values_repository,
+            history_repository,
+            saved_views_repository,
+            assignees_repository,
             pool,
         }
     }
+
+    fn repositories(&self) -> WorkItemRepositories<'_> {
+        WorkItemRepositories {
+            repository: &self.repository,
+            work_item_types_repository: &self.work_item_types_repository,
+            number_ranges_repository: &self.number_ranges_repository,
+            number_aliases_repository: &self.number_aliases_repository,
+            relationships_repository: &self.relationships_repository,
+            field_values_repository: &self.field_values_repository,
+            history_repository: &self.history_repository,
+            assignees_repository: &self.assignees_repository,
+            pool: &self.pool,
+        }
+    }
 }
 
 impl WorkItemsManager for SqliteWorkItemManager {
@@ -60,16 +103,141 @@ impl WorkItemsManager for SqliteWorkItemManager {
 
     fn create_work_item(&self, work_item: WorkItemModel, sequence_prefix: &str, machine_id: &str) -> anyhow::Result<WorkItemModel> {
         create_work_item::create_work_item(
+            self.repositories(),
+            work_item,
+            sequence_prefix,
+            machine_id,
+        )
+    }
+
+    fn update_work_item(&self, work_item: WorkItemModel, updated_by: &str) -> anyhow::Result<WorkItemModel> {
+        update_work_item::update_work_item(
+            self.repositories(),
+            work_item,
+            updated_by,
+        )
+    }
+
+    fn bulk_update_work_items(&self, selection: WorkItemSelection, patch: WorkItemPatch, updated_by: &str) -> anyhow::Result<BulkUpdateResult> {
+        bulk_update_work_items::bulk_update_work_items(
+            self.repositories(),
+            selection,
+            patch,
+            updated_by,
+        )
+    }
+
+    fn change_work_item_type(&self, work_item_id: &str, change: WorkItemTypeChange, updated_by: &str) -> anyhow::Result<WorkItemModel> {
+        change_work_item_type::change_work_item_type(
+            self.repositories(),
+            work_item_id,
+            change,
+            updated_by,
+        )
+    }
+
+    fn archive_work_item(&self, work_item_id: &str, archived_by: &str) -> anyhow::Result<WorkItemModel> {
+        archive_work_item::archive_work_item(
             &self.repository,
             &self.work_item_types_repository,
-            &self.number_ranges_repository,
+            &self.history_repository,
             &self.pool,
-            work_item,
+            work_item_id,
+            archived_by,
+        )
+    }
+
+    fn restore_work_item(&self, work_item_id: &str, restored_by: &str) -> anyhow::Result<WorkItemModel> {
+        archive_work_item::restore_work_item(
+            &self.repository,
+            &self.work_item_types_repository,
+            &self.history_repository,
+            &self.pool,
+            work_item_id,
+            restored_by,
+        )
+    }
+
+    fn ensure_work_item_archived(&self, conn: &mut Connection, work_item_id: &str, archived_by: &str) -> anyhow::Result<WorkItemModel> {
+        archive_work_item::ensure_work_item_archived(
+            &self.repository,
+            &self.work_item_types_repository,
+            &self.history_repository,
+            conn,
+            work_item_id,
+            archived_by,
+        )
+    }
+
+    fn ensure_work_item_restored(&self, conn: &mut Connection, work_item_id: &str, restored_by: &str) -> anyhow::Result<WorkItemModel> {
+        archive_work_item::ensure_work_item_restored(
+            &self.repository,
+            &self.work_item_types_repository,
+            &self.history_repository,
+            conn,
+            work_item_id,
+            restored_by,
+        )
+    }
+
+    fn purge_work_item(&self, work_item_id: &str) -> anyhow::Result<()> {
+        archive_work_item::purge_work_item(
+            &self.repository,
+            &self.field_values_repository,
+            &self.relationships_repository,
+            &self.history_repository,
+            &self.number_aliases_repository,
+            &self.pool,
+            work_item_id,
+        )
+    }
+
+    fn clone_work_item(
+        &self,
+        work_item_id: &str,
+        options: WorkItemCloneOptions,
+        cloned_by: &str,
+        sequence_prefix: &str,
+        machine_id: &str,
+    ) -> anyhow::Result<WorkItemCloneResult> {
+        clone_work_item::clone_work_item(
+            self.repositories(),
+            work_item_id,
+            options,
+            cloned_by,
             sequence_prefix,
             machine_id,
         )
     }
 
+    fn move_work_item(
+        &self,
+        work_item_id: &str,
+        request: WorkItemMove,
+        moved_by: &str,
+        sequence_prefix: &str,
+        machine_id: &str,
+    ) -> anyhow::Result<WorkItemMoveResult> {
+        move_work_item::move_work_item(
+            self.repositories(),
+            work_item_id,
+            request,
+            moved_by,
+            sequence_prefix,
+            machine_id,
+        )
+    }
+
+    fn resolve_work_item_number(&self, sequential_number: &str) -> anyhow::Result<Option<WorkItemModel>> {
+        get_work_item::resolve_work_item_number(
+            &self.repository,
+            &self.work_item_types_repository,
+            &self.number_aliases_repository,
+            &self.pool,
+            sequential_number,
+        )
+    }
+
     fn list_work_items(&self, request: WorkItemListRequest) -> anyhow::Result<WorkItemListResponse> {
         list_work_items::list_work_items(
             &self.repository,
@@ -79,6 +247,41 @@ impl WorkItemsManager for SqliteWorkItemManager {
         )
     }
 
+    fn parse_work_item_query(&self, project_id: &str, query: &str, current_user: Option<&str>) -> anyhow::Result<WorkItemQuery> {
+        parse_work_item_query::parse_work_item_query(
+            &self.work_item_types_repository,
+            project_id,
+            query,
+            current_user,
+        )
+    }
+
+    fn get_next_statuses(&self, work_item_id: &str) -> anyhow::Result<Vec<AllowedStatus>> {
+        get_next_statuses::get_next_statuses(
+            &self.repository,
+            &self.work_item_types_repository,
+            work_item_id,
+        )
+    }
+
+    fn get_field_states(
+        &self,
+        work_item_type_id: &str,
+        status: &str,
+        field_values: Vec<WorkItemFieldValueModel>,
+    ) -> anyhow::Result<Vec<FieldState>> {
+        get_field_states::get_field_states(
+            &self.work_item_types_repository,
+            work_item_type_id,
+            status,
+            &field_values,
+        )
+    }
+
+    fn get_work_item_history(&self, work_item_id: &str) -> anyhow::Result<Vec<WorkItemHistoryEntryModel>> {
+        get_work_item_history::get_work_item_history(&self.history_repository, work_item_id)
+    }
+
     fn get_work_item_types_by_project(&self, project_id: &str) -> anyhow::Result<Vec<WorkItemTypeModel>> {
         get_work_item_types_by_project::get_work_item_types_by_project(&self.work_item_types_repository, project_id)
     }
@@ -88,23 +291,38 @@ impl WorkItemsManager for SqliteWorkItemManager {
     }
 
     fn create_work_item_type(&self, work_item_type: WorkItemTypeModel) -> anyhow::Result<WorkItemTypeModel> {
-        create_work_item_type::create_work_item_type(&self.work_item_types_repository, work_item_type)
+        create_work_item_type::create_work_item_type(&self.work_item_types_repository, work_item_type, None)
     }
 
-    fn update_work_item_type(&self, work_item_type: WorkItemTypeModel) -> anyhow::Result<WorkItemTypeModel> {
-        update_work_item_type::update_work_item_type(&self.work_item_types_repository, work_item_type)
+    fn analyze_work_item_type_update(&self, work_item_type: WorkItemTypeModel) -> anyhow::Result<WorkItemTypeImpact> {
+        evolve_work_item_type::analyze_work_item_type_update(
+            &self.repository,
+            &self.work_item_types_repository,
+            &self.field_values_repository,
+            &self.pool,
+            &work_item_type,
+        )
+    }
+
+    fn update_work_item_type(&self, work_item_type: WorkItemTypeModel, migration: WorkItemTypeMigration, updated_by: &str) -> anyhow::Result<WorkItemTypeModel> {
+        evolve_work_item_type::update_work_item_type_with_migration(
+            self.repositories(),
+            work_item_type,
+            migration,
+            updated_by,
+        )
     }
 
-    fn mark_work_item_type_inactive(&self, id: &str) -> anyhow::Result<()> {
-        mark_work_item_type_inactive::mark_work_item_type_inactive(&self.work_item_types_repository, id)
+    fn mark_work_item_type_inactive(&self, id: &str, deleted_by: &str) -> anyhow::Result<()> {
+        mark_work_item_type_inactive::mark_work_item_type_inactive(&self.work_item_types_repository, &self.pool, id, deleted_by)
     }
 
     fn apply_template(&self, project_id: String, work_item_types: Vec<WorkItemTypeTemplate>) -> anyhow::Result<Vec<WorkItemTypeModel>> {
-        apply_template::apply_template(&self.work_item_types_repository, project_id, work_item_types)
+        apply_template::apply_template(&self.work_item_types_repository, &self.pool, project_id, work_item_types)
     }
 
     fn create_work_item_relationship(&self, relationship: WorkItemRelationshipModel, created_by: &str) -> anyhow::Result<WorkItemRelationshipModel> {
-        create_work_item_relationship::create_work_item_relationship(&self.relationships_repository, relationship, created_by)
+        create_work_item_relationship::create_work_item_relationship(&self.relationships_repository, &self.repository, &self.work_item_types_repository, &self.history_repository, &self.pool, relationship, created_by)
     }
 
     fn get_work_item_relationships(&self, work_item_id: &str) -> anyhow::Result<Vec<WorkItemRelationshipModel>> {
@@ -127,8 +345,56 @@ impl WorkItemsManager for SqliteWorkItemManager {
         get_work_item_relationships::get_work_item_target_relationships_by_type(&self.relationships_repository, work_item_id, relationship_type.as_str())
     }
 
-    fn delete_work_item_relationship(&self, relationship_id: &str) -> anyhow::Result<()> {
-        delete_work_item_relationship::delete_work_item_relationship(&self.relationships_repository, relationship_id)
+    fn get_work_item_ancestors(&self, work_item_id: &str) -> anyhow::Result<Vec<WorkItemHierarchyEntry>> {
+        get_work_item_hierarchy::get_work_item_ancestors(&self.repository, work_item_id)
+    }
+
+    fn get_work_item_descendants(&self, work_item_id: &str, max_depth: Option<u32>) -> anyhow::Result<Vec<WorkItemHierarchyEntry>> {
+        get_work_item_hierarchy::get_work_item_descendants(&self.repository, work_item_id, max_depth)
+    }
+
+    fn get_work_item_subtree(&self, work_item_id: &str, max_depth: Option<u32>) -> anyhow::Result<Option<WorkItemTreeNode>> {
+        get_work_item_hierarchy::get_work_item_subtree(&self.repository, work_item_id, max_depth)
+    }
+
+    fn delete_work_item_relationship(&self, relationship_id: &str, deleted_by: &str) -> anyhow::Result<()> {
+        delete_work_item_relationship::delete_work_item_relationship(&self.relationships_repository, &self.history_repository, &self.pool, relationship_id, deleted_by)
+    }
+
+    fn check_relationship_integrity(&self, project_id: &str) -> anyhow::Result<Vec<RelationshipCycle>> {
+        check_relationship_integrity::check_relationship_integrity(&self.relationships_repository, project_id)
+    }
+
+    fn create_saved_view(&self, saved_view: SavedViewModel, created_by: &str) -> anyhow::Result<SavedViewModel> {
+        saved_views::create_saved_view(&self.saved_views_repository, saved_view, created_by)
+    }
+
+    fn update_saved_view(&self, saved_view: SavedViewModel, updated_by: &str) -> anyhow::Result<SavedViewModel> {
+        saved_views::update_saved_view(&self.saved_views_repository, saved_view, updated_by)
+    }
+
+    fn get_saved_view(&self, id: &str) -> anyhow::Result<Option<SavedViewModel>> {
+        saved_views::get_saved_view(&self.saved_views_repository, id)
+    }
+
+    fn list_saved_views(&self, project_id: &str, person_id: Option<&str>) -> anyhow::Result<Vec<SavedViewModel>> {
+        saved_views::list_saved_views(&self.saved_views_repository, project_id, person_id)
+    }
+
+    fn delete_saved_view(&self, id: &str, deleted_by: &str) -> anyhow::Result<()> {
+        saved_views::delete_saved_view(&self.saved_views_repository, id, deleted_by)
+    }
+
+    fn execute_saved_view(&self, id: &str, page: Option<usize>, page_size: Option<usize>) -> anyhow::Result<WorkItemListResponse> {
+        saved_views::execute_saved_view(
+            &self.saved_views_repository,
+            &self.repository,
+            &self.work_item_types_repository,
+            &self.pool,
+            id,
+            page,
+            page_size,
+        )
     }
 }
 
@@ -1,11 +1,23 @@
 use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
 use crate::work_item_types_repository::WorkItemTypesRepository;
 use anyhow::Result;
+use chrono::Utc;
 
+/// Mark a work item type inactive and record it in the trash, as a single unit of work
 pub fn mark_work_item_type_inactive(
     repository: &Arc<dyn WorkItemTypesRepository>,
+    pool: &Arc<ConnectionPool>,
     id: &str,
+    deleted_by: &str,
 ) -> Result<()> {
-    repository.mark_inactive(id)
-}
+    let now = Utc::now().to_rfc3339();
 
+    pool.get()?.get_mut().transaction(|conn| {
+        // Recorded first, while the type still reads as active
+        if !repository.record_in_trash(id, deleted_by, &now, Some(conn))? {
+            return Err(anyhow::anyhow!("No active work item type found: {}", id));
+        }
+        repository.mark_inactive(id, &now, Some(conn))
+    })
+}
@@ -1,16 +1,43 @@
 mod create_work_item;
+mod update_work_item;
+mod bulk_update_work_items;
+mod change_work_item_type;
+mod archive_work_item;
+mod clone_work_item;
+mod move_work_item;
 mod get_work_item;
 mod list_work_items;
+mod query_language;
+mod parse_work_item_query;
 mod get_work_item_types_by_project;
 mod get_work_item_type;
 mod create_work_item_type;
 mod update_work_item_type;
+mod evolve_work_item_type;
 mod mark_work_item_type_inactive;
+mod validate_work_item_type;
 mod validate_field_value;
+mod validate_assignment;
+mod field_value_projection;
+mod work_item_defaults;
+mod rollup_fields;
+mod formula_fields;
+mod field_rules;
+mod status_workflow;
+mod validate_hierarchy;
+mod relationship_cycles;
+mod get_next_statuses;
+mod get_field_states;
+mod work_item_history;
+mod get_work_item_history;
 mod number_range_manager;
 mod apply_template;
 mod create_work_item_relationship;
 mod get_work_item_relationships;
+mod get_work_item_hierarchy;
 mod delete_work_item_relationship;
+mod check_relationship_integrity;
+mod saved_views;
+mod work_item_repositories;
 pub mod manager;
 
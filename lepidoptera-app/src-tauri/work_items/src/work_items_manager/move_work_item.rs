@@ -0,0 +1,251 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use crate::entities::WorkItemNumberAlias;
+use crate::errors::HierarchyError;
+use crate::models::{
+    WorkItemModel, WorkItemTypeModel, WorkItemTypeChange, WorkItemChangeType,
+    WorkItemMove, MovedWorkItem, WorkItemMoveResult,
+};
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_items_manager::{
+    get_work_item, work_item_repositories::WorkItemRepositories, archive_work_item::ensure_not_archived, change_work_item_type::map_to_type,
+    get_work_item_hierarchy::MAX_HIERARCHY_DEPTH, number_range_manager::NumberRangeManager,
+    update_work_item::write_work_item_update, status_workflow::validate_status,
+    validate_field_value::validate_field_values_for_status, validate_assignment::validate_assignments,
+    work_item_history::{HistoryRecorder, record_relationship_change},
+};
+use anyhow::{Result, Context};
+use chrono::Utc;
+use ulid::Ulid;
+
+/// Move a work item to another project, and with `request.move_children` its descendants.
+///
+/// Each moved item takes a type of the destination project, either from
+/// `request.type_changes` or by name, and its status and field values are carried over as
+/// on a type change. It gets a new number with the destination's prefix; the old number is
+/// kept as an alias. Relationships between moved items move with them, every other
+/// relationship of a moved item is detached. Everything is written as a single unit of work.
+pub fn move_work_item(
+    repositories: WorkItemRepositories,
+    work_item_id: &str,
+    request: WorkItemMove,
+    moved_by: &str,
+    sequence_prefix: &str,
+    machine_id: &str,
+) -> Result<WorkItemMoveResult> {
+    let WorkItemRepositories {
+        repository,
+        work_item_types_repository,
+        number_ranges_repository,
+        number_aliases_repository,
+        relationships_repository,
+        field_values_repository,
+        history_repository,
+        assignees_repository,
+        pool,
+    } = repositories;
+    let root = get_work_item::get_stored_work_item(repository, work_item_types_repository, pool.get()?.get_mut(), work_item_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", work_item_id))?;
+    ensure_not_archived(&root)?;
+    let destination = request.destination_project_id.as_str();
+    if root.project_id == destination {
+        let name = root.sequential_number.as_deref().unwrap_or(work_item_id);
+        return Err(anyhow::anyhow!("Work item {} is already in project {}", name, destination));
+    }
+
+    // Descendants move whether or not they are archived, so none is left behind without its parent
+    let mut moved_ids = vec![work_item_id.to_string()];
+    let mut links: Vec<(String, String)> = Vec::new();
+    if request.move_children {
+        for row in repository.find_descendants(work_item_id, MAX_HIERARCHY_DEPTH, true)? {
+            let child_id = row.work_item.id.clone().unwrap_or_default();
+            let link = (row.via_work_item_id, child_id.clone());
+            if !links.contains(&link) {
+                links.push(link);
+            }
+            if !moved_ids.contains(&child_id) {
+                moved_ids.push(child_id);
+            }
+        }
+    }
+
+    let destination_types: Vec<WorkItemTypeModel> = work_item_types_repository
+        .find_by_project_id(destination)?
+        .into_iter()
+        .map(WorkItemTypeModel::from_entity)
+        .collect::<Result<Vec<_>>>()
+        .context("Failed to convert work item type entity to model")?
+        .into_iter()
+        .filter(|t| t.is_active)
+        .collect();
+
+    let mut moves: Vec<(WorkItemModel, WorkItemTypeModel, WorkItemModel)> = Vec::new();
+    for id in &moved_ids {
+        let previous = if id == work_item_id {
+            root.clone()
+        } else {
+            get_work_item::get_stored_work_item(repository, work_item_types_repository, pool.get()?.get_mut(), id)?
+                .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", id))?
+        };
+
+        let change = destination_type_change(work_item_types_repository, &destination_types, &request, &previous.type_id)?;
+        let new_type = destination_types.iter()
+            .find(|t| t.id.as_deref() == Some(change.new_type_id.as_str()))
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!(
+                "Work item type {} is not available in project {}",
+                change.new_type_id,
+                destination
+            ))?;
+
+        let mut work_item = map_to_type(&previous, &new_type, &change)?;
+        work_item.project_id = destination.to_string();
+        for field_value in &mut work_item.field_values {
+            field_value.project_id = destination.to_string();
+        }
+
+        validate_status(&new_type, &work_item.status)?;
+        // Carried over values stay even where the new type hides them
+        validate_field_values_for_status(&new_type, &work_item.status, &work_item.field_values, Some(&work_item.field_values))?;
+        validate_assignments(assignees_repository, &new_type, &work_item, Some(&previous))?;
+        moves.push((previous, new_type, work_item));
+    }
+
+    // The moved subtree has to fit the destination types' parent/child rules
+    let new_types: HashMap<&str, &WorkItemTypeModel> = moves.iter()
+        .map(|(previous, new_type, _)| (previous.id.as_deref().unwrap_or_default(), new_type))
+        .collect();
+    for (parent_id, child_id) in &links {
+        let (parent_type, child_type) = (new_types[parent_id.as_str()], new_types[child_id.as_str()]);
+        let child_type_id = child_type.id.clone().unwrap_or_default();
+        if !parent_type.allowed_children_type_ids.contains(&child_type_id) {
+            return Err(HierarchyError::ChildTypeNotAllowed {
+                parent_type_id: parent_type.id.clone().unwrap_or_default(),
+                parent_type_name: parent_type.name.clone(),
+                child_type_id,
+                child_type_name: child_type.name.clone(),
+            }.into());
+        }
+    }
+
+    let moved_set: HashSet<&str> = moved_ids.iter().map(|id| id.as_str()).collect();
+    let mut relationships = Vec::new();
+    let mut seen = HashSet::new();
+    for id in &moved_ids {
+        for relationship in relationships_repository.find_by_work_item_id(id)? {
+            if seen.insert(relationship.id.clone()) {
+                relationships.push(relationship);
+            }
+        }
+    }
+    let (kept, detached): (Vec<_>, Vec<_>) = relationships.into_iter().partition(|r| {
+        moved_set.contains(r.source_work_item_id.as_str()) && moved_set.contains(r.target_work_item_id.as_str())
+    });
+
+    let now = Utc::now().to_rfc3339();
+    let moved = pool.get()?.get_mut().transaction(|conn| {
+        // Detached first, so the history entries this writes move along with the work items
+        for relationship in &detached {
+            let relationship_id = relationship.id.clone().unwrap_or_default();
+            relationships_repository.mark_inactive(&relationship_id, Some(conn))?;
+            record_relationship_change(
+                history_repository,
+                conn,
+                relationship,
+                WorkItemChangeType::RelationshipRemoved,
+                moved_by,
+                &now,
+            )?;
+        }
+        for relationship in &kept {
+            relationships_repository.move_to_project(&relationship.id.clone().unwrap_or_default(), destination, Some(conn))?;
+        }
+
+        let number_range_manager = NumberRangeManager::new(number_ranges_repository.clone());
+        let mut moved = Vec::new();
+        for (previous, new_type, work_item) in &moves {
+            let id = previous.id.clone().unwrap_or_default();
+            let number = number_range_manager.get_next_number(destination, machine_id, conn)?;
+            let sequential_number = format!("{}-{:04}", sequence_prefix, number);
+
+            if let Some(previous_number) = &previous.sequential_number {
+                number_aliases_repository.create(
+                    WorkItemNumberAlias {
+                        id: Some(Ulid::new().to_string()),
+                        work_item_id: id.clone(),
+                        project_id: previous.project_id.clone(),
+                        sequential_number: previous_number.clone(),
+                        created_at: now.clone(),
+                        created_by: moved_by.to_string(),
+                    },
+                    Some(conn),
+                )?;
+            }
+            repository.move_to_project(&id, destination, &sequential_number, Some(conn))?;
+
+            let existing = repository.find_by_id(&id, Some(conn))?
+                .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", id))?;
+            write_work_item_update(repositories, conn, new_type, &existing, work_item, moved_by, &now)?;
+            HistoryRecorder::new(history_repository, destination, &id, moved_by, &now).record(
+                conn,
+                WorkItemChangeType::Moved,
+                Some(&previous.project_id),
+                previous.sequential_number.clone(),
+                Some(sequential_number.clone()),
+            )?;
+
+            moved.push(MovedWorkItem {
+                work_item_id: id,
+                previous_sequential_number: previous.sequential_number.clone(),
+                sequential_number,
+            });
+        }
+
+        Ok(moved)
+    })?;
+
+    let work_item = get_work_item::get_work_item(repository, work_item_types_repository, pool, work_item_id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found after moving: {}", work_item_id))?;
+
+    Ok(WorkItemMoveResult {
+        work_item,
+        moved,
+        detached_relationships: detached.len(),
+    })
+}
+
+/// The type change for work items of `type_id`: the mapping given in the request, or
+/// else the destination type with the same name
+fn destination_type_change(
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    destination_types: &[WorkItemTypeModel],
+    request: &WorkItemMove,
+    type_id: &str,
+) -> Result<WorkItemTypeChange> {
+    if let Some(change) = request.type_changes.get(type_id) {
+        return Ok(change.clone());
+    }
+
+    let source_type = match work_item_types_repository
+        .find_by_id(type_id)
+        .context("Failed to find work item type")?
+    {
+        Some(entity) => WorkItemTypeModel::from_entity(entity)
+            .context("Failed to convert work item type entity to model")?,
+        None => return Err(anyhow::anyhow!("Work item type not found: {}", type_id)),
+    };
+
+    match destination_types.iter().find(|t| t.name == source_type.name) {
+        Some(new_type) => Ok(WorkItemTypeChange {
+            new_type_id: new_type.id.clone().unwrap_or_default(),
+            status_mapping: HashMap::new(),
+            field_mapping: HashMap::new(),
+            assignment_field_mapping: HashMap::new(),
+        }),
+        None => Err(anyhow::anyhow!(
+            "Project {} has no work item type named '{}'; map the type to one of its types",
+            request.destination_project_id,
+            source_type.name
+        )),
+    }
+}
@@ -1,5 +1,5 @@
 use std::sync::Arc;
-use db::connection_pool::ConnectionPool;
+use db::Connection;
 use crate::work_item_number_ranges_repository::WorkItemNumberRangesRepository;
 use crate::entities::WorkItemNumberRange;
 use anyhow::{Result, Context};
@@ -15,17 +15,14 @@ const RANGE_EXHAUSTION_THRESHOLD: i64 = 800; // Claim new range when 80% exhaust
 /// When a range is exhausted, a new range is claimed.
 pub struct NumberRangeManager {
     repository: Arc<dyn WorkItemNumberRangesRepository>,
-    pool: Arc<ConnectionPool>,
 }
 
 impl NumberRangeManager {
     pub fn new(
         repository: Arc<dyn WorkItemNumberRangesRepository>,
-        pool: Arc<ConnectionPool>,
     ) -> Self {
         Self {
             repository,
-            pool,
         }
     }
 
@@ -33,13 +30,26 @@ impl NumberRangeManager {
     /// 
     /// If no active range exists, claims a new range.
     /// If the current range is exhausted, claims a new range.
+    ///
+    /// Runs in a transaction on `conn` (nested if the caller already has one open), so a
+    /// number is only consumed if the caller's unit of work commits.
     pub fn get_next_number(
         &self,
         project_id: &str,
         machine_id: &str,
+        conn: &mut Connection,
+    ) -> Result<i64> {
+        conn.transaction(|conn| self.next_number_in_transaction(project_id, machine_id, conn))
+    }
+
+    fn next_number_in_transaction(
+        &self,
+        project_id: &str,
+        machine_id: &str,
+        conn: &mut Connection,
     ) -> Result<i64> {
         // Try to find an active range
-        let mut active_range = self.repository.find_active_range(project_id, machine_id)?;
+        let mut active_range = self.repository.find_active_range(project_id, machine_id, Some(conn))?;
 
         // If no active range or range is exhausted, claim a new one
         let needs_new_range = match &active_range {
@@ -52,7 +62,7 @@ impl NumberRangeManager {
         };
 
         if needs_new_range {
-            active_range = Some(self.claim_range(project_id, machine_id)?);
+            active_range = Some(self.claim_range(project_id, machine_id, conn)?);
         }
 
         let range = active_range.ok_or_else(|| {
@@ -64,11 +74,12 @@ impl NumberRangeManager {
         
         if next_number > range.range_end {
             // Range is exhausted, claim a new one
-            let new_range = self.claim_range(project_id, machine_id)?;
+            let new_range = self.claim_range(project_id, machine_id, conn)?;
             let next_number = new_range.current_number + 1;
             self.repository.update_current_number(
                 new_range.id.as_ref().unwrap(),
                 next_number,
+                Some(conn),
             )?;
             Ok(next_number)
         } else {
@@ -76,6 +87,7 @@ impl NumberRangeManager {
             self.repository.update_current_number(
                 range.id.as_ref().unwrap(),
                 next_number,
+                Some(conn),
             )?;
             Ok(next_number)
         }
@@ -89,9 +101,10 @@ impl NumberRangeManager {
         &self,
         project_id: &str,
         machine_id: &str,
+        conn: &mut Connection,
     ) -> Result<WorkItemNumberRange> {
         // Find all existing ranges for this project
-        let existing_ranges = self.repository.find_ranges_by_project(project_id)?;
+        let existing_ranges = self.repository.find_ranges_by_project(project_id, Some(conn))?;
 
         // Determine the next range_start
         let range_start = if existing_ranges.is_empty() {
@@ -122,7 +135,7 @@ impl NumberRangeManager {
         };
 
         // Save the range
-        let created_range = self.repository.create_range(new_range)
+        let created_range = self.repository.create_range(new_range, Some(conn))
             .context("Failed to create number range")?;
 
         Ok(created_range)
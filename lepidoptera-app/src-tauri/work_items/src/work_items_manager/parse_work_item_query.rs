@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use crate::models::WorkItemQuery;
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_items_manager::get_work_item_types_by_project::get_work_item_types_by_project;
+use crate::work_items_manager::query_language::{compile_query, parse_query, QueryContext};
+use anyhow::Result;
+
+/// Parse a text query (e.g. `type:Bug status:open assignee:me sort:-updated`) into a `WorkItemQuery`.
+///
+/// Type, status, priority and field names are resolved against the project's work item types.
+/// Errors are `QueryParseError`s carrying the position of the offending term.
+pub fn parse_work_item_query(
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    project_id: &str,
+    query: &str,
+    current_user: Option<&str>,
+) -> Result<WorkItemQuery> {
+    let terms = parse_query(query)?;
+    let work_item_types = get_work_item_types_by_project(work_item_types_repository, project_id)?;
+
+    let context = QueryContext {
+        project_id,
+        current_user,
+        work_item_types: &work_item_types,
+    };
+    Ok(compile_query(terms, &context)?)
+}
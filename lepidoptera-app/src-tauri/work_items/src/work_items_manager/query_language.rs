@@ -0,0 +1,736 @@
+//! A small text query language for work items.
+//!
+//! A query is a whitespace separated list of terms:
+//! - `key:value` / `key=value` filters, e.g. `type:Bug status:open,in_review assignee:me`
+//! - comparisons on priority, e.g. `priority>=3`
+//! - custom field filters, e.g. `field.story_points>5`, `field.due_date<2025-06-01`,
+//!   `field.component~auth` (contains), `field.component:auth*` (starts with),
+//!   `field.severity:high,critical` (any of), `field.owner:""` (empty) and `field.owner!=""` (not empty)
+//! - a sort order, e.g. `sort:-updated` (`-` for descending)
+//! - free text, bare or `"quoted"`, matched against the title
+//!
+//! Parsing is split in two steps: `parse_query` checks the syntax and `compile_query`
+//! resolves names against the project's work item types to build a `WorkItemQuery`.
+//! Both report errors with the character position of the offending term.
+
+use std::collections::HashSet;
+use crate::errors::QueryParseError;
+use crate::models::{FieldValueQuery, FieldValueOperator, SortDirection, SortField, WorkItemQuery, WorkItemTypeModel};
+use crate::schemas::StatusCategory;
+
+/// Comparison operator between a filter key and its value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryOperator {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    /// `~`, a substring match (field filters only)
+    Contains,
+}
+
+impl QueryOperator {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QueryOperator::Eq => ":",
+            QueryOperator::Ne => "!=",
+            QueryOperator::Gt => ">",
+            QueryOperator::Gte => ">=",
+            QueryOperator::Lt => "<",
+            QueryOperator::Lte => "<=",
+            QueryOperator::Contains => "~",
+        }
+    }
+}
+
+/// A piece of query text and the character position it starts at
+#[derive(Debug, Clone)]
+pub struct Spanned {
+    pub text: String,
+    pub position: usize,
+}
+
+impl Spanned {
+    fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn error(&self, message: String) -> QueryParseError {
+        QueryParseError::new(message, self.position, self.len())
+    }
+}
+
+/// A single term of a parsed query
+#[derive(Debug, Clone)]
+pub enum QueryTerm {
+    /// Free text matched against the title
+    Text(Spanned),
+    /// A `key<operator>value` filter
+    Filter {
+        key: Spanned,
+        operator: QueryOperator,
+        operator_position: usize,
+        value: Spanned,
+    },
+}
+
+/// What a query is compiled against
+pub struct QueryContext<'a> {
+    pub project_id: &'a str,
+    /// The user `me` refers to
+    pub current_user: Option<&'a str>,
+    /// The project's work item types, used to resolve type, status, priority and field names
+    pub work_item_types: &'a [WorkItemTypeModel],
+}
+
+/// Parses query text into terms
+pub fn parse_query(input: &str) -> Result<Vec<QueryTerm>, QueryParseError> {
+    let mut scanner = Scanner { chars: input.chars().collect(), position: 0 };
+    let mut terms = Vec::new();
+
+    loop {
+        scanner.skip_whitespace();
+        let c = match scanner.peek() {
+            Some(c) => c,
+            None => break,
+        };
+
+        if c == '"' {
+            terms.push(QueryTerm::Text(scanner.quoted()?));
+            continue;
+        }
+
+        let key = scanner.take_while(is_key_char);
+        if key.text.is_empty() {
+            return Err(QueryParseError::new(format!("Unexpected '{}'", c), scanner.position, 1));
+        }
+
+        let operator_position = scanner.position;
+        match scanner.operator() {
+            Some(operator) => {
+                let value = match scanner.peek() {
+                    Some('"') => scanner.quoted()?,
+                    Some(c) if !c.is_whitespace() => scanner.take_while(|c| !c.is_whitespace()),
+                    _ => {
+                        return Err(QueryParseError::new(
+                            format!("Expected a value after '{}{}'", key.text, operator.as_str()),
+                            scanner.position,
+                            0,
+                        ));
+                    }
+                };
+                terms.push(QueryTerm::Filter { key, operator, operator_position, value });
+            }
+            None => {
+                // Not a filter, so the whole word is free text
+                let rest = scanner.take_while(|c| !c.is_whitespace());
+                terms.push(QueryTerm::Text(Spanned {
+                    text: format!("{}{}", key.text, rest.text),
+                    position: key.position,
+                }));
+            }
+        }
+    }
+
+    Ok(terms)
+}
+
+/// Compiles parsed terms into a `WorkItemQuery` for the context's project
+pub fn compile_query(terms: Vec<QueryTerm>, context: &QueryContext) -> Result<WorkItemQuery, QueryParseError> {
+    let mut compiler = QueryCompiler {
+        context,
+        query: empty_query(context.project_id),
+        seen_keys: HashSet::new(),
+        text: Vec::new(),
+    };
+
+    for term in terms {
+        match term {
+            QueryTerm::Text(text) => compiler.text.push(text.text),
+            QueryTerm::Filter { key, operator, operator_position, value } => {
+                compiler.filter(&key, operator, operator_position, &value)?;
+            }
+        }
+    }
+
+    if !compiler.text.is_empty() {
+        compiler.query.title_contains = Some(compiler.text.join(" "));
+    }
+
+    Ok(compiler.query)
+}
+
+struct Scanner {
+    chars: Vec<char>,
+    position: usize,
+}
+
+impl Scanner {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.position + offset).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(|c| c.is_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> Spanned {
+        let start = self.position;
+        while self.peek().is_some_and(&predicate) {
+            self.position += 1;
+        }
+        Spanned {
+            text: self.chars[start..self.position].iter().collect(),
+            position: start,
+        }
+    }
+
+    /// Reads a `"quoted"` string; `\"` escapes a quote
+    fn quoted(&mut self) -> Result<Spanned, QueryParseError> {
+        let start = self.position;
+        self.position += 1;
+
+        let mut text = String::new();
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.position += 1;
+                    return Ok(Spanned { text, position: start });
+                }
+                Some('\\') if self.peek_at(1) == Some('"') => {
+                    text.push('"');
+                    self.position += 2;
+                }
+                Some(c) => {
+                    text.push(c);
+                    self.position += 1;
+                }
+                None => {
+                    return Err(QueryParseError::new(
+                        "Unterminated quoted string".to_string(),
+                        start,
+                        self.position - start,
+                    ));
+                }
+            }
+        }
+    }
+
+    fn operator(&mut self) -> Option<QueryOperator> {
+        let (operator, width) = match (self.peek(), self.peek_at(1)) {
+            (Some('!'), Some('=')) => (QueryOperator::Ne, 2),
+            (Some('>'), Some('=')) => (QueryOperator::Gte, 2),
+            (Some('<'), Some('=')) => (QueryOperator::Lte, 2),
+            (Some('>'), _) => (QueryOperator::Gt, 1),
+            (Some('<'), _) => (QueryOperator::Lt, 1),
+            (Some(':'), _) | (Some('='), _) => (QueryOperator::Eq, 1),
+            (Some('~'), _) => (QueryOperator::Contains, 1),
+            _ => return None,
+        };
+        self.position += width;
+        Some(operator)
+    }
+}
+
+fn is_key_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_' || c == '.' || c == '-'
+}
+
+struct QueryCompiler<'a> {
+    context: &'a QueryContext<'a>,
+    query: WorkItemQuery,
+    seen_keys: HashSet<&'static str>,
+    text: Vec<String>,
+}
+
+impl QueryCompiler<'_> {
+    fn filter(
+        &mut self,
+        key: &Spanned,
+        operator: QueryOperator,
+        operator_position: usize,
+        value: &Spanned,
+    ) -> Result<(), QueryParseError> {
+        let name = key.text.to_lowercase();
+
+        if let Some(field) = name.strip_prefix("field.") {
+            return self.field_filter(key, field, operator, value);
+        }
+
+        let canonical = match name.as_str() {
+            "type" => "type",
+            "status" => "status",
+            "category" => "category",
+            "priority" => "priority",
+            "assignee" | "assigned_to" => "assignee",
+            "author" | "created_by" => "author",
+            "number" | "num" => "number",
+            "title" => "title",
+            "sort" => "sort",
+            _ => return Err(key.error(format!("Unknown filter '{}'", key.text))),
+        };
+
+        if canonical != "priority" && canonical != "title" {
+            expect_eq(key, operator, operator_position)?;
+            if !self.seen_keys.insert(canonical) {
+                return Err(key.error(format!("Filter '{}' is used more than once", key.text)));
+            }
+        }
+
+        match canonical {
+            "type" => self.type_filter(value),
+            "status" => self.status_filter(value),
+            "category" => self.category_filter(value),
+            "priority" => self.priority_filter(operator, value),
+            "assignee" => {
+                self.query.assigned_to = Some(self.user(value)?);
+                Ok(())
+            }
+            "author" => {
+                self.query.created_by = Some(self.user(value)?);
+                Ok(())
+            }
+            "number" => {
+                self.query.sequence_numbers = Some(split_values(value)?.into_iter().map(|v| v.text).collect());
+                Ok(())
+            }
+            "title" => {
+                expect_eq(key, operator, operator_position)?;
+                self.text.push(value.text.clone());
+                Ok(())
+            }
+            _ => self.sort(value),
+        }
+    }
+
+    fn type_filter(&mut self, value: &Spanned) -> Result<(), QueryParseError> {
+        let mut type_ids = Vec::new();
+        for part in split_values(value)? {
+            let work_item_type = self.context.work_item_types
+                .iter()
+                .find(|t| {
+                    t.id.as_deref() == Some(part.text.as_str())
+                        || t.name.eq_ignore_ascii_case(&part.text)
+                        || t.display_name.eq_ignore_ascii_case(&part.text)
+                })
+                .ok_or_else(|| part.error(format!("Unknown work item type '{}'", part.text)))?;
+            type_ids.extend(work_item_type.id.clone());
+        }
+
+        if type_ids.len() == 1 {
+            self.query.type_id = type_ids.pop();
+        } else {
+            self.query.type_ids = Some(type_ids);
+        }
+        Ok(())
+    }
+
+    fn status_filter(&mut self, value: &Spanned) -> Result<(), QueryParseError> {
+        let parts = split_values(value)?;
+        let mut statuses: Vec<String> = Vec::new();
+
+        for part in &parts {
+            let matching: Vec<String> = self.context.work_item_types
+                .iter()
+                .flat_map(|t| t.allowed_statuses.iter())
+                .filter(|s| s.id.eq_ignore_ascii_case(&part.text) || s.label.eq_ignore_ascii_case(&part.text))
+                .map(|s| s.id.clone())
+                .collect();
+
+            if !matching.is_empty() {
+                for id in matching {
+                    if !statuses.contains(&id) {
+                        statuses.push(id);
+                    }
+                }
+                continue;
+            }
+
+            // `open` and `closed` stand for status categories when no status has that name
+            let categories = match part.text.to_lowercase().as_str() {
+                "open" => vec![StatusCategory::Todo, StatusCategory::InProgress],
+                "closed" => vec![StatusCategory::Done],
+                _ => return Err(part.error(format!("Unknown status '{}'", part.text))),
+            };
+            if parts.len() > 1 {
+                return Err(part.error(format!("'{}' can't be combined with other statuses", part.text)));
+            }
+            if self.query.status_categories.is_some() {
+                return Err(part.error(format!("'{}' can't be combined with a category filter", part.text)));
+            }
+            self.query.status_categories = Some(categories);
+        }
+
+        if !statuses.is_empty() {
+            self.query.statuses = Some(statuses);
+        }
+        Ok(())
+    }
+
+    fn category_filter(&mut self, value: &Spanned) -> Result<(), QueryParseError> {
+        if self.query.status_categories.is_some() {
+            return Err(value.error("Category can't be combined with 'status:open' or 'status:closed'".to_string()));
+        }
+
+        let categories = split_values(value)?
+            .into_iter()
+            .map(|part| match part.text.to_lowercase().replace('-', "_").as_str() {
+                "todo" => Ok(StatusCategory::Todo),
+                "in_progress" => Ok(StatusCategory::InProgress),
+                "done" => Ok(StatusCategory::Done),
+                _ => Err(part.error(format!("Unknown status category '{}'", part.text))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.query.status_categories = Some(categories);
+        Ok(())
+    }
+
+    fn priority_filter(&mut self, operator: QueryOperator, value: &Spanned) -> Result<(), QueryParseError> {
+        let priority = match value.text.parse::<i32>() {
+            Ok(priority) => priority,
+            Err(_) => self.context.work_item_types
+                .iter()
+                .flat_map(|t| t.allowed_priorities.iter())
+                .find(|p| p.id.eq_ignore_ascii_case(&value.text) || p.label.eq_ignore_ascii_case(&value.text))
+                .map(|p| p.value)
+                .ok_or_else(|| value.error(format!("Unknown priority '{}'", value.text)))?,
+        };
+
+        // `>` and `<` become inclusive bounds one past the value, which the largest and
+        // smallest priorities don't have
+        let out_of_range = || value.error(format!("No priority is {} {}", operator.as_str(), priority));
+        let (slot, bound) = match operator {
+            QueryOperator::Eq => (&mut self.query.priority, priority),
+            QueryOperator::Gte => (&mut self.query.priority_min, priority),
+            QueryOperator::Gt => (&mut self.query.priority_min, priority.checked_add(1).ok_or_else(out_of_range)?),
+            QueryOperator::Lte => (&mut self.query.priority_max, priority),
+            QueryOperator::Lt => (&mut self.query.priority_max, priority.checked_sub(1).ok_or_else(out_of_range)?),
+            QueryOperator::Ne | QueryOperator::Contains => {
+                return Err(value.error(format!("Operator '{}' is not supported for priority", operator.as_str())));
+            }
+        };
+
+        if slot.is_some() {
+            return Err(value.error("Priority bound is given more than once".to_string()));
+        }
+        *slot = Some(bound);
+        Ok(())
+    }
+
+    fn field_filter(
+        &mut self,
+        key: &Spanned,
+        field: &str,
+        operator: QueryOperator,
+        value: &Spanned,
+    ) -> Result<(), QueryParseError> {
+        let (field_id, is_assignment_field) = self.resolve_field(field)
+            .ok_or_else(|| key.error(format!("Unknown field '{}'", field)))?;
+
+        let text = value.text.clone();
+        let mut values = Vec::new();
+        let (operator, value) = match operator {
+            QueryOperator::Eq if text.is_empty() => (FieldValueOperator::IsEmpty, text),
+            QueryOperator::Ne if text.is_empty() => (FieldValueOperator::IsNotEmpty, text),
+            QueryOperator::Eq if text.contains(',') => {
+                values = split_values(value)?.into_iter().map(|v| v.text).collect();
+                (FieldValueOperator::In, String::new())
+            }
+            QueryOperator::Eq if text.len() > 1 && text.ends_with('*') => {
+                (FieldValueOperator::StartsWith, text[..text.len() - 1].to_string())
+            }
+            QueryOperator::Eq => (FieldValueOperator::Eq, text),
+            QueryOperator::Ne => (FieldValueOperator::Ne, text),
+            QueryOperator::Gt => (FieldValueOperator::Gt, text),
+            QueryOperator::Gte => (FieldValueOperator::Gte, text),
+            QueryOperator::Lt => (FieldValueOperator::Lt, text),
+            QueryOperator::Lte => (FieldValueOperator::Lte, text),
+            QueryOperator::Contains => (FieldValueOperator::Contains, text),
+        };
+
+        self.query.field_value_queries.get_or_insert_with(Vec::new).push(FieldValueQuery {
+            field_id,
+            is_assignment_field,
+            operator,
+            value,
+            values,
+        });
+        Ok(())
+    }
+
+    fn sort(&mut self, value: &Spanned) -> Result<(), QueryParseError> {
+        let (direction, name) = match value.text.strip_prefix('-') {
+            Some(name) => (SortDirection::Desc, name),
+            None => (SortDirection::Asc, value.text.strip_prefix('+').unwrap_or(&value.text)),
+        };
+
+        let sort_field = match name.to_lowercase().as_str() {
+            "created" | "created_at" => SortField::CreatedAt,
+            "updated" | "updated_at" => SortField::UpdatedAt,
+            "title" => SortField::Title,
+            "status" => SortField::Status,
+            "category" => SortField::StatusCategory,
+            "priority" => SortField::Priority,
+            "type" => SortField::TypeId,
+            other => match other.strip_prefix("field.").and_then(|field| self.resolve_field(field)) {
+                Some((field_id, is_assignment_field)) => SortField::FieldValue { field_id, is_assignment_field },
+                None => return Err(value.error(format!("Unknown sort field '{}'", name))),
+            },
+        };
+
+        self.query.sort_by = Some(sort_field);
+        self.query.sort_direction = Some(direction);
+        Ok(())
+    }
+
+    fn user(&self, value: &Spanned) -> Result<String, QueryParseError> {
+        if value.text.eq_ignore_ascii_case("me") {
+            return self.context.current_user
+                .map(|user| user.to_string())
+                .ok_or_else(|| value.error("'me' can't be used without a current user".to_string()));
+        }
+        Ok(value.text.clone())
+    }
+
+    /// Finds a custom or assignment field by id or label, returning its id and whether it's an assignment field
+    fn resolve_field(&self, name: &str) -> Option<(String, bool)> {
+        let types = self.context.work_item_types;
+        types.iter()
+            .flat_map(|t| t.work_item_fields.iter())
+            .find(|f| f.id.eq_ignore_ascii_case(name) || f.label.eq_ignore_ascii_case(name))
+            .map(|f| (f.id.clone(), false))
+            .or_else(|| {
+                types.iter()
+                    .flat_map(|t| t.assignment_field_definitions.iter())
+                    .find(|f| f.id.eq_ignore_ascii_case(name) || f.label.eq_ignore_ascii_case(name))
+                    .map(|f| (f.id.clone(), true))
+            })
+    }
+}
+
+fn expect_eq(key: &Spanned, operator: QueryOperator, operator_position: usize) -> Result<(), QueryParseError> {
+    if operator != QueryOperator::Eq {
+        return Err(QueryParseError::new(
+            format!("Operator '{}' is not supported for '{}'", operator.as_str(), key.text),
+            operator_position,
+            operator.as_str().len(),
+        ));
+    }
+    Ok(())
+}
+
+/// Splits a comma separated value into its parts
+fn split_values(value: &Spanned) -> Result<Vec<Spanned>, QueryParseError> {
+    let mut parts = Vec::new();
+    let mut position = value.position;
+    for part in value.text.split(',') {
+        let spanned = Spanned { text: part.to_string(), position };
+        if part.is_empty() {
+            return Err(spanned.error("Expected a value".to_string()));
+        }
+        position += spanned.len() + 1;
+        parts.push(spanned);
+    }
+    Ok(parts)
+}
+
+fn empty_query(project_id: &str) -> WorkItemQuery {
+    WorkItemQuery {
+        project_id: project_id.to_string(),
+        statuses: None,
+        status_categories: None,
+        priority: None,
+        priority_min: None,
+        priority_max: None,
+        type_id: None,
+        type_ids: None,
+        assigned_to: None,
+        created_by: None,
+        title_contains: None,
+        sequence_numbers: None,
+        field_value_queries: None,
+        page: None,
+        page_size: None,
+        limit: None,
+        offset: None,
+        sort_by: None,
+        sort_direction: None,
+        include_archived: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+    use crate::test_support::{status, priority, assignment_field, field};
+
+    fn work_item_type(id: &str, statuses: &[(&str, &str)]) -> WorkItemTypeModel {
+        WorkItemTypeModel {
+            allowed_statuses: statuses.iter().map(|(id, label)| status(id, label)).collect(),
+            allowed_priorities: vec![priority("low", "Low", 1), priority("high", "High", 3)],
+            assignment_field_definitions: vec![assignment_field("reviewer", "Reviewer")],
+            work_item_fields: vec![
+                field("story_points", "Story Points", "number"),
+                field("component", "Component", "text"),
+            ],
+            ..crate::test_support::work_item_type(id)
+        }
+    }
+
+    fn project_types() -> Vec<WorkItemTypeModel> {
+        vec![
+            work_item_type("bug", &[("todo", "To Do"), ("in_review", "In Review"), ("done", "Done")]),
+            work_item_type("task", &[("todo", "To Do"), ("done", "Done")]),
+        ]
+    }
+
+    fn compile(input: &str, work_item_types: &[WorkItemTypeModel], current_user: Option<&str>) -> Result<Value, QueryParseError> {
+        let context = QueryContext { project_id: "project", current_user, work_item_types };
+        let query = compile_query(parse_query(input)?, &context)?;
+        Ok(serde_json::to_value(query).unwrap())
+    }
+
+    /// Checks that each query compiles to the empty query with the given keys set
+    fn assert_compiles(cases: &[(&str, Value)], work_item_types: &[WorkItemTypeModel]) {
+        for (input, changes) in cases {
+            let mut expected = serde_json::to_value(empty_query("project")).unwrap();
+            for (key, value) in changes.as_object().unwrap() {
+                expected[key] = value.clone();
+            }
+            let actual = compile(input, work_item_types, Some("u1"))
+                .unwrap_or_else(|e| panic!("{}: {}", input, e));
+            assert_eq!(actual, expected, "{}", input);
+        }
+    }
+
+    fn field_query(field_id: &str, is_assignment_field: bool, operator: &str, value: &str, values: &[&str]) -> Value {
+        json!([{
+            "field_id": field_id,
+            "is_assignment_field": is_assignment_field,
+            "operator": operator,
+            "value": value,
+            "values": values,
+        }])
+    }
+
+    #[test]
+    fn compiles_filters() {
+        assert_compiles(&[
+            ("type:bug", json!({ "type_id": "bug" })),
+            ("type:BUG,task", json!({ "type_ids": ["bug", "task"] })),
+            ("status:todo,done", json!({ "statuses": ["todo", "done"] })),
+            ("status:\"In Review\"", json!({ "statuses": ["in_review"] })),
+            ("status=in_review", json!({ "statuses": ["in_review"] })),
+            ("category:in-progress,done", json!({ "status_categories": ["in_progress", "done"] })),
+            ("priority:2", json!({ "priority": 2 })),
+            ("priority=high", json!({ "priority": 3 })),
+            ("priority>=2 priority<=4", json!({ "priority_min": 2, "priority_max": 4 })),
+            ("priority>Low priority<high", json!({ "priority_min": 2, "priority_max": 2 })),
+            ("assignee:me", json!({ "assigned_to": "u1" })),
+            ("created_by:ME", json!({ "created_by": "u1" })),
+            ("assigned_to:bob author:alice", json!({ "assigned_to": "bob", "created_by": "alice" })),
+            ("number:B-1,B-2", json!({ "sequence_numbers": ["B-1", "B-2"] })),
+            ("login \"crash on save\" title:\"a \\\"b\\\"\"", json!({ "title_contains": "login crash on save a \"b\"" })),
+            ("-1", json!({ "title_contains": "-1" })),
+        ], &project_types());
+    }
+
+    #[test]
+    fn compiles_field_filters() {
+        assert_compiles(&[
+            ("field.story_points>5", json!({ "field_value_queries": field_query("story_points", false, "gt", "5", &[]) })),
+            ("field.story_points<=8", json!({ "field_value_queries": field_query("story_points", false, "lte", "8", &[]) })),
+            ("field.component~auth", json!({ "field_value_queries": field_query("component", false, "contains", "auth", &[]) })),
+            ("field.component:auth*", json!({ "field_value_queries": field_query("component", false, "starts_with", "auth", &[]) })),
+            ("field.Component:\"user auth*\"", json!({ "field_value_queries": field_query("component", false, "starts_with", "user auth", &[]) })),
+            ("field.component:*", json!({ "field_value_queries": field_query("component", false, "eq", "*", &[]) })),
+            ("field.component:ui,api", json!({ "field_value_queries": field_query("component", false, "in", "", &["ui", "api"]) })),
+            ("field.component!=ui", json!({ "field_value_queries": field_query("component", false, "ne", "ui", &[]) })),
+            ("field.reviewer:\"\"", json!({ "field_value_queries": field_query("reviewer", true, "is_empty", "", &[]) })),
+            ("field.Reviewer!=\"\"", json!({ "field_value_queries": field_query("reviewer", true, "is_not_empty", "", &[]) })),
+        ], &project_types());
+    }
+
+    #[test]
+    fn open_and_closed_are_categories_unless_a_status_has_that_name() {
+        assert_compiles(&[
+            ("status:open", json!({ "status_categories": ["todo", "in_progress"] })),
+            ("status:Closed", json!({ "status_categories": ["done"] })),
+        ], &project_types());
+
+        let mut types = project_types();
+        types.push(work_item_type("support", &[("open", "Open"), ("resolved", "Closed")]));
+        assert_compiles(&[
+            ("status:open", json!({ "statuses": ["open"] })),
+            ("status:closed,todo", json!({ "statuses": ["resolved", "todo"] })),
+        ], &types);
+    }
+
+    #[test]
+    fn compiles_sort_orders() {
+        assert_compiles(&[
+            ("sort:-updated", json!({ "sort_by": { "type": "updated_at" }, "sort_direction": "desc" })),
+            ("sort:created_at", json!({ "sort_by": { "type": "created_at" }, "sort_direction": "asc" })),
+            ("sort:+Priority", json!({ "sort_by": { "type": "priority" }, "sort_direction": "asc" })),
+            ("sort:category", json!({ "sort_by": { "type": "status_category" }, "sort_direction": "asc" })),
+            ("sort:-type", json!({ "sort_by": { "type": "type_id" }, "sort_direction": "desc" })),
+            (
+                "sort:field.story_points",
+                json!({ "sort_by": { "type": "field_value", "field_id": "story_points", "is_assignment_field": false }, "sort_direction": "asc" }),
+            ),
+            (
+                "sort:-field.Reviewer",
+                json!({ "sort_by": { "type": "field_value", "field_id": "reviewer", "is_assignment_field": true }, "sort_direction": "desc" }),
+            ),
+        ], &project_types());
+    }
+
+    #[test]
+    fn reports_errors_with_their_position() {
+        let cases = [
+            (">3", "Unexpected '>'", 0, 1),
+            ("\"abc", "Unterminated quoted string", 0, 4),
+            ("status:", "Expected a value after 'status:'", 7, 0),
+            ("typo:x", "Unknown filter 'typo'", 0, 4),
+            ("type:bug type:task", "Filter 'type' is used more than once", 9, 4),
+            ("assignee:me assigned_to:bob", "Filter 'assigned_to' is used more than once", 12, 11),
+            ("sort:title sort:-title", "Filter 'sort' is used more than once", 11, 4),
+            ("type>bug", "Operator '>' is not supported for 'type'", 4, 1),
+            ("title!=x", "Operator '!=' is not supported for 'title'", 5, 2),
+            ("type:bug,epic", "Unknown work item type 'epic'", 9, 4),
+            ("status:nope", "Unknown status 'nope'", 7, 4),
+            ("status:todo,,done", "Expected a value", 12, 0),
+            ("status:open,todo", "'open' can't be combined with other statuses", 7, 4),
+            ("status:open category:done", "Category can't be combined with 'status:open' or 'status:closed'", 21, 4),
+            ("category:later", "Unknown status category 'later'", 9, 5),
+            ("priority:urgent", "Unknown priority 'urgent'", 9, 6),
+            ("priority~3", "Operator '~' is not supported for priority", 9, 1),
+            ("priority>=1 priority>2", "Priority bound is given more than once", 21, 1),
+            ("priority>2147483647", "No priority is > 2147483647", 9, 10),
+            ("priority<-2147483648", "No priority is < -2147483648", 9, 11),
+            ("field.nope:1", "Unknown field 'nope'", 0, 10),
+            ("sort:size", "Unknown sort field 'size'", 5, 4),
+            ("sort:-field.nope", "Unknown sort field 'field.nope'", 5, 11),
+        ];
+        for (input, message, position, length) in cases {
+            let error = compile(input, &project_types(), Some("u1")).unwrap_err();
+            assert_eq!((error.message.as_str(), error.position, error.length), (message, position, length), "{}", input);
+        }
+
+        let error = compile("bug assignee:me", &project_types(), None).unwrap_err();
+        assert_eq!(
+            (error.message.as_str(), error.position, error.length),
+            ("'me' can't be used without a current user", 13, 2)
+        );
+    }
+}
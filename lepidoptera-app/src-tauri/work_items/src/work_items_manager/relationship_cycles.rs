@@ -0,0 +1,132 @@
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use crate::entities::WorkItemRelationship;
+use crate::errors::RelationshipCycleError;
+use crate::models::{RelationshipCycle, RelationshipGraph, RelationshipType};
+
+type Adjacency<'a> = BTreeMap<&'a str, Vec<&'a str>>;
+
+/// Checks that adding `relationship` to the existing active relationships keeps its graph acyclic.
+///
+/// On failure the error holds the path the new edge would close, starting and ending with its
+/// `from` work item.
+pub fn validate_no_cycle(
+    existing: &[WorkItemRelationship],
+    relationship: &WorkItemRelationship,
+) -> Result<(), RelationshipCycleError> {
+    let edge = RelationshipType::from_str(&relationship.relationship_type)
+        .and_then(|t| t.graph_edge(&relationship.source_work_item_id, &relationship.target_work_item_id));
+    let (graph, from, to) = match edge {
+        Some(edge) => edge,
+        None => return Ok(()),
+    };
+
+    if from == to {
+        return Err(RelationshipCycleError { graph, path: vec![from.to_string(), to.to_string()] });
+    }
+
+    let adjacency = build_adjacency(existing, graph);
+    match find_path(&adjacency, to, from) {
+        Some(path) => {
+            let mut cycle = vec![from.to_string()];
+            cycle.extend(path.into_iter().map(|id| id.to_string()));
+            Err(RelationshipCycleError { graph, path: cycle })
+        }
+        None => Ok(()),
+    }
+}
+
+/// Lists the cycles in the hierarchy and blocking graphs formed by `relationships`.
+///
+/// Each cycle is reported once, rotated to start at its smallest work item id.
+pub fn find_cycles(relationships: &[WorkItemRelationship]) -> Vec<RelationshipCycle> {
+    let mut cycles = Vec::new();
+
+    for graph in [RelationshipGraph::Hierarchy, RelationshipGraph::Blocking] {
+        let adjacency = build_adjacency(relationships, graph);
+        let mut visited: HashSet<&str> = HashSet::new();
+        let mut seen: HashSet<Vec<&str>> = HashSet::new();
+
+        for &start in adjacency.keys() {
+            if !visited.contains(start) {
+                let mut stack = Vec::new();
+                collect_cycles(&adjacency, start, &mut visited, &mut stack, &mut seen, graph, &mut cycles);
+            }
+        }
+    }
+
+    cycles
+}
+
+fn build_adjacency(relationships: &[WorkItemRelationship], graph: RelationshipGraph) -> Adjacency<'_> {
+    let mut adjacency: Adjacency = BTreeMap::new();
+    for relationship in relationships.iter().filter(|r| r.is_active) {
+        let edge = RelationshipType::from_str(&relationship.relationship_type)
+            .and_then(|t| t.graph_edge(&relationship.source_work_item_id, &relationship.target_work_item_id));
+        if let Some((edge_graph, from, to)) = edge {
+            if edge_graph == graph {
+                adjacency.entry(from).or_default().push(to);
+            }
+        }
+    }
+    adjacency
+}
+
+/// Breadth-first search for the shortest path from `start` to `goal`, inclusive of both ends
+fn find_path<'a>(adjacency: &Adjacency<'a>, start: &'a str, goal: &str) -> Option<Vec<&'a str>> {
+    let mut predecessors: HashMap<&str, &str> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    let mut visited = HashSet::from([start]);
+
+    while let Some(node) = queue.pop_front() {
+        if node == goal {
+            let mut path = vec![node];
+            let mut current = node;
+            while let Some(&previous) = predecessors.get(current) {
+                path.push(previous);
+                current = previous;
+            }
+            path.reverse();
+            return Some(path);
+        }
+
+        for &next in adjacency.get(node).into_iter().flatten() {
+            if visited.insert(next) {
+                predecessors.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+fn collect_cycles<'a>(
+    adjacency: &Adjacency<'a>,
+    node: &'a str,
+    visited: &mut HashSet<&'a str>,
+    stack: &mut Vec<&'a str>,
+    seen: &mut HashSet<Vec<&'a str>>,
+    graph: RelationshipGraph,
+    cycles: &mut Vec<RelationshipCycle>,
+) {
+    visited.insert(node);
+    stack.push(node);
+
+    for &next in adjacency.get(node).into_iter().flatten() {
+        if let Some(position) = stack.iter().position(|&id| id == next) {
+            // Back edge: the stack from `next` onwards is a cycle
+            let mut cycle: Vec<&str> = stack[position..].to_vec();
+            let smallest = cycle.iter().enumerate().min_by_key(|(_, id)| **id).map(|(i, _)| i).unwrap_or(0);
+            cycle.rotate_left(smallest);
+            if seen.insert(cycle.clone()) {
+                let mut work_item_ids: Vec<String> = cycle.iter().map(|id| id.to_string()).collect();
+                work_item_ids.push(cycle[0].to_string());
+                cycles.push(RelationshipCycle { graph, work_item_ids });
+            }
+        } else if !visited.contains(next) {
+            collect_cycles(adjacency, next, visited, stack, seen, graph, cycles);
+        }
+    }
+
+    stack.pop();
+}
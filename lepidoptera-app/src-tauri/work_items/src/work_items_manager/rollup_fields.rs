@@ -0,0 +1,585 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use db::{ToSql, Connection};
+use crate::models::{WorkItemTypeModel, WorkItemFieldValueModel, FieldDefinition, RelationshipType};
+use crate::schemas::{RollupDefinition, RollupFunction, StatusCategory};
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_items_manager::list_work_items::add_param;
+use anyhow::{Result, Context};
+
+/// How many levels of rollups over rollups are followed before giving up
+const MAX_ROLLUP_DEPTH: usize = 5;
+
+/// Validates the rollup fields of a work item type
+pub fn validate_rollup_fields(work_item_type: &WorkItemTypeModel) -> Result<()> {
+    for field in &work_item_type.work_item_fields {
+        match (field.field_type == "rollup", &field.rollup) {
+            (true, None) => {
+                return Err(anyhow::anyhow!("Rollup field '{}' has no rollup definition", field.label));
+            }
+            (false, Some(_)) => {
+                return Err(anyhow::anyhow!(
+                    "Field '{}' has a rollup definition but its type is '{}'",
+                    field.label,
+                    field.field_type
+                ));
+            }
+            (true, Some(rollup)) => {
+                let needs_child_field = matches!(
+                    rollup.function,
+                    RollupFunction::Sum | RollupFunction::Min | RollupFunction::Max
+                );
+                if needs_child_field && rollup.child_field_id.as_deref().unwrap_or_default().is_empty() {
+                    return Err(anyhow::anyhow!("Rollup field '{}' needs a child field to aggregate", field.label));
+                }
+            }
+            (false, None) => {}
+        }
+    }
+    Ok(())
+}
+
+/// The rollup fields and done statuses of a project, used to build rollup SQL
+pub struct RollupContext {
+    project_id: String,
+    /// Rollup definitions by field id, with the type that defines each
+    rollups: HashMap<String, Vec<(String, RollupDefinition)>>,
+    /// (type_id, status) pairs in the done category
+    done_statuses: Vec<(String, String)>,
+}
+
+impl RollupContext {
+    pub fn load(
+        work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+        project_id: &str,
+    ) -> Result<Self> {
+        let types = work_item_types_repository.find_by_project_id(project_id)?
+            .into_iter()
+            .map(WorkItemTypeModel::from_entity)
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::from_types(project_id, &types))
+    }
+
+    pub fn from_types(project_id: &str, types: &[WorkItemTypeModel]) -> Self {
+        let mut rollups: HashMap<String, Vec<(String, RollupDefinition)>> = HashMap::new();
+        let mut done_statuses = Vec::new();
+        for work_item_type in types {
+            let type_id = match &work_item_type.id {
+                Some(id) => id.clone(),
+                None => continue,
+            };
+            for field in &work_item_type.work_item_fields {
+                if let Some(rollup) = &field.rollup {
+                    rollups.entry(field.id.clone()).or_default().push((type_id.clone(), rollup.clone()));
+                }
+            }
+            for status in &work_item_type.allowed_statuses {
+                if work_item_type.status_category(&status.id) == Some(StatusCategory::Done) {
+                    done_statuses.push((type_id.clone(), status.id.clone()));
+                }
+            }
+        }
+        Self { project_id: project_id.to_string(), rollups, done_statuses }
+    }
+
+    /// Whether any type in the project computes this field as a rollup
+    pub fn is_rollup(&self, field_id: &str) -> bool {
+        self.rollups.contains_key(field_id)
+    }
+}
+
+/// What a common table expression of a `RollupQuery` computes: a field's value at a depth,
+/// or a type's rollup of a field at a depth
+#[derive(PartialEq, Eq, Hash)]
+enum RollupExpression {
+    Value { field_id: String, depth: usize },
+    Rollup { type_id: String, field_id: String, depth: usize },
+}
+
+/// The rollup SQL of one query, as common table expressions with an `id` and a `value`
+/// column for the project's work items.
+///
+/// Each field value and each type's rollup is computed once per depth and shared by every
+/// rollup that uses it, so the SQL grows with the number of rollup fields rather than
+/// exponentially with their nesting. Join the expressions a query needs on `id` and put
+/// `with_clause()` in front of it.
+pub struct RollupQuery<'a> {
+    context: &'a RollupContext,
+    /// Expressions in the order they depend on each other
+    ctes: Vec<String>,
+    names: HashMap<RollupExpression, String>,
+    has_links: bool,
+}
+
+impl<'a> RollupQuery<'a> {
+    pub fn new(context: &'a RollupContext) -> Self {
+        Self { context, ctes: Vec::new(), names: HashMap::new(), has_links: false }
+    }
+
+    /// Whether any type in the project computes this field as a rollup
+    pub fn is_rollup(&self, field_id: &str) -> bool {
+        self.context.is_rollup(field_id)
+    }
+
+    /// The WITH clause defining every expression added so far
+    pub fn with_clause(&self) -> String {
+        if self.ctes.is_empty() {
+            String::new()
+        } else {
+            format!("WITH {}", self.ctes.join(",\n"))
+        }
+    }
+
+    /// Expression with the numeric value of a field: the rollup for types that compute the
+    /// field, the stored number for the others
+    pub fn field_value(
+        &mut self,
+        field_id: &str,
+        params: &mut Vec<Box<dyn ToSql>>,
+        param_index: &mut usize,
+    ) -> String {
+        self.value_at(field_id, 0, params, param_index)
+    }
+
+    /// Expression with the value of a rollup field of `type_id` for the work items of that type
+    pub fn rollup(
+        &mut self,
+        type_id: &str,
+        field_id: &str,
+        rollup: &RollupDefinition,
+        params: &mut Vec<Box<dyn ToSql>>,
+        param_index: &mut usize,
+    ) -> String {
+        self.rollup_at(type_id, field_id, rollup, 0, params, param_index)
+    }
+
+    fn value_at(
+        &mut self,
+        field_id: &str,
+        depth: usize,
+        params: &mut Vec<Box<dyn ToSql>>,
+        param_index: &mut usize,
+    ) -> String {
+        let key = RollupExpression::Value { field_id: field_id.to_string(), depth };
+        if let Some(name) = self.names.get(&key) {
+            return name.clone();
+        }
+
+        let context = self.context;
+        let rollups = match context.rollups.get(field_id) {
+            Some(rollups) if depth < MAX_ROLLUP_DEPTH => rollups.as_slice(),
+            _ => &[],
+        };
+        let mut joins = Vec::new();
+        let mut when_clauses = Vec::new();
+        for (i, (type_id, rollup)) in rollups.iter().enumerate() {
+            let rollup_name = self.rollup_at(type_id, field_id, rollup, depth, params, param_index);
+            joins.push(format!("LEFT JOIN {rollup_name} r{i} ON r{i}.id = w.id"));
+            when_clauses.push(format!(
+                "WHEN {} THEN r{i}.value",
+                add_param(params, param_index, Box::new(type_id.clone()))
+            ));
+        }
+
+        let stored = format!(
+            "(SELECT fv.value_number FROM work_item_field_values fv WHERE fv.work_item_id = w.id AND fv.field_id = {} AND fv.is_assignment_field = 0 AND fv.is_active = 1)",
+            add_param(params, param_index, Box::new(field_id.to_string())),
+        );
+        let value = if when_clauses.is_empty() {
+            stored
+        } else {
+            format!("CASE w.type_id {} ELSE {} END", when_clauses.join(" "), stored)
+        };
+
+        let name = format!("rollup_value_{}", self.ctes.len());
+        self.ctes.push(format!(
+            "{name}(id, value) AS (SELECT w.id, {value} FROM work_items w {} WHERE w.project_id = {})",
+            joins.join(" "),
+            add_param(params, param_index, Box::new(context.project_id.clone())),
+        ));
+        self.names.insert(key, name.clone());
+        name
+    }
+
+    fn rollup_at(
+        &mut self,
+        type_id: &str,
+        field_id: &str,
+        rollup: &RollupDefinition,
+        depth: usize,
+        params: &mut Vec<Box<dyn ToSql>>,
+        param_index: &mut usize,
+    ) -> String {
+        let key = RollupExpression::Rollup { type_id: type_id.to_string(), field_id: field_id.to_string(), depth };
+        if let Some(name) = self.names.get(&key) {
+            return name.clone();
+        }
+
+        let mut children = "c.archived_at IS NULL".to_string();
+        if !rollup.child_type_ids.is_empty() {
+            let placeholders: Vec<String> = rollup.child_type_ids.iter()
+                .map(|type_id| add_param(params, param_index, Box::new(type_id.clone())))
+                .collect();
+            children.push_str(&format!(" AND c.type_id IN ({})", placeholders.join(", ")));
+        }
+
+        let (child_values, value) = match rollup.function {
+            RollupFunction::Count => (String::new(), "COUNT(c.id)".to_string()),
+            RollupFunction::PercentDone => {
+                let done = if self.context.done_statuses.is_empty() {
+                    "0".to_string()
+                } else {
+                    self.context.done_statuses.iter()
+                        .map(|(type_id, status)| {
+                            format!(
+                                "(c.type_id = {} AND c.status = {})",
+                                add_param(params, param_index, Box::new(type_id.clone())),
+                                add_param(params, param_index, Box::new(status.clone())),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(" OR ")
+                };
+                (
+                    String::new(),
+                    format!(
+                        "CASE WHEN COUNT(c.id) = 0 THEN NULL ELSE 100.0 * SUM(CASE WHEN {} THEN 1 ELSE 0 END) / COUNT(c.id) END",
+                        done
+                    ),
+                )
+            }
+            RollupFunction::Sum | RollupFunction::Min | RollupFunction::Max => {
+                let aggregate = match rollup.function {
+                    RollupFunction::Sum => "SUM",
+                    RollupFunction::Min => "MIN",
+                    _ => "MAX",
+                };
+                let child_field_id = rollup.child_field_id.as_deref().unwrap_or_default();
+                let child_values = self.value_at(child_field_id, depth + 1, params, param_index);
+                (
+                    format!("LEFT JOIN {child_values} cv ON cv.id = c.id"),
+                    format!("{}(cv.value)", aggregate),
+                )
+            }
+        };
+
+        let links = self.links();
+        let name = format!("rollup_{}", self.ctes.len());
+        self.ctes.push(format!(
+            "{name}(id, value) AS (
+                SELECT p.id, {value}
+                FROM work_items p
+                LEFT JOIN {links} l ON l.parent_id = p.id
+                LEFT JOIN work_items c ON c.id = l.child_id AND {children}
+                {child_values}
+                WHERE p.project_id = {} AND p.type_id = {}
+                GROUP BY p.id
+            )",
+            add_param(params, param_index, Box::new(self.context.project_id.clone())),
+            add_param(params, param_index, Box::new(type_id.to_string())),
+        ));
+        self.names.insert(key, name.clone());
+        name
+    }
+
+    /// Expression with the parent/child links, whichever way round they are recorded
+    fn links(&mut self) -> &'static str {
+        const NAME: &str = "rollup_links";
+        if !self.has_links {
+            self.ctes.push(format!(
+                "{NAME}(parent_id, child_id) AS (
+                    SELECT source_work_item_id, target_work_item_id FROM work_item_relationships
+                    WHERE relationship_type = '{parent}' AND is_active = 1
+                    UNION
+                    SELECT target_work_item_id, source_work_item_id FROM work_item_relationships
+                    WHERE relationship_type = '{child}' AND is_active = 1
+                )",
+                parent = RelationshipType::Parent.as_str(),
+                child = RelationshipType::Child.as_str(),
+            ));
+            self.has_links = true;
+        }
+        NAME
+    }
+}
+
+/// Computes the rollup fields of `work_item_type` for the given work items.
+///
+/// Only fields in `field_ids` are computed when it is given. Work items whose rollup has no
+/// value (e.g. a sum over no children) get no field value for it.
+pub fn compute_rollups(
+    conn: &Connection,
+    context: &RollupContext,
+    work_item_type: &WorkItemTypeModel,
+    work_item_ids: &[String],
+    field_ids: Option<&HashSet<String>>,
+) -> Result<HashMap<String, Vec<WorkItemFieldValueModel>>> {
+    let fields: Vec<_> = work_item_type.work_item_fields.iter()
+        .filter(|field| field.rollup.is_some())
+        .filter(|field| field_ids.is_none_or(|ids| ids.contains(&field.id)))
+        .collect();
+
+    let mut rollups: HashMap<String, Vec<WorkItemFieldValueModel>> = HashMap::new();
+    if fields.is_empty() || work_item_ids.is_empty() {
+        return Ok(rollups);
+    }
+
+    let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+    let mut param_index = 1;
+    let type_id = work_item_type.id.clone().unwrap_or_default();
+    let mut query = RollupQuery::new(context);
+    let joins: Vec<String> = fields.iter()
+        .enumerate()
+        .map(|(i, field)| {
+            // The field may be a stored value in other types, but this type computes it
+            let rollup = field.rollup.as_ref().expect("filtered to rollup fields");
+            let name = query.rollup(&type_id, &field.id, rollup, &mut params, &mut param_index);
+            format!("LEFT JOIN {name} r{i} ON r{i}.id = work_items.id")
+        })
+        .collect();
+    let columns: Vec<String> = (0..fields.len()).map(|i| format!("r{i}.value")).collect();
+    let id_placeholders: Vec<String> = work_item_ids.iter()
+        .map(|id| add_param(&mut params, &mut param_index, Box::new(id.clone())))
+        .collect();
+    let type_placeholder = add_param(&mut params, &mut param_index, Box::new(type_id));
+
+    let sql = format!(
+        "{} SELECT work_items.id, work_items.project_id, {} FROM work_items {} WHERE work_items.id IN ({}) AND work_items.type_id = {}",
+        query.with_clause(),
+        columns.join(", "),
+        joins.join(" "),
+        id_placeholders.join(", "),
+        type_placeholder
+    );
+    let param_refs: Vec<&dyn ToSql> = params.iter().map(|p| p.as_ref()).collect();
+    let rows = conn.query(&sql, &param_refs, |row| {
+        let values = (0..fields.len())
+            .map(|i| row.get::<_, Option<f64>>(i + 2))
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, values))
+    })
+    .context("Failed to compute rollup fields")?;
+
+    for (work_item_id, project_id, values) in rows {
+        for (field, value) in fields.iter().zip(values) {
+            let value = match value {
+                Some(value) => value,
+                None => continue,
+            };
+            rollups.entry(work_item_id.clone()).or_default().push(WorkItemFieldValueModel {
+                id: None,
+                project_id: project_id.clone(),
+                work_item_id: work_item_id.clone(),
+                field_id: field.id.clone(),
+                is_assignment_field: false,
+                value: format_computed_number(value),
+                created_at: String::new(),
+                updated_at: None,
+                created_by: String::new(),
+                updated_by: None,
+                is_active: true,
+                field_definition: Some(FieldDefinition::WorkItemField((*field).clone())),
+            });
+        }
+    }
+
+    Ok(rollups)
+}
+
+/// Whole numbers are shown without decimals, anything else with at most two
+pub fn format_computed_number(value: f64) -> String {
+    if value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        let rounded = format!("{:.2}", value);
+        rounded.trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::RollupFunction;
+    use crate::test_support::{work_item_type, field, rollup_field, status};
+
+    /// A type that sums `remaining` over its children, like the SAFe template's estimates
+    fn summing_type(id: &str) -> WorkItemTypeModel {
+        WorkItemTypeModel {
+            work_item_fields: vec![rollup_field("remaining", RollupFunction::Sum, Some("remaining"))],
+            ..work_item_type(id)
+        }
+    }
+
+    #[test]
+    fn nested_rollups_share_each_level() {
+        let types: Vec<_> = ["epic", "capability", "feature", "story"].into_iter().map(summing_type).collect();
+        let context = RollupContext::from_types("project", &types);
+        let mut query = RollupQuery::new(&context);
+        let mut params = Vec::new();
+        let mut param_index = 1;
+
+        let values = query.field_value("remaining", &mut params, &mut param_index);
+        let again = query.field_value("remaining", &mut params, &mut param_index);
+
+        assert_eq!(values, again);
+        // The links, a value per depth and a rollup per type below the last depth
+        assert_eq!(query.ctes.len(), 1 + (MAX_ROLLUP_DEPTH + 1) + types.len() * MAX_ROLLUP_DEPTH);
+        assert_eq!(params.len(), (MAX_ROLLUP_DEPTH + 1) * 2 + types.len() * MAX_ROLLUP_DEPTH * 3);
+        assert_eq!(param_index, params.len() + 1);
+        assert_eq!(query.with_clause().matches("rollup_links(parent_id, child_id) AS").count(), 1);
+    }
+
+    /// An in-memory database with the app's schema and project `project`
+    fn database() -> Connection {
+        let mut conn = Connection::new(":memory:").unwrap();
+        conn.inner()
+            .execute("INSERT INTO projects (id, created_at, name) VALUES ('project', 'now', 'Project')", [])
+            .unwrap();
+        conn
+    }
+
+    fn insert_work_item(conn: &mut Connection, id: &str, type_id: &str, status: &str, archived: bool) {
+        conn.inner().execute(
+            "INSERT INTO work_items (id, title, status, created_at, priority, created_by, project_id, type_id, archived_at)
+             VALUES (?1, ?1, ?2, 'now', 0, 'test', 'project', ?3, ?4)",
+            rusqlite::params![id, status, type_id, archived.then_some("now")],
+        ).unwrap();
+    }
+
+    fn insert_number(conn: &mut Connection, work_item_id: &str, field_id: &str, value: f64) {
+        conn.inner().execute(
+            "INSERT INTO work_item_field_values (id, project_id, work_item_id, field_id, value, created_at, created_by, value_number)
+             VALUES (?1 || ?2, 'project', ?1, ?2, ?3, 'now', 'test', ?3)",
+            rusqlite::params![work_item_id, field_id, value],
+        ).unwrap();
+    }
+
+    fn insert_link(conn: &mut Connection, parent_id: &str, child_id: &str) {
+        conn.inner().execute(
+            "INSERT INTO work_item_relationships (id, project_id, source_work_item_id, target_work_item_id, relationship_type, created_at, created_by)
+             VALUES (?1 || ?2, 'project', ?1, ?2, ?3, 'now', 'test')",
+            rusqlite::params![parent_id, child_id, RelationshipType::Parent.as_str()],
+        ).unwrap();
+    }
+
+    /// The computed values of `work_item_type`'s rollups by work item id and field id
+    fn rollup_values(
+        conn: &Connection,
+        types: &[WorkItemTypeModel],
+        work_item_type: &WorkItemTypeModel,
+        work_item_ids: &[&str],
+    ) -> HashMap<(String, String), String> {
+        let context = RollupContext::from_types("project", types);
+        let ids: Vec<String> = work_item_ids.iter().map(|id| id.to_string()).collect();
+        compute_rollups(conn, &context, work_item_type, &ids, None)
+            .unwrap()
+            .into_values()
+            .flatten()
+            .map(|fv| ((fv.work_item_id, fv.field_id), fv.value))
+            .collect()
+    }
+
+    fn value(values: &HashMap<(String, String), String>, work_item_id: &str, field_id: &str) -> Option<String> {
+        values.get(&(work_item_id.to_string(), field_id.to_string())).cloned()
+    }
+
+    #[test]
+    fn rollups_are_computed_over_the_tree() {
+        let statuses = vec![status("new", "New"), status("doing", "Doing"), status("done", "Done")];
+        let epic = WorkItemTypeModel {
+            allowed_statuses: statuses.clone(),
+            work_item_fields: vec![
+                rollup_field("points", RollupFunction::Sum, Some("points")),
+                rollup_field("stories", RollupFunction::Count, None),
+                rollup_field("progress", RollupFunction::PercentDone, None),
+            ],
+            ..work_item_type("epic")
+        };
+        let story = WorkItemTypeModel {
+            allowed_statuses: statuses.clone(),
+            work_item_fields: vec![
+                rollup_field("points", RollupFunction::Sum, Some("points")),
+                rollup_field("smallest", RollupFunction::Min, Some("points")),
+                rollup_field("largest", RollupFunction::Max, Some("points")),
+            ],
+            ..work_item_type("story")
+        };
+        let task = WorkItemTypeModel {
+            allowed_statuses: statuses,
+            work_item_fields: vec![field("points", "Points", "number")],
+            ..work_item_type("task")
+        };
+        let types = [epic.clone(), story.clone(), task];
+
+        let mut conn = database();
+        insert_work_item(&mut conn, "e", "epic", "doing", false);
+        insert_work_item(&mut conn, "s1", "story", "done", false);
+        insert_work_item(&mut conn, "s2", "story", "new", false);
+        insert_work_item(&mut conn, "s3", "story", "done", true);
+        insert_work_item(&mut conn, "s4", "story", "new", false);
+        for (id, status, points, parent) in [
+            ("t1", "new", 3.0, "s1"),
+            ("t2", "done", 4.5, "s1"),
+            ("t3", "new", 5.0, "s2"),
+            ("t4", "new", 100.0, "s3"),
+        ] {
+            insert_work_item(&mut conn, id, "task", status, false);
+            insert_number(&mut conn, id, "points", points);
+            insert_link(&mut conn, parent, id);
+        }
+        insert_work_item(&mut conn, "t5", "task", "new", true);
+        insert_number(&mut conn, "t5", "points", 1000.0);
+        insert_link(&mut conn, "s2", "t5");
+        // A stored value of a field the type computes is ignored
+        insert_number(&mut conn, "s1", "points", 1000.0);
+        for story_id in ["s1", "s2", "s3", "s4"] {
+            insert_link(&mut conn, "e", story_id);
+        }
+
+        let stories = rollup_values(&conn, &types, &story, &["s1", "s2", "s3", "s4"]);
+        assert_eq!(value(&stories, "s1", "points").as_deref(), Some("7.5"));
+        assert_eq!(value(&stories, "s1", "smallest").as_deref(), Some("3"));
+        assert_eq!(value(&stories, "s1", "largest").as_deref(), Some("4.5"));
+        // Archived children are left out, archived parents still have their rollups
+        assert_eq!(value(&stories, "s2", "points").as_deref(), Some("5"));
+        assert_eq!(value(&stories, "s3", "points").as_deref(), Some("100"));
+        // A sum over no children has no value
+        assert_eq!(value(&stories, "s4", "points"), None);
+
+        let epics = rollup_values(&conn, &types, &epic, &["e"]);
+        assert_eq!(value(&epics, "e", "points").as_deref(), Some("12.5"));
+        assert_eq!(value(&epics, "e", "stories").as_deref(), Some("3"));
+        assert_eq!(value(&epics, "e", "progress").as_deref(), Some("33.33"));
+    }
+
+    #[test]
+    fn nested_rollups_stop_at_the_depth_limit() {
+        let node = summing_type("node");
+        let types = [node.clone()];
+
+        // A chain deeper than the limit, where every item also has a stored value
+        let mut conn = database();
+        let ids: Vec<String> = (0..MAX_ROLLUP_DEPTH + 3).map(|i| format!("n{}", i)).collect();
+        for (i, id) in ids.iter().enumerate() {
+            insert_work_item(&mut conn, id, "node", "new", false);
+            insert_number(&mut conn, id, "remaining", 10f64.powi(i as i32));
+            if i > 0 {
+                insert_link(&mut conn, &ids[i - 1], id);
+            }
+        }
+
+        let id_refs: Vec<&str> = ids.iter().map(|id| id.as_str()).collect();
+        let values = rollup_values(&conn, &types, &node, &id_refs);
+        // Past the limit the stored value is used instead of following the rollup further
+        assert_eq!(
+            value(&values, "n0", "remaining"),
+            Some(format_computed_number(10f64.powi(MAX_ROLLUP_DEPTH as i32)))
+        );
+        assert_eq!(
+            value(&values, "n1", "remaining"),
+            Some(format_computed_number(10f64.powi(MAX_ROLLUP_DEPTH as i32 + 1)))
+        );
+        // The last item has no children to sum
+        assert_eq!(value(&values, &ids[ids.len() - 1], "remaining"), None);
+    }
+}
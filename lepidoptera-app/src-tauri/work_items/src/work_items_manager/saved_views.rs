@@ -0,0 +1,131 @@
+use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
+use crate::models::{SavedViewModel, WorkItemListRequest, WorkItemListResponse};
+use crate::repository::WorkItemsRepository;
+use crate::saved_views_repository::SavedViewsRepository;
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_items_manager::list_work_items;
+use anyhow::{Result, Context};
+use ulid::Ulid;
+use chrono::Utc;
+
+/// Save a new view for a project. The view's query is always scoped to the view's project.
+pub fn create_saved_view(
+    repository: &Arc<dyn SavedViewsRepository>,
+    mut saved_view: SavedViewModel,
+    created_by: &str,
+) -> Result<SavedViewModel> {
+    validate_saved_view(&mut saved_view)?;
+
+    if saved_view.id.is_none() {
+        saved_view.id = Some(Ulid::new().to_string());
+    }
+    saved_view.created_at = Utc::now().to_rfc3339();
+    saved_view.created_by = created_by.to_string();
+    saved_view.updated_at = None;
+    saved_view.updated_by = None;
+    saved_view.is_active = true;
+
+    let created_entity = repository.create(saved_view.to_entity()?, None)
+        .context("Failed to create saved view")?;
+    SavedViewModel::from_entity(created_entity)
+}
+
+/// Update a view's name, owner, query and columns. A view cannot be moved to another project.
+pub fn update_saved_view(
+    repository: &Arc<dyn SavedViewsRepository>,
+    mut saved_view: SavedViewModel,
+    updated_by: &str,
+) -> Result<SavedViewModel> {
+    let id = saved_view.id.clone()
+        .ok_or_else(|| anyhow::anyhow!("SavedView must have an id to update"))?;
+    let existing = get_saved_view(repository, &id)?
+        .ok_or_else(|| anyhow::anyhow!("Saved view not found: {}", id))?;
+
+    if saved_view.project_id != existing.project_id {
+        return Err(anyhow::anyhow!("Saved view '{}' cannot be moved to another project", existing.name));
+    }
+    validate_saved_view(&mut saved_view)?;
+
+    saved_view.updated_at = Some(Utc::now().to_rfc3339());
+    saved_view.updated_by = Some(updated_by.to_string());
+    repository.update(&saved_view.to_entity()?, None)
+        .context("Failed to update saved view")?;
+
+    get_saved_view(repository, &id)?
+        .ok_or_else(|| anyhow::anyhow!("Saved view not found after update: {}", id))
+}
+
+pub fn get_saved_view(
+    repository: &Arc<dyn SavedViewsRepository>,
+    id: &str,
+) -> Result<Option<SavedViewModel>> {
+    repository.find_by_id(id)?
+        .map(SavedViewModel::from_entity)
+        .transpose()
+}
+
+/// List a project's shared views plus the views of `person_id`, ordered by name
+pub fn list_saved_views(
+    repository: &Arc<dyn SavedViewsRepository>,
+    project_id: &str,
+    person_id: Option<&str>,
+) -> Result<Vec<SavedViewModel>> {
+    repository.find_by_project_id(project_id, person_id)?
+        .into_iter()
+        .map(SavedViewModel::from_entity)
+        .collect()
+}
+
+pub fn delete_saved_view(
+    repository: &Arc<dyn SavedViewsRepository>,
+    id: &str,
+    deleted_by: &str,
+) -> Result<()> {
+    repository.mark_inactive(id, deleted_by, &Utc::now().to_rfc3339())
+}
+
+/// Run a view's query with its column selection.
+///
+/// `page` and `page_size`, when given, replace the pagination stored in the view.
+pub fn execute_saved_view(
+    repository: &Arc<dyn SavedViewsRepository>,
+    work_items_repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    pool: &Arc<ConnectionPool>,
+    id: &str,
+    page: Option<usize>,
+    page_size: Option<usize>,
+) -> Result<WorkItemListResponse> {
+    let saved_view = get_saved_view(repository, id)?
+        .ok_or_else(|| anyhow::anyhow!("Saved view not found: {}", id))?;
+
+    let mut query = saved_view.query;
+    if page.is_some() || page_size.is_some() {
+        query.page = page.or(query.page);
+        query.page_size = page_size.or(query.page_size);
+        query.limit = None;
+        query.offset = None;
+    }
+
+    let request = WorkItemListRequest {
+        query,
+        include_fields: saved_view.include_fields,
+        include_category_counts: None,
+    };
+    list_work_items::list_work_items(work_items_repository, work_item_types_repository, pool, request)
+}
+
+fn validate_saved_view(saved_view: &mut SavedViewModel) -> Result<()> {
+    saved_view.name = saved_view.name.trim().to_string();
+    if saved_view.name.is_empty() {
+        return Err(anyhow::anyhow!("Saved view name cannot be empty"));
+    }
+    if saved_view.project_id.is_empty() {
+        return Err(anyhow::anyhow!("Saved view must belong to a project"));
+    }
+
+    // Queries cannot span projects, so a view only ever lists its own project's work items
+    saved_view.query.project_id = saved_view.project_id.clone();
+    Ok(())
+}
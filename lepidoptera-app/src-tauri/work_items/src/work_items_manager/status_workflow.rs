@@ -0,0 +1,139 @@
+use crate::models::WorkItemTypeModel;
+use crate::schemas::{AllowedStatus, WorkflowDefinition};
+use anyhow::Result;
+
+/// Validates that a work item type's workflow only references statuses the type allows
+pub fn validate_workflow(work_item_type: &WorkItemTypeModel) -> Result<()> {
+    let workflow = match &work_item_type.workflow {
+        Some(workflow) => workflow,
+        None => return Ok(()),
+    };
+
+    let mut referenced: Vec<&String> = Vec::new();
+    referenced.extend(workflow.initial_status.iter());
+    referenced.extend(workflow.terminal_statuses.iter());
+    for transition in &workflow.transitions {
+        referenced.push(&transition.from);
+        referenced.extend(transition.to.iter());
+    }
+
+    for status in referenced {
+        if !is_allowed_status(work_item_type, status) {
+            return Err(anyhow::anyhow!(
+                "Workflow for work item type '{}' references unknown status '{}'",
+                work_item_type.name,
+                status
+            ));
+        }
+    }
+
+    if let Some(transition) = workflow.transitions.iter()
+        .find(|t| workflow.terminal_statuses.contains(&t.from) && !t.to.is_empty())
+    {
+        return Err(anyhow::anyhow!(
+            "Workflow for work item type '{}' has transitions out of terminal status '{}'",
+            work_item_type.name,
+            transition.from
+        ));
+    }
+
+    Ok(())
+}
+
+/// Validates that a status is one of the type's allowed statuses
+pub fn validate_status(work_item_type: &WorkItemTypeModel, status: &str) -> Result<()> {
+    if !is_allowed_status(work_item_type, status) {
+        return Err(anyhow::anyhow!(
+            "Status '{}' is not allowed for work item type '{}'",
+            status,
+            work_item_type.name
+        ));
+    }
+
+    Ok(())
+}
+
+/// Resolves the status a new work item starts in.
+///
+/// An empty status falls back to the workflow's initial status, or to the type's first allowed
+/// status when there is no workflow. If the workflow defines an initial status, new work items
+/// must start in it.
+pub fn resolve_initial_status(work_item_type: &WorkItemTypeModel, status: &str) -> Result<String> {
+    let initial_status = work_item_type.workflow.as_ref()
+        .and_then(|workflow| workflow.initial_status.as_deref());
+
+    let status = match initial_status {
+        Some(initial) if status.is_empty() => initial.to_string(),
+        Some(initial) if status != initial => {
+            return Err(anyhow::anyhow!(
+                "Work items of type '{}' must start in status '{}', not '{}'",
+                work_item_type.name,
+                initial,
+                status
+            ));
+        }
+        None if status.is_empty() => work_item_type.allowed_statuses.first()
+            .map(|s| s.id.clone())
+            .unwrap_or_default(),
+        _ => status.to_string(),
+    };
+
+    validate_status(work_item_type, &status)?;
+    Ok(status)
+}
+
+/// Validates a status change against the type's workflow
+pub fn validate_status_transition(work_item_type: &WorkItemTypeModel, from: &str, to: &str) -> Result<()> {
+    validate_status(work_item_type, to)?;
+
+    if from == to {
+        return Ok(());
+    }
+
+    if let Some(workflow) = &work_item_type.workflow {
+        if !can_transition(workflow, from, to) {
+            return Err(anyhow::anyhow!(
+                "Cannot move work item of type '{}' from status '{}' to '{}'",
+                work_item_type.name,
+                from,
+                to
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the statuses a work item in `current` status can move to, in the type's status order
+pub fn next_statuses(work_item_type: &WorkItemTypeModel, current: &str) -> Vec<AllowedStatus> {
+    work_item_type.allowed_statuses
+        .iter()
+        .filter(|status| status.id != current)
+        .filter(|status| match &work_item_type.workflow {
+            Some(workflow) => can_transition(workflow, current, &status.id),
+            None => true,
+        })
+        .cloned()
+        .collect()
+}
+
+fn can_transition(workflow: &WorkflowDefinition, from: &str, to: &str) -> bool {
+    if workflow.terminal_statuses.iter().any(|s| s == from) {
+        return false;
+    }
+
+    if workflow.transitions.is_empty() {
+        return true;
+    }
+
+    workflow.transitions
+        .iter()
+        .filter(|transition| transition.from == from)
+        .any(|transition| transition.to.iter().any(|s| s == to))
+}
+
+fn is_allowed_status(work_item_type: &WorkItemTypeModel, status: &str) -> bool {
+    // Types without a status list accept any status
+    work_item_type.allowed_statuses.is_empty()
+        || work_item_type.allowed_statuses.iter().any(|s| s.id == status)
+}
@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use db::Connection;
+use crate::models::{WorkItemModel, WorkItemTypeModel, WorkItemChangeType};
+use crate::entities::{WorkItem, WorkItemFieldValue};
+use crate::work_items_manager::{
+    get_work_item, validate_field_value::validate_field_values_for_status, status_workflow::validate_status_transition,
+    work_item_history::HistoryRecorder, field_value_projection::apply_projection,
+    validate_assignment::validate_assignments, archive_work_item::ensure_not_archived,
+    work_item_repositories::WorkItemRepositories,
+};
+use anyhow::{Result, Context};
+use ulid::Ulid;
+use chrono::Utc;
+
+/// Update a work item's core columns and field values.
+///
+/// The field values on `work_item` are treated as the complete set of active values:
+/// - values that are unchanged are left alone
+/// - changed values are updated in place
+/// - new values are inserted
+/// - values that are no longer present are marked inactive
+///
+/// Every change is recorded in the work item's history.
+pub fn update_work_item(
+    repositories: WorkItemRepositories,
+    mut work_item: WorkItemModel,
+    updated_by: &str,
+) -> Result<WorkItemModel> {
+    let id = work_item.id.clone()
+        .ok_or_else(|| anyhow::anyhow!("WorkItem must have an id to update"))?;
+
+    let WorkItemRepositories { repository, work_item_types_repository, assignees_repository, pool, .. } = repositories;
+    let now = Utc::now().to_rfc3339();
+
+    // The work item is read, validated and written as a single unit of work, so the checks
+    // see the state the update changes
+    pool.get()?.get_mut().transaction(|conn| {
+        let previous = get_work_item::get_stored_work_item(repository, work_item_types_repository, conn, &id)?
+            .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", id))?;
+        ensure_not_archived(&previous)?;
+        let existing = previous.to_entity();
+
+        // A type change has to check the parent's allowed children and map statuses and fields
+        if work_item.type_id != existing.type_id {
+            return Err(anyhow::anyhow!(
+                "Work item {} can't change its type with an update; use change_work_item_type instead",
+                previous.sequential_number.as_deref().unwrap_or(&id)
+            ));
+        }
+
+        // Get the work item type to access field definitions
+        let work_item_type = match work_item_types_repository
+            .find_by_id(&work_item.type_id)
+            .context("Failed to find work item type")?
+        {
+            Some(entity) => WorkItemTypeModel::from_entity(entity)
+                .context("Failed to convert work item type entity to model")?,
+            None => return Err(anyhow::anyhow!("Work item type not found: {}", work_item.type_id)),
+        };
+
+        // Computed values come back from clients with the rest of the item; they are never stored
+        work_item.field_values.retain(|fv| fv.is_assignment_field || !work_item_type.is_computed_field(&fv.field_id));
+
+        // Status changes must follow the type's workflow
+        validate_status_transition(&work_item_type, &existing.status, &work_item.status)?;
+
+        // Validate all field values before touching the database
+        validate_field_values_for_status(&work_item_type, &work_item.status, &work_item.field_values, Some(&previous.field_values))?;
+        validate_assignments(assignees_repository, &work_item_type, &work_item, Some(&previous))?;
+
+        write_work_item_update(repositories, conn, &work_item_type, &existing, &work_item, updated_by, &now)
+    })?;
+
+    get_work_item::get_work_item(repository, work_item_types_repository, pool, &id)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found after update: {}", id))
+}
+
+/// Write an already validated update of `existing` to `work_item` as part of the caller's
+/// unit of work, recording every change in the work item's history.
+pub fn write_work_item_update(
+    repositories: WorkItemRepositories,
+    conn: &mut Connection,
+    work_item_type: &WorkItemTypeModel,
+    existing: &WorkItem,
+    work_item: &WorkItemModel,
+    updated_by: &str,
+    now: &str,
+) -> Result<()> {
+    let WorkItemRepositories { repository, field_values_repository, history_repository, .. } = repositories;
+    let id = existing.id.clone()
+        .ok_or_else(|| anyhow::anyhow!("WorkItem must have an id to update"))?;
+
+    // Only the editable columns are taken from the incoming model
+    let entity = WorkItem {
+        title: work_item.title.clone(),
+        description: work_item.description.clone(),
+        status: work_item.status.clone(),
+        priority: work_item.priority,
+        assigned_to: work_item.assigned_to.clone(),
+        type_id: work_item.type_id.clone(),
+        updated_at: Some(now.to_string()),
+        updated_by: Some(updated_by.to_string()),
+        ..existing.clone()
+    };
+    let history = HistoryRecorder::new(history_repository, &existing.project_id, &id, updated_by, now);
+
+    history.record_core_changes(conn, existing, &entity)?;
+    repository.update(entity, Some(conn))?;
+
+    // Diff field values against the currently active ones
+    let mut existing_values: HashMap<(String, bool), WorkItemFieldValue> = field_values_repository
+        .find_active_by_work_item_id(&id, Some(conn))?
+        .into_iter()
+        .map(|fv| ((fv.field_id.clone(), fv.is_assignment_field), fv))
+        .collect();
+
+    for field_value_model in &work_item.field_values {
+        let key = (field_value_model.field_id.clone(), field_value_model.is_assignment_field);
+        let change_type = field_value_change_type(field_value_model.is_assignment_field);
+        match existing_values.remove(&key) {
+            Some(mut current) => {
+                if current.value != field_value_model.value {
+                    history.record(
+                        conn,
+                        change_type,
+                        Some(&current.field_id),
+                        Some(current.value.clone()),
+                        Some(field_value_model.value.clone()),
+                    )?;
+                    current.value = field_value_model.value.clone();
+                    current.updated_at = Some(now.to_string());
+                    current.updated_by = Some(updated_by.to_string());
+                    apply_projection(work_item_type, &mut current);
+                    field_values_repository.update_value(&current, Some(conn))
+                        .context("Failed to update work item field value")?;
+                }
+            }
+            None => {
+                let mut field_value_entity = WorkItemFieldValue {
+                    id: Some(Ulid::new().to_string()),
+                    project_id: work_item.project_id.clone(),
+                    work_item_id: id.clone(),
+                    field_id: field_value_model.field_id.clone(),
+                    is_assignment_field: field_value_model.is_assignment_field,
+                    value: field_value_model.value.clone(),
+                    created_at: now.to_string(),
+                    updated_at: None,
+                    created_by: updated_by.to_string(),
+                    updated_by: None,
+                    is_active: true,
+                    value_number: None,
+                    value_date: None,
+                };
+                apply_projection(work_item_type, &mut field_value_entity);
+                field_values_repository.create(field_value_entity, Some(conn))
+                    .context("Failed to create work item field value")?;
+                history.record(
+                    conn,
+                    change_type,
+                    Some(&field_value_model.field_id),
+                    None,
+                    Some(field_value_model.value.clone()),
+                )?;
+            }
+        }
+    }
+
+    // Anything left over was removed by the caller
+    for (_, mut removed) in existing_values {
+        removed.updated_at = Some(now.to_string());
+        removed.updated_by = Some(updated_by.to_string());
+        field_values_repository.mark_inactive(&removed, Some(conn))
+            .context("Failed to deactivate work item field value")?;
+        history.record(
+            conn,
+            field_value_change_type(removed.is_assignment_field),
+            Some(&removed.field_id),
+            Some(removed.value.clone()),
+            None,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn field_value_change_type(is_assignment_field: bool) -> WorkItemChangeType {
+    if is_assignment_field {
+        WorkItemChangeType::AssignmentFieldValue
+    } else {
+        WorkItemChangeType::FieldValue
+    }
+}
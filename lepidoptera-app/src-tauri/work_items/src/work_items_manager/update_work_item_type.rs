@@ -1,12 +1,15 @@
 use std::sync::Arc;
 use crate::models::WorkItemTypeModel;
 use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_items_manager::validate_work_item_type::validate_work_item_type;
 use anyhow::Result;
+use db::Connection;
 use chrono::Utc;
 
 pub fn update_work_item_type(
     repository: &Arc<dyn WorkItemTypesRepository>,
     mut work_item_type: WorkItemTypeModel,
+    conn: Option<&mut Connection>,
 ) -> Result<WorkItemTypeModel> {
     // Ensure id is present
     work_item_type.id.as_ref()
@@ -15,11 +18,13 @@ pub fn update_work_item_type(
     // Set updated_at
     work_item_type.updated_at = Some(Utc::now().to_rfc3339());
     
+    validate_work_item_type(&work_item_type)?;
+
     // Convert model to entity (this validates JSON fields during serialization)
     let entity = work_item_type.to_entity()?;
     
     // Update in repository
-    let updated_entity = repository.update(entity)?;
+    let updated_entity = repository.update(entity, conn)?;
     
     // Convert back to model
     WorkItemTypeModel::from_entity(updated_entity)
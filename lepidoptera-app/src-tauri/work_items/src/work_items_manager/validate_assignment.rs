@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::assignees_repository::AssigneesRepository;
+use crate::errors::FieldValidationError;
+use crate::models::{WorkItemModel, WorkItemTypeModel};
+use crate::schemas::AssignmentFieldDefinition;
+
+const ASSIGNED_TO_FIELD_ID: &str = "assigned_to";
+const ASSIGNED_TO_LABEL: &str = "Assignee";
+
+/// What an assignment value refers to, from `AssignmentFieldDefinition.field_type`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AssigneeKind {
+    Person,
+    Team,
+}
+
+impl AssigneeKind {
+    fn from_field_type(field_type: &str) -> Option<Self> {
+        match field_type {
+            "person" => Some(AssigneeKind::Person),
+            "team" => Some(AssigneeKind::Team),
+            _ => None,
+        }
+    }
+
+    fn noun(&self) -> &'static str {
+        match self {
+            AssigneeKind::Person => "person",
+            AssigneeKind::Team => "team",
+        }
+    }
+}
+
+/// A reference to a person or team that has to be checked
+struct AssigneeReference<'a> {
+    field_id: &'a str,
+    field_label: &'a str,
+    kind: AssigneeKind,
+    id: &'a str,
+}
+
+/// Validates `assigned_to` and the assignment field values of a work item.
+///
+/// - required assignment fields must have a value
+/// - every assignment field value must be defined by the type
+/// - `assigned_to` and "person" fields must reference an existing, active person
+/// - "team" fields must reference an existing, active team
+///
+/// Custom fields of type "person" or "team" are checked the same way.
+///
+/// When `previous` is given (an update), only references that are new or changed are looked
+/// up, so deactivating a person doesn't block unrelated edits to work items assigned to them.
+pub fn validate_assignments(
+    assignees_repository: &Arc<dyn AssigneesRepository>,
+    work_item_type: &WorkItemTypeModel,
+    work_item: &WorkItemModel,
+    previous: Option<&WorkItemModel>,
+) -> anyhow::Result<()> {
+    let values: HashMap<&str, &str> = assignment_values(work_item);
+    let previous_values: HashMap<&str, &str> = previous.map(assignment_values).unwrap_or_default();
+
+    for definition in &work_item_type.assignment_field_definitions {
+        if definition.required && values.get(definition.id.as_str()).is_none_or(|v| v.is_empty()) {
+            return Err(FieldValidationError::new(
+                definition.id.clone(),
+                definition.label.clone(),
+                format!("Field '{}' is required but no value was provided", definition.label),
+            ).into());
+        }
+    }
+
+    let mut references = Vec::new();
+
+    if let Some(assigned_to) = work_item.assigned_to.as_deref().filter(|id| !id.is_empty()) {
+        let unchanged = previous.is_some_and(|p| p.assigned_to.as_deref() == Some(assigned_to));
+        if !unchanged {
+            references.push(AssigneeReference {
+                field_id: ASSIGNED_TO_FIELD_ID,
+                field_label: ASSIGNED_TO_LABEL,
+                kind: AssigneeKind::Person,
+                id: assigned_to,
+            });
+        }
+    }
+
+    for field_value in work_item.field_values.iter().filter(|fv| fv.is_assignment_field) {
+        let (field_id, value) = (field_value.field_id.as_str(), field_value.value.as_str());
+        let definition = find_definition(work_item_type, field_id).ok_or_else(|| {
+            anyhow::anyhow!(
+                "Assignment field '{}' is not defined in work item type '{}'",
+                field_id,
+                work_item_type.id.as_deref().unwrap_or_default()
+            )
+        })?;
+
+        if value.is_empty() || previous_values.get(field_id) == Some(&value) {
+            continue;
+        }
+        // Other assignment field types ("custom") hold free-form values
+        if let Some(kind) = AssigneeKind::from_field_type(&definition.field_type) {
+            references.push(AssigneeReference {
+                field_id: &definition.id,
+                field_label: &definition.label,
+                kind,
+                id: value,
+            });
+        }
+    }
+
+    for field_value in work_item.field_values.iter().filter(|fv| !fv.is_assignment_field) {
+        let field = match work_item_type.work_item_fields.iter().find(|f| f.id == field_value.field_id) {
+            Some(field) => field,
+            // Undefined fields are reported by validate_field_values
+            None => continue,
+        };
+        let value = field_value.value.trim();
+        let unchanged = previous.is_some_and(|p| {
+            p.field_values.iter().any(|fv| !fv.is_assignment_field && fv.field_id == field.id && fv.value.trim() == value)
+        });
+        if value.is_empty() || unchanged {
+            continue;
+        }
+        if let Some(kind) = AssigneeKind::from_field_type(&field.field_type) {
+            references.push(AssigneeReference {
+                field_id: &field.id,
+                field_label: &field.label,
+                kind,
+                id: value,
+            });
+        }
+    }
+
+    check_references(assignees_repository, &references)
+}
+
+/// The work item's assignment field values by field id
+fn assignment_values(work_item: &WorkItemModel) -> HashMap<&str, &str> {
+    work_item.field_values
+        .iter()
+        .filter(|fv| fv.is_assignment_field)
+        .map(|fv| (fv.field_id.as_str(), fv.value.as_str()))
+        .collect()
+}
+
+fn find_definition<'a>(work_item_type: &'a WorkItemTypeModel, field_id: &str) -> Option<&'a AssignmentFieldDefinition> {
+    work_item_type.assignment_field_definitions
+        .iter()
+        .find(|definition| definition.id == field_id)
+}
+
+fn check_references(
+    assignees_repository: &Arc<dyn AssigneesRepository>,
+    references: &[AssigneeReference],
+) -> anyhow::Result<()> {
+    let ids_of = |kind: AssigneeKind| -> Vec<String> {
+        references.iter()
+            .filter(|r| r.kind == kind)
+            .map(|r| r.id.to_string())
+            .collect()
+    };
+    let persons = assignees_repository.find_persons(&ids_of(AssigneeKind::Person))?;
+    let teams = assignees_repository.find_teams(&ids_of(AssigneeKind::Team))?;
+
+    for reference in references {
+        let found = match reference.kind {
+            AssigneeKind::Person => &persons,
+            AssigneeKind::Team => &teams,
+        };
+        let message = match found.get(reference.id) {
+            Some(true) => continue,
+            Some(false) => format!(
+                "Field '{}' references inactive {} '{}'",
+                reference.field_label, reference.kind.noun(), reference.id
+            ),
+            None => format!(
+                "Field '{}' references unknown {} '{}'",
+                reference.field_label, reference.kind.noun(), reference.id
+            ),
+        };
+        return Err(FieldValidationError::new(
+            reference.field_id.to_string(),
+            reference.field_label.to_string(),
+            message,
+        ).into());
+    }
+
+    Ok(())
+}
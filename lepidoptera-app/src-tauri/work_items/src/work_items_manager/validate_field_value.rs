@@ -1,36 +1,177 @@
 use crate::schemas::{WorkItemField, FieldValidation};
 use crate::errors::FieldValidationError;
+use crate::models::{WorkItemFieldValueModel, WorkItemTypeModel};
+use crate::work_items_manager::field_rules::evaluate_field_states;
 use chrono::{DateTime, NaiveDate, NaiveDateTime};
 
-/// Validates a field value according to the field definition's validation rules
+/// An absolute URL: a scheme, `://` and a host
+const URL_PATTERN: &str = r"^[A-Za-z][A-Za-z0-9+.-]*://[^\s/?#]+[^\s]*$";
+const EMAIL_PATTERN: &str = r"^[^@\s]+@[^@\s]+\.[^@\s.]+$";
+
+/// Validates a set of field values against the field definitions of a work item type
+pub fn validate_field_values(
+    work_item_type: &WorkItemTypeModel,
+    field_values: &[WorkItemFieldValueModel],
+) -> anyhow::Result<()> {
+    for field_value in field_values {
+        if field_value.is_assignment_field {
+            // Assignment fields reference people and teams, so they are checked
+            // against the database by validate_assignments
+            continue;
+        }
+
+        // Find the matching field definition
+        let field_def = work_item_type.work_item_fields
+            .iter()
+            .find(|field| field.id == field_value.field_id);
+
+        match field_def {
+            Some(field) => {
+                validate_field_value(field, &field_value.value)
+                    .map_err(|e| anyhow::anyhow!("{}", e))?;
+            }
+            None => {
+                return Err(anyhow::anyhow!(
+                    "Field '{}' is not defined in work item type '{}'",
+                    field_value.field_id,
+                    work_item_type.id.as_deref().unwrap_or_default()
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Validates a work item's field values against the field definitions and the fields'
+/// conditional rules for the work item's status.
+///
+/// A field with conditional rules must have a value while it is required. A hidden field can't be
+/// given a new value: a value that differs from `previous` (the stored values, or none for a
+/// new work item) is rejected. A hidden field is never required.
+pub fn validate_field_values_for_status(
+    work_item_type: &WorkItemTypeModel,
+    status: &str,
+    field_values: &[WorkItemFieldValueModel],
+    previous: Option<&[WorkItemFieldValueModel]>,
+) -> anyhow::Result<()> {
+    let states = evaluate_field_states(work_item_type, status, field_values);
+
+    for (field, state) in work_item_type.work_item_fields.iter().zip(&states) {
+        let value = field_values.iter()
+            .find(|fv| !fv.is_assignment_field && fv.field_id == field.id)
+            .map(|fv| fv.value.as_str());
+
+        if !state.visible {
+            let unchanged = value.unwrap_or_default().is_empty() || previous
+                .and_then(|values| values.iter().find(|fv| !fv.is_assignment_field && fv.field_id == field.id))
+                .is_some_and(|fv| Some(fv.value.as_str()) == value);
+            if !unchanged {
+                return Err(anyhow::anyhow!("{}", FieldValidationError::new(
+                    field.id.clone(),
+                    field.label.clone(),
+                    format!("Field '{}' is hidden for this work item and can't be set", field.label),
+                )));
+            }
+        }
+
+        match value {
+            Some(value) => check_field_value(field, value, state.required),
+            // Missing values have always been allowed for statically required fields; only
+            // a field with conditional rules must be present while it's required
+            None if state.required && has_conditions(field) => Err(required_error(field)),
+            None => Ok(()),
+        }
+        .map_err(|e| anyhow::anyhow!("{}", e))?;
+    }
+
+    // Values for fields the type doesn't define
+    validate_field_values(
+        work_item_type,
+        &field_values.iter()
+            .filter(|fv| !work_item_type.work_item_fields.iter().any(|f| f.id == fv.field_id))
+            .cloned()
+            .collect::<Vec<_>>(),
+    )
+}
+
+/// Validates a field value according to the field definition's type and validation rules.
+///
+/// Values are stored as text in these formats:
+/// - number, integer: a decimal number
+/// - currency: a decimal amount in the field's `currency_code`
+/// - duration: a whole number of minutes
+/// - date, datetime: RFC 3339, `YYYY-MM-DD HH:MM:SS` or `YYYY-MM-DD`
+/// - boolean: `true` or `false`
+/// - select, radio: one of the field's options
+/// - multi_select: a JSON array of the field's options, e.g. `["a","b"]`
+/// - url: an absolute URL such as `https://example.com`
+/// - email: an email address
+/// - person, team: the id of a person or team, checked by `validate_assignments`
+/// - anything else: text
 pub fn validate_field_value(
     field: &WorkItemField,
     value: &str,
+) -> Result<(), FieldValidationError> {
+    check_field_value(field, value, field.required)
+}
+
+fn has_conditions(field: &WorkItemField) -> bool {
+    field.required_when.is_some() || field.visible_when.is_some()
+}
+
+fn required_error(field: &WorkItemField) -> FieldValidationError {
+    FieldValidationError::new(
+        field.id.clone(),
+        field.label.clone(),
+        format!("Field '{}' is required but no value was provided", field.label),
+    )
+}
+
+fn check_field_value(
+    field: &WorkItemField,
+    value: &str,
+    required: bool,
 ) -> Result<(), FieldValidationError> {
     // Check required fields
-    if field.required && value.is_empty() {
-        return Err(FieldValidationError::new(
-            field.id.clone(),
-            field.label.clone(),
-            format!("Field '{}' is required but no value was provided", field.label),
-        ));
+    if required && is_empty_value(field, value) {
+        return Err(required_error(field));
     }
 
     // Skip validation if value is empty and field is not required
-    if value.is_empty() {
+    if is_empty_value(field, value) {
         return Ok(());
     }
 
-    // Get validation rules if they exist
-    let validation = match &field.validation {
-        Some(v) => v,
-        None => return Ok(()), // No validation rules, value is acceptable
-    };
+    if field.is_computed() {
+        return Err(FieldValidationError::new(
+            field.id.clone(),
+            field.label.clone(),
+            format!("Field '{}' is computed and can't be set", field.label),
+        ));
+    }
+
+    // Values are checked against their type even when the field has no validation rules
+    let no_rules = FieldValidation::default();
+    let validation = field.validation.as_ref().unwrap_or(&no_rules);
 
     // Validate based on field type
     match field.field_type.as_str() {
-        "number" | "integer" => validate_number_field(field, value, validation)?,
+        "number" | "integer" | "currency" => validate_number_field(field, value, validation)?,
+        "duration" => validate_duration_field(field, value, validation)?,
         "date" | "datetime" => validate_date_field(field, value, validation)?,
+        "boolean" => validate_boolean_field(field, value)?,
+        "multi_select" => validate_multi_select_field(field, value)?,
+        "url" => {
+            validate_format(field, value, URL_PATTERN, "an absolute URL")?;
+            validate_text_field(field, value, validation)?;
+        }
+        "email" => {
+            validate_format(field, value, EMAIL_PATTERN, "an email address")?;
+            validate_text_field(field, value, validation)?;
+        }
+        // References are looked up in the database by validate_assignments
+        "person" | "team" => {}
         "text" | "textarea" | "select" | "radio" => validate_text_field(field, value, validation)?,
         _ => {
             // For unknown field types, only validate text-based rules
@@ -41,6 +182,47 @@ pub fn validate_field_value(
     Ok(())
 }
 
+/// Whether a value counts as no value for a field. An empty multi-select list is empty too.
+pub fn is_empty_value(field: &WorkItemField, value: &str) -> bool {
+    let value = value.trim();
+    value.is_empty() || (field.field_type == "multi_select" && value == "[]")
+}
+
+/// Validates the type-specific settings of a work item type's fields
+pub fn validate_field_definitions(work_item_type: &WorkItemTypeModel) -> anyhow::Result<()> {
+    for field in &work_item_type.work_item_fields {
+        if field.field_type == "multi_select" && field.options.as_ref().is_none_or(|options| options.is_empty()) {
+            return Err(anyhow::anyhow!("Multi-select field '{}' has no options", field.label));
+        }
+        match (field.field_type == "currency", &field.currency_code) {
+            (true, None) => {
+                return Err(anyhow::anyhow!("Currency field '{}' has no currency code", field.label));
+            }
+            (true, Some(code)) if code.len() != 3 || !code.chars().all(|c| c.is_ascii_uppercase()) => {
+                return Err(anyhow::anyhow!(
+                    "Currency code '{}' of field '{}' is not a three-letter ISO 4217 code",
+                    code,
+                    field.label
+                ));
+            }
+            (false, Some(_)) => {
+                return Err(anyhow::anyhow!(
+                    "Field '{}' has a currency code but its type is '{}'",
+                    field.label,
+                    field.field_type
+                ));
+            }
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// The values selected in a multi-select field value, or `None` when it isn't a JSON array of strings
+pub fn parse_multi_select(value: &str) -> Option<Vec<String>> {
+    serde_json::from_str(value).ok()
+}
+
 fn validate_number_field(
     field: &WorkItemField,
     value: &str,
@@ -224,3 +406,83 @@ fn validate_text_field(
     Ok(())
 }
 
+fn validate_duration_field(
+    field: &WorkItemField,
+    value: &str,
+    validation: &FieldValidation,
+) -> Result<(), FieldValidationError> {
+    if value.trim().parse::<u64>().is_err() {
+        return Err(FieldValidationError::new(
+            field.id.clone(),
+            field.label.clone(),
+            format!("Field '{}' must be a whole number of minutes, but got '{}'", field.label, value),
+        ));
+    }
+    validate_number_field(field, value.trim(), validation)
+}
+
+fn validate_boolean_field(
+    field: &WorkItemField,
+    value: &str,
+) -> Result<(), FieldValidationError> {
+    match value {
+        "true" | "false" => Ok(()),
+        _ => Err(FieldValidationError::new(
+            field.id.clone(),
+            field.label.clone(),
+            format!("Field '{}' must be true or false, but got '{}'", field.label, value),
+        )),
+    }
+}
+
+fn validate_multi_select_field(
+    field: &WorkItemField,
+    value: &str,
+) -> Result<(), FieldValidationError> {
+    let selected = parse_multi_select(value).ok_or_else(|| {
+        FieldValidationError::new(
+            field.id.clone(),
+            field.label.clone(),
+            format!("Field '{}' must be a list of options, but got '{}'", field.label, value),
+        )
+    })?;
+
+    let options = field.options.as_deref().unwrap_or_default();
+    for (index, choice) in selected.iter().enumerate() {
+        if !options.iter().any(|option| &option.value == choice) {
+            return Err(FieldValidationError::new(
+                field.id.clone(),
+                field.label.clone(),
+                format!("Field '{}' value '{}' is not one of the allowed options", field.label, choice),
+            ));
+        }
+        if selected[..index].contains(choice) {
+            return Err(FieldValidationError::new(
+                field.id.clone(),
+                field.label.clone(),
+                format!("Field '{}' selects '{}' more than once", field.label, choice),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_format(
+    field: &WorkItemField,
+    value: &str,
+    pattern: &str,
+    description: &str,
+) -> Result<(), FieldValidationError> {
+    let regex = regex::Regex::new(pattern).map_err(|e| {
+        FieldValidationError::new(field.id.clone(), field.label.clone(), e.to_string())
+    })?;
+    if !regex.is_match(value) {
+        return Err(FieldValidationError::new(
+            field.id.clone(),
+            field.label.clone(),
+            format!("Field '{}' must be {}, but got '{}'", field.label, description, value),
+        ));
+    }
+    Ok(())
+}
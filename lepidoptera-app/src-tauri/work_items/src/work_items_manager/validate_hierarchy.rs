@@ -0,0 +1,102 @@
+use std::sync::Arc;
+use crate::errors::HierarchyError;
+use crate::models::{RelationshipType, WorkItemRelationshipModel, WorkItemTypeModel};
+use crate::repository::WorkItemsRepository;
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_item_relationships_repository::WorkItemRelationshipsRepository;
+use anyhow::{Result, Context};
+
+/// Validates that a relationship respects the `allowed_children_type_ids` of the parent's type.
+///
+/// Only `Parent` and `Child` relationships are checked; other relationship types are always allowed.
+pub fn validate_hierarchy(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    relationship: &WorkItemRelationshipModel,
+) -> Result<()> {
+    let (parent_id, child_id) = match relationship.relationship_type {
+        RelationshipType::Parent => (&relationship.source_work_item_id, &relationship.target_work_item_id),
+        RelationshipType::Child => (&relationship.target_work_item_id, &relationship.source_work_item_id),
+        _ => return Ok(()),
+    };
+
+    let parent = repository.find_by_id(parent_id, None)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", parent_id))?;
+    let child = repository.find_by_id(child_id, None)?
+        .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", child_id))?;
+
+    let parent_type = load_type(work_item_types_repository, &parent.type_id)?;
+    if parent_type.allowed_children_type_ids.contains(&child.type_id) {
+        return Ok(());
+    }
+
+    let child_type = load_type(work_item_types_repository, &child.type_id)?;
+    Err(HierarchyError::ChildTypeNotAllowed {
+        parent_type_id: parent.type_id,
+        parent_type_name: parent_type.name,
+        child_type_id: child.type_id,
+        child_type_name: child_type.name,
+    }.into())
+}
+
+/// Validates that a work item can take `new_type` without breaking the `allowed_children_type_ids`
+/// rules of its active parent and child relationships.
+pub fn validate_hierarchy_for_type(
+    repository: &Arc<dyn WorkItemsRepository>,
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    relationships_repository: &Arc<dyn WorkItemRelationshipsRepository>,
+    work_item_id: &str,
+    new_type: &WorkItemTypeModel,
+) -> Result<()> {
+    let new_type_id = new_type.id.clone().unwrap_or_default();
+
+    for relationship in relationships_repository.find_by_work_item_id(work_item_id)? {
+        let (parent_id, child_id) = match RelationshipType::from_str(&relationship.relationship_type) {
+            Some(RelationshipType::Parent) => (relationship.source_work_item_id, relationship.target_work_item_id),
+            Some(RelationshipType::Child) => (relationship.target_work_item_id, relationship.source_work_item_id),
+            _ => continue,
+        };
+
+        if parent_id == work_item_id {
+            let child = repository.find_by_id(&child_id, None)?
+                .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", child_id))?;
+            if !new_type.allowed_children_type_ids.contains(&child.type_id) {
+                let child_type = load_type(work_item_types_repository, &child.type_id)?;
+                return Err(HierarchyError::ChildTypeNotAllowed {
+                    parent_type_id: new_type_id,
+                    parent_type_name: new_type.name.clone(),
+                    child_type_id: child.type_id,
+                    child_type_name: child_type.name,
+                }.into());
+            }
+        } else {
+            let parent = repository.find_by_id(&parent_id, None)?
+                .ok_or_else(|| anyhow::anyhow!("Work item not found: {}", parent_id))?;
+            let parent_type = load_type(work_item_types_repository, &parent.type_id)?;
+            if !parent_type.allowed_children_type_ids.contains(&new_type_id) {
+                return Err(HierarchyError::ChildTypeNotAllowed {
+                    parent_type_id: parent.type_id,
+                    parent_type_name: parent_type.name,
+                    child_type_id: new_type_id,
+                    child_type_name: new_type.name.clone(),
+                }.into());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn load_type(
+    work_item_types_repository: &Arc<dyn WorkItemTypesRepository>,
+    type_id: &str,
+) -> Result<WorkItemTypeModel> {
+    match work_item_types_repository
+        .find_by_id(type_id)
+        .context("Failed to find work item type")?
+    {
+        Some(entity) => WorkItemTypeModel::from_entity(entity)
+            .context("Failed to convert work item type entity to model"),
+        None => Err(anyhow::anyhow!("Work item type not found: {}", type_id)),
+    }
+}
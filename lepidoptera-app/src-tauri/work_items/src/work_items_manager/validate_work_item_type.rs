@@ -0,0 +1,17 @@
+use crate::models::WorkItemTypeModel;
+use crate::work_items_manager::{
+    status_workflow::validate_workflow, rollup_fields::validate_rollup_fields,
+    formula_fields::validate_formula_fields, field_rules::validate_field_conditions,
+    validate_field_value::validate_field_definitions,
+};
+use anyhow::Result;
+
+/// Checks everything a work item type defines before it is stored: its workflow, field
+/// definitions, rollups, formulas and field conditions
+pub fn validate_work_item_type(work_item_type: &WorkItemTypeModel) -> Result<()> {
+    validate_workflow(work_item_type)?;
+    validate_rollup_fields(work_item_type)?;
+    validate_formula_fields(work_item_type)?;
+    validate_field_conditions(work_item_type)?;
+    validate_field_definitions(work_item_type)
+}
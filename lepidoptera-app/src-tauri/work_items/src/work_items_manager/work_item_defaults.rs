@@ -0,0 +1,121 @@
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
+use regex::Regex;
+use serde_json::Value;
+use std::sync::OnceLock;
+use crate::models::{WorkItemModel, WorkItemTypeModel, WorkItemFieldValueModel};
+use crate::work_items_manager::field_rules::is_visible;
+
+/// Fills in what the caller left out of a new work item from its type's defaults.
+///
+/// A priority of 0 becomes the type's default priority, and missing or empty field values
+/// and assignment values take the field's `default_value`, except for fields hidden in the
+/// work item's status. Status is resolved first, by `resolve_initial_status`.
+pub fn apply_defaults(work_item_type: &WorkItemTypeModel, work_item: &mut WorkItemModel, now: DateTime<Utc>) {
+    if work_item.priority == 0 && !work_item_type.allowed_priorities.iter().any(|p| p.value == 0) {
+        if let Some(priority) = work_item_type.allowed_priorities.iter().find(|p| p.is_default) {
+            work_item.priority = priority.value;
+        }
+    }
+
+    for field in work_item_type.work_item_fields.iter().filter(|field| !field.is_computed()) {
+        if !is_visible(work_item_type, field, &work_item.status, &work_item.field_values) {
+            continue;
+        }
+        let default = match field.default_value.as_ref().and_then(json_default) {
+            Some(default) => resolve_default(&default, &work_item.created_by, now),
+            None => continue,
+        };
+        set_if_missing(work_item, &field.id, false, default);
+    }
+
+    for definition in &work_item_type.assignment_field_definitions {
+        let default = match definition.default_value.as_deref() {
+            Some(default) if !default.trim().is_empty() => resolve_default(default, &work_item.created_by, now),
+            _ => continue,
+        };
+        set_if_missing(work_item, &definition.id, true, default);
+    }
+}
+
+/// Resolves a default value, expanding the dynamic forms:
+///
+/// - `today` / `today+7d`: a date (`YYYY-MM-DD`)
+/// - `now` / `now-2h`: a timestamp (RFC 3339)
+/// - `current user` / `me`: the user creating the work item
+///
+/// Offsets take a unit of `m` (minutes), `h`, `d` or `w`. Anything else is used as is.
+pub fn resolve_default(default: &str, current_user: &str, now: DateTime<Utc>) -> String {
+    let token = default.trim().to_lowercase().replace(['_', '-'], " ");
+    if token == "current user" || token == "me" {
+        return current_user.to_string();
+    }
+
+    let captures = match relative_date_pattern().captures(default.trim()) {
+        Some(captures) => captures,
+        None => return default.to_string(),
+    };
+
+    let mut at = now;
+    if let (Some(sign), Some(amount), Some(unit)) = (captures.get(2), captures.get(3), captures.get(4)) {
+        let amount: i64 = amount.as_str().parse().unwrap_or(0);
+        let offset = match unit.as_str().to_lowercase().as_str() {
+            "m" => Duration::minutes(amount),
+            "h" => Duration::hours(amount),
+            "w" => Duration::weeks(amount),
+            _ => Duration::days(amount),
+        };
+        at = if sign.as_str() == "-" { at - offset } else { at + offset };
+    }
+
+    if captures[1].eq_ignore_ascii_case("today") {
+        at.format("%Y-%m-%d").to_string()
+    } else {
+        at.to_rfc3339_opts(SecondsFormat::Secs, true)
+    }
+}
+
+fn relative_date_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)^(today|now)\s*(?:([+-])\s*(\d+)\s*([mhdw]))?$").expect("valid relative date pattern")
+    })
+}
+
+/// The string form of a field's JSON default value, if it has one
+fn json_default(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(s) if s.trim().is_empty() => None,
+        Value::String(s) => Some(s.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+fn set_if_missing(work_item: &mut WorkItemModel, field_id: &str, is_assignment_field: bool, value: String) {
+    // e.g. "current user" when the creator is unknown
+    if value.is_empty() {
+        return;
+    }
+
+    let existing = work_item.field_values.iter_mut()
+        .find(|fv| fv.field_id == field_id && fv.is_assignment_field == is_assignment_field);
+
+    match existing {
+        Some(field_value) if field_value.value.trim().is_empty() => field_value.value = value,
+        Some(_) => {}
+        None => work_item.field_values.push(WorkItemFieldValueModel {
+            id: None,
+            project_id: work_item.project_id.clone(),
+            work_item_id: work_item.id.clone().unwrap_or_default(),
+            field_id: field_id.to_string(),
+            is_assignment_field,
+            value,
+            created_at: String::new(),
+            updated_at: None,
+            created_by: work_item.created_by.clone(),
+            updated_by: None,
+            is_active: true,
+            field_definition: None,
+        }),
+    }
+}
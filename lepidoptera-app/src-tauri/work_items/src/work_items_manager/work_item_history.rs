@@ -0,0 +1,116 @@
+use std::sync::Arc;
+use db::Connection;
+use crate::entities::{WorkItem, WorkItemHistoryEntry, WorkItemRelationship};
+use crate::models::{RelationshipType, WorkItemChangeType};
+use crate::work_item_history_repository::WorkItemHistoryRepository;
+use anyhow::{Result, Context};
+use ulid::Ulid;
+
+/// Records changes to a single work item as part of the caller's unit of work.
+///
+/// All entries written by one recorder share the same actor and timestamp.
+pub struct HistoryRecorder<'a> {
+    repository: &'a Arc<dyn WorkItemHistoryRepository>,
+    project_id: String,
+    work_item_id: String,
+    changed_by: String,
+    changed_at: String,
+}
+
+impl<'a> HistoryRecorder<'a> {
+    pub fn new(
+        repository: &'a Arc<dyn WorkItemHistoryRepository>,
+        project_id: &str,
+        work_item_id: &str,
+        changed_by: &str,
+        changed_at: &str,
+    ) -> Self {
+        Self {
+            repository,
+            project_id: project_id.to_string(),
+            work_item_id: work_item_id.to_string(),
+            changed_by: changed_by.to_string(),
+            changed_at: changed_at.to_string(),
+        }
+    }
+
+    /// Record a single change
+    pub fn record(
+        &self,
+        conn: &mut Connection,
+        change_type: WorkItemChangeType,
+        field: Option<&str>,
+        old_value: Option<String>,
+        new_value: Option<String>,
+    ) -> Result<()> {
+        let entry = WorkItemHistoryEntry {
+            id: Some(Ulid::new().to_string()),
+            project_id: self.project_id.clone(),
+            work_item_id: self.work_item_id.clone(),
+            change_type: change_type.as_str().to_string(),
+            field: field.map(|f| f.to_string()),
+            old_value,
+            new_value,
+            changed_by: self.changed_by.clone(),
+            changed_at: self.changed_at.clone(),
+        };
+
+        self.repository.create(entry, Some(conn))
+            .context("Failed to record work item history")?;
+        Ok(())
+    }
+
+    /// Record one `Updated` entry for each core column that differs between `before` and `after`
+    pub fn record_core_changes(&self, conn: &mut Connection, before: &WorkItem, after: &WorkItem) -> Result<()> {
+        let changes = [
+            ("title", Some(before.title.clone()), Some(after.title.clone())),
+            ("description", before.description.clone(), after.description.clone()),
+            ("status", Some(before.status.clone()), Some(after.status.clone())),
+            ("priority", Some(before.priority.to_string()), Some(after.priority.to_string())),
+            ("assigned_to", before.assigned_to.clone(), after.assigned_to.clone()),
+            ("type_id", Some(before.type_id.clone()), Some(after.type_id.clone())),
+        ];
+
+        for (field, old_value, new_value) in changes {
+            if old_value != new_value {
+                self.record(conn, WorkItemChangeType::Updated, Some(field), old_value, new_value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Record a relationship being added or removed on both of the work items it connects.
+///
+/// The target work item's entry uses the inverse relationship type, so each side reads
+/// naturally (e.g. "parent" on the source and "child" on the target).
+pub fn record_relationship_change(
+    repository: &Arc<dyn WorkItemHistoryRepository>,
+    conn: &mut Connection,
+    relationship: &WorkItemRelationship,
+    change_type: WorkItemChangeType,
+    changed_by: &str,
+    changed_at: &str,
+) -> Result<()> {
+    let inverse_type = RelationshipType::from_str(&relationship.relationship_type)
+        .and_then(|t| t.inverse())
+        .map(|t| t.as_str().to_string())
+        .unwrap_or_else(|| relationship.relationship_type.clone());
+
+    let sides = [
+        (&relationship.source_work_item_id, &relationship.target_work_item_id, relationship.relationship_type.as_str()),
+        (&relationship.target_work_item_id, &relationship.source_work_item_id, inverse_type.as_str()),
+    ];
+
+    for (work_item_id, other_work_item_id, relationship_type) in sides {
+        let recorder = HistoryRecorder::new(repository, &relationship.project_id, work_item_id, changed_by, changed_at);
+        let (old_value, new_value) = match change_type {
+            WorkItemChangeType::RelationshipRemoved => (Some(other_work_item_id.clone()), None),
+            _ => (None, Some(other_work_item_id.clone())),
+        };
+        recorder.record(conn, change_type, Some(relationship_type), old_value, new_value)?;
+    }
+
+    Ok(())
+}
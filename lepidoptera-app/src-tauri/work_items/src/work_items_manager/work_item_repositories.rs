@@ -0,0 +1,25 @@
+use std::sync::Arc;
+use db::connection_pool::ConnectionPool;
+use crate::repository::WorkItemsRepository;
+use crate::work_item_types_repository::WorkItemTypesRepository;
+use crate::work_item_number_ranges_repository::WorkItemNumberRangesRepository;
+use crate::work_item_number_aliases_repository::WorkItemNumberAliasesRepository;
+use crate::work_item_relationships_repository::WorkItemRelationshipsRepository;
+use crate::work_item_field_values_repository::WorkItemFieldValuesRepository;
+use crate::work_item_history_repository::WorkItemHistoryRepository;
+use crate::assignees_repository::AssigneesRepository;
+
+/// The repositories and pool of the operations that write whole work items with their
+/// numbers, relationships, field values and history
+#[derive(Clone, Copy)]
+pub struct WorkItemRepositories<'a> {
+    pub repository: &'a Arc<dyn WorkItemsRepository>,
+    pub work_item_types_repository: &'a Arc<dyn WorkItemTypesRepository>,
+    pub number_ranges_repository: &'a Arc<dyn WorkItemNumberRangesRepository>,
+    pub number_aliases_repository: &'a Arc<dyn WorkItemNumberAliasesRepository>,
+    pub relationships_repository: &'a Arc<dyn WorkItemRelationshipsRepository>,
+    pub field_values_repository: &'a Arc<dyn WorkItemFieldValuesRepository>,
+    pub history_repository: &'a Arc<dyn WorkItemHistoryRepository>,
+    pub assignees_repository: &'a Arc<dyn AssigneesRepository>,
+    pub pool: &'a Arc<ConnectionPool>,
+}
@@ -1,7 +1,13 @@
+use crate::schemas::AllowedStatus;
 use crate::models::{
-    RelationshipType, WorkItemListRequest, WorkItemListResponse, WorkItemModel,
-    WorkItemRelationshipModel, WorkItemTypeModel, WorkItemTypeTemplate,
+    RelationshipCycle, RelationshipType, WorkItemListRequest, WorkItemListResponse, WorkItemModel, WorkItemQuery,
+    WorkItemRelationshipModel, WorkItemTypeModel, WorkItemTypeTemplate, WorkItemHistoryEntryModel,
+    WorkItemHierarchyEntry, WorkItemTreeNode, SavedViewModel,
+    WorkItemSelection, WorkItemPatch, BulkUpdateResult, WorkItemTypeChange,
+    WorkItemTypeImpact, WorkItemTypeMigration, WorkItemFieldValueModel, FieldState,
+    WorkItemCloneOptions, WorkItemCloneResult, WorkItemMove, WorkItemMoveResult,
 };
+use db::Connection;
 use anyhow::Result;
 
 pub trait WorkItemsManager: Send + Sync {
@@ -18,16 +24,115 @@ pub trait WorkItemsManager: Send + Sync {
         sequence_prefix: &str,
         machine_id: &str,
     ) -> Result<WorkItemModel>;
+    /// Update a work item's core columns (title, status, priority, assignee, type)
+    /// and its field values.
+    ///
+    /// The field values on `work_item` replace the current set: changed values are
+    /// updated, new values are inserted and values that are missing are deactivated.
+    fn update_work_item(&self, work_item: WorkItemModel, updated_by: &str) -> Result<WorkItemModel>;
+    /// Apply one patch (status, priority, assignee, field values) to many work items,
+    /// selected by id or by query.
+    ///
+    /// Every item is validated against its type first. Changes are written in one
+    /// transaction, and only if every item is valid; the result reports each item's outcome.
+    fn bulk_update_work_items(
+        &self,
+        selection: WorkItemSelection,
+        patch: WorkItemPatch,
+        updated_by: &str,
+    ) -> Result<BulkUpdateResult>;
+    /// Change a work item's type, translating its status and field ids through the
+    /// mappings in `change`.
+    ///
+    /// Values the new type has no field for are kept as inactive rows. The result must be
+    /// valid for the new type and for the parent/child rules of the item's relationships.
+    fn change_work_item_type(
+        &self,
+        work_item_id: &str,
+        change: WorkItemTypeChange,
+        updated_by: &str,
+    ) -> Result<WorkItemModel>;
+    /// Archive a work item. Archived items are left out of `list_work_items` unless the
+    /// query asks for them, and can't be changed until they are restored.
+    fn archive_work_item(&self, work_item_id: &str, archived_by: &str) -> Result<WorkItemModel>;
+    /// Restore an archived work item
+    fn restore_work_item(&self, work_item_id: &str, restored_by: &str) -> Result<WorkItemModel>;
+    /// Archive a work item as part of the caller's unit of work on `conn`, so that whatever
+    /// the caller records about it commits together with the archiving. A work item that is
+    /// already archived is left as it is.
+    fn ensure_work_item_archived(&self, conn: &mut Connection, work_item_id: &str, archived_by: &str) -> Result<WorkItemModel>;
+    /// Restore a work item as part of the caller's unit of work on `conn`. A work item that
+    /// is not archived is left as it is.
+    fn ensure_work_item_restored(&self, conn: &mut Connection, work_item_id: &str, restored_by: &str) -> Result<WorkItemModel>;
+    /// Permanently delete an archived work item together with its field values,
+    /// relationships, history, number aliases, tag assignments and the conversations scoped to it
+    fn purge_work_item(&self, work_item_id: &str) -> Result<()>;
+    /// Clone a work item with new sequential numbers, and with `options.recursive` its
+    /// non-archived descendants, recreating the parent/child links between the copies.
+    /// Field values are copied; `options` decide whether the copies start in the initial
+    /// status and whether their assignees are cleared.
+    fn clone_work_item(
+        &self,
+        work_item_id: &str,
+        options: WorkItemCloneOptions,
+        cloned_by: &str,
+        sequence_prefix: &str,
+        machine_id: &str,
+    ) -> Result<WorkItemCloneResult>;
+    /// Move a work item to another project, and with `request.move_children` its descendants.
+    /// Types are mapped to the destination project's types, statuses and fields are remapped
+    /// as on a type change, and each moved item gets a new sequential number with
+    /// `sequence_prefix`. Old numbers keep resolving as aliases. Relationships to work items
+    /// that stay behind are detached.
+    fn move_work_item(
+        &self,
+        work_item_id: &str,
+        request: WorkItemMove,
+        moved_by: &str,
+        sequence_prefix: &str,
+        machine_id: &str,
+    ) -> Result<WorkItemMoveResult>;
+    /// Find a work item by its sequential number, or by a number it had before it moved
+    /// to another project
+    fn resolve_work_item_number(&self, sequential_number: &str) -> Result<Option<WorkItemModel>>;
     fn list_work_items(&self, request: WorkItemListRequest) -> Result<WorkItemListResponse>;
+    /// Parse a text query such as `type:Bug status:open priority>=3 assignee:me "login" sort:-updated`
+    /// into a `WorkItemQuery` for the project. `me` resolves to `current_user`.
+    fn parse_work_item_query(&self, project_id: &str, query: &str, current_user: Option<&str>) -> Result<WorkItemQuery>;
+    /// Get the statuses a work item can move to from its current status,
+    /// according to its type's workflow
+    fn get_next_statuses(&self, work_item_id: &str) -> Result<Vec<AllowedStatus>>;
+    /// Get which custom fields of a work item type are visible and required for a work item
+    /// with this status and these field values, according to the fields' conditional rules
+    fn get_field_states(
+        &self,
+        work_item_type_id: &str,
+        status: &str,
+        field_values: Vec<WorkItemFieldValueModel>,
+    ) -> Result<Vec<FieldState>>;
+    /// Get the audit log of a work item: core column, field value and relationship
+    /// changes, oldest first
+    fn get_work_item_history(&self, work_item_id: &str) -> Result<Vec<WorkItemHistoryEntryModel>>;
 
     // WorkItemType methods
     fn get_work_item_types_by_project(&self, project_id: &str) -> Result<Vec<WorkItemTypeModel>>;
     fn get_work_item_type(&self, id: &str) -> Result<Option<WorkItemTypeModel>>;
     fn create_work_item_type(&self, work_item_type: WorkItemTypeModel)
         -> Result<WorkItemTypeModel>;
-    fn update_work_item_type(&self, work_item_type: WorkItemTypeModel)
-        -> Result<WorkItemTypeModel>;
-    fn mark_work_item_type_inactive(&self, id: &str) -> Result<()>;
+    /// Report how many work items use each status and field an update to their type removes
+    fn analyze_work_item_type_update(&self, work_item_type: WorkItemTypeModel)
+        -> Result<WorkItemTypeImpact>;
+    /// Update a work item type and migrate its work items in one transaction.
+    ///
+    /// Removed statuses and fields that are still in use must be mapped or orphaned by `migration`.
+    fn update_work_item_type(
+        &self,
+        work_item_type: WorkItemTypeModel,
+        migration: WorkItemTypeMigration,
+        updated_by: &str,
+    ) -> Result<WorkItemTypeModel>;
+    /// Mark a work item type inactive and record it in the trash
+    fn mark_work_item_type_inactive(&self, id: &str, deleted_by: &str) -> Result<()>;
 
     /// Apply a template to create work item types for a project
     fn apply_template(
@@ -76,6 +181,33 @@ pub trait WorkItemsManager: Send + Sync {
         relationship_type: RelationshipType,
     ) -> Result<Vec<WorkItemRelationshipModel>>;
 
+    /// Get all ancestors of a work item (parent, grandparent, ...), nearest first
+    fn get_work_item_ancestors(&self, work_item_id: &str) -> Result<Vec<WorkItemHierarchyEntry>>;
+
+    /// Get all descendants of a work item, ordered by depth.
+    /// `max_depth` limits how many levels are returned (1 = direct children only).
+    fn get_work_item_descendants(&self, work_item_id: &str, max_depth: Option<u32>) -> Result<Vec<WorkItemHierarchyEntry>>;
+
+    /// Get a work item and its descendants as a nested tree, down to `max_depth` levels
+    fn get_work_item_subtree(&self, work_item_id: &str, max_depth: Option<u32>) -> Result<Option<WorkItemTreeNode>>;
+
     /// Delete a work item relationship (soft delete)
-    fn delete_work_item_relationship(&self, relationship_id: &str) -> Result<()>;
+    fn delete_work_item_relationship(&self, relationship_id: &str, deleted_by: &str) -> Result<()>;
+
+    /// List existing cycles among a project's parent/child and blocks relationships
+    fn check_relationship_integrity(&self, project_id: &str) -> Result<Vec<RelationshipCycle>>;
+
+    // SavedView methods
+    /// Save a named query and column selection for a project.
+    /// Views without a `person_id` are shared with the whole project.
+    fn create_saved_view(&self, saved_view: SavedViewModel, created_by: &str) -> Result<SavedViewModel>;
+    fn update_saved_view(&self, saved_view: SavedViewModel, updated_by: &str) -> Result<SavedViewModel>;
+    fn get_saved_view(&self, id: &str) -> Result<Option<SavedViewModel>>;
+    /// List a project's shared views plus, if `person_id` is given, that person's own views
+    fn list_saved_views(&self, project_id: &str, person_id: Option<&str>) -> Result<Vec<SavedViewModel>>;
+    /// Delete a saved view (soft delete)
+    fn delete_saved_view(&self, id: &str, deleted_by: &str) -> Result<()>;
+    /// Run a saved view's query with its column selection.
+    /// `page` and `page_size` override the pagination stored in the view.
+    fn execute_saved_view(&self, id: &str, page: Option<usize>, page_size: Option<usize>) -> Result<WorkItemListResponse>;
 }
@@ -1,8 +1,10 @@
 use crate::entities::WorkItem;
-use db::repository_base::GenericRepository;
+use db::repository_base::{Entity, GenericRepository};
+use db::{to_sql_vec, Connection, ToSql};
 use std::sync::Arc;
 use db::connection_pool::ConnectionPool;
-use crate::repository::WorkItemsRepository;
+use crate::models::RelationshipType;
+use crate::repository::{WorkItemsRepository, WorkItemHierarchyRow};
 
 pub struct SqliteWorkItemsRepository {
     inner: GenericRepository<WorkItem>,
@@ -16,13 +18,177 @@ impl SqliteWorkItemsRepository {
     }
 }
 
+/// Which way to walk the parent/child hierarchy
+enum HierarchyDirection {
+    Up,
+    Down,
+}
+
+impl SqliteWorkItemsRepository {
+    /// Walks the hierarchy with a recursive CTE.
+    ///
+    /// Parent and child relationships are first normalized into parent -> child edges, then
+    /// followed from `work_item_id` in the requested direction until `max_depth` is reached.
+    /// Unless `include_archived` is set, the walk stops at archived work items.
+    fn find_in_hierarchy(
+        &self,
+        work_item_id: &str,
+        max_depth: u32,
+        include_archived: bool,
+        direction: HierarchyDirection,
+    ) -> anyhow::Result<Vec<WorkItemHierarchyRow>> {
+        let (from_column, to_column) = match direction {
+            HierarchyDirection::Up => ("child_id", "parent_id"),
+            HierarchyDirection::Down => ("parent_id", "child_id"),
+        };
+        let columns = WorkItem::columns()
+            .iter()
+            .map(|column| format!("w.{}", column))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let archived_filter = if include_archived { "" } else { "AND reached.archived_at IS NULL" };
+        let sql = format!(
+            "WITH RECURSIVE
+                edges(parent_id, child_id) AS (
+                    SELECT source_work_item_id, target_work_item_id FROM work_item_relationships
+                    WHERE relationship_type = '{parent}' AND is_active = 1
+                    UNION
+                    SELECT target_work_item_id, source_work_item_id FROM work_item_relationships
+                    WHERE relationship_type = '{child}' AND is_active = 1
+                ),
+                hierarchy(id, via_id, depth) AS (
+                    SELECT edges.{to}, edges.{from}, 1
+                    FROM edges JOIN work_items reached ON reached.id = edges.{to}
+                    WHERE edges.{from} = ?1 {archived_filter}
+                    UNION
+                    SELECT edges.{to}, edges.{from}, hierarchy.depth + 1
+                    FROM edges JOIN hierarchy ON edges.{from} = hierarchy.id
+                    JOIN work_items reached ON reached.id = edges.{to}
+                    WHERE hierarchy.depth < ?2 {archived_filter}
+                )
+             SELECT {columns}, hierarchy.via_id, hierarchy.depth
+             FROM hierarchy JOIN work_items w ON w.id = hierarchy.id
+             ORDER BY hierarchy.depth, w.created_at",
+            parent = RelationshipType::Parent.as_str(),
+            child = RelationshipType::Child.as_str(),
+            from = from_column,
+            to = to_column,
+            archived_filter = archived_filter,
+            columns = columns,
+        );
+
+        let column_count = WorkItem::columns().len();
+        self.inner.with_optional_connection(None, |conn| {
+            let params: &[&dyn ToSql] = &[&work_item_id, &max_depth];
+            conn.query(&sql, params, |row| {
+                Ok(WorkItemHierarchyRow {
+                    work_item: WorkItem::from_row(row)?,
+                    via_work_item_id: row.get(column_count)?,
+                    depth: row.get(column_count + 1)?,
+                })
+            })
+        })
+    }
+}
+
 impl WorkItemsRepository for SqliteWorkItemsRepository {
-    fn find_by_id(&self, id: &str) -> anyhow::Result<Option<WorkItem>> {
-        self.inner.find_by_id(id, None)
+    fn find_by_id(&self, id: &str, conn: Option<&mut Connection>) -> anyhow::Result<Option<WorkItem>> {
+        self.inner.find_by_id(id, conn)
     }
 
-    fn create(&self, work_item: WorkItem) -> anyhow::Result<WorkItem> {
-       self.inner.create(work_item, None) 
+    fn create(&self, work_item: WorkItem, conn: Option<&mut Connection>) -> anyhow::Result<WorkItem> {
+       self.inner.create(work_item, conn) 
     }
-}
 
+    fn update(&self, work_item: WorkItem, conn: Option<&mut Connection>) -> anyhow::Result<WorkItem> {
+        self.inner.with_optional_connection(conn, |conn| {
+            self.inner.update(&work_item, Some(conn))?;
+            Ok(work_item)
+        })
+    }
+
+    fn delete(&self, id: &str, conn: Option<&mut Connection>) -> anyhow::Result<()> {
+        self.inner.delete(id, conn)?;
+        Ok(())
+    }
+
+    fn delete_tags_and_conversations(&self, work_item_id: &str, conn: Option<&mut Connection>) -> anyhow::Result<()> {
+        let conversations = "SELECT id FROM conversations WHERE conversation_scope = 'work_item' AND conversation_scope_id = ?1";
+        let statements = [
+            "DELETE FROM tagged_items WHERE reference_type = 'work_item' AND reference_id = ?1".to_string(),
+            format!("DELETE FROM read_records WHERE conversation_id IN ({})", conversations),
+            format!("DELETE FROM messages WHERE conversation_id IN ({})", conversations),
+            "DELETE FROM conversations WHERE conversation_scope = 'work_item' AND conversation_scope_id = ?1".to_string(),
+        ];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            for sql in &statements {
+                let values = to_sql_vec![work_item_id.to_string()];
+                conn.execute(sql, rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn move_to_project(&self, work_item_id: &str, project_id: &str, sequential_number: &str, conn: Option<&mut Connection>) -> anyhow::Result<()> {
+        let (id, project_id) = (work_item_id.to_string(), project_id.to_string());
+        let statements = [
+            (
+                "UPDATE work_items SET project_id = ?1, sequential_number = ?2 WHERE id = ?3",
+                to_sql_vec![project_id.clone(), sequential_number.to_string(), id.clone()],
+            ),
+            (
+                "UPDATE work_item_field_values SET project_id = ?1 WHERE work_item_id = ?2",
+                to_sql_vec![project_id.clone(), id.clone()],
+            ),
+            (
+                "UPDATE work_item_history SET project_id = ?1 WHERE work_item_id = ?2",
+                to_sql_vec![project_id.clone(), id.clone()],
+            ),
+            (
+                "DELETE FROM tagged_items WHERE reference_type = 'work_item' AND reference_id = ?1
+                    AND tag_id IN (SELECT id FROM tags WHERE project_id IS NOT ?2)",
+                to_sql_vec![id.clone(), project_id.clone()],
+            ),
+        ];
+
+        self.inner.with_optional_connection(conn, |conn| {
+            for (sql, values) in &statements {
+                conn.execute(sql, rusqlite::params_from_iter(values.iter().map(|v| v.as_ref())))?;
+            }
+            Ok(())
+        })
+    }
+
+    fn find_by_sequential_number(&self, sequential_number: &str, conn: Option<&mut Connection>) -> anyhow::Result<Vec<WorkItem>> {
+        let sql = format!(
+            "SELECT {} FROM {} WHERE sequential_number = ?1 ORDER BY created_at",
+            WorkItem::columns().join(", "),
+            WorkItem::table_name()
+        );
+        self.inner.with_optional_connection(conn, |conn| {
+            let params: &[&dyn ToSql] = &[&sequential_number];
+            Ok(conn.query(&sql, params, |row| WorkItem::from_row(row))?)
+        })
+    }
+
+    fn find_by_type_id(&self, type_id: &str, conn: Option<&mut Connection>) -> anyhow::Result<Vec<WorkItem>> {
+        let sql = format!(
+            "SELECT {} FROM {} WHERE type_id = ?1 ORDER BY created_at",
+            WorkItem::columns().join(", "),
+            WorkItem::table_name()
+        );
+        self.inner.with_optional_connection(conn, |conn| {
+            let params: &[&dyn ToSql] = &[&type_id];
+            Ok(conn.query(&sql, params, |row| WorkItem::from_row(row))?)
+        })
+    }
+
+    fn find_ancestors(&self, work_item_id: &str, max_depth: u32, include_archived: bool) -> anyhow::Result<Vec<WorkItemHierarchyRow>> {
+        self.find_in_hierarchy(work_item_id, max_depth, include_archived, HierarchyDirection::Up)
+    }
+
+    fn find_descendants(&self, work_item_id: &str, max_depth: u32, include_archived: bool) -> anyhow::Result<Vec<WorkItemHierarchyRow>> {
+        self.find_in_hierarchy(work_item_id, max_depth, include_archived, HierarchyDirection::Down)
+    }
+}
@@ -32,11 +32,13 @@ interface FormValues {
     description: string;
     iconAttachmentId: string | null;
     color: string;
+    trashRetentionDays: string;
 }
 
 const MAX_FILE_SIZE = 2 * 1024 * 1024; // 2MB
 const ALLOWED_FILE_TYPES = ['image/png', 'image/jpeg', 'image/jpg', 'image/gif'];
 const ALLOWED_EXTENSIONS = ['png', 'jpg', 'jpeg', 'gif'];
+const DEFAULT_TRASH_RETENTION_DAYS = 30; // Matches Rust DEFAULT_TRASH_RETENTION_DAYS
 
 export default function ProjectGeneralSettings({ projectId }: ProjectGeneralSettingsProps) {
     const {
@@ -53,6 +55,7 @@ export default function ProjectGeneralSettings({ projectId }: ProjectGeneralSett
             description: '',
             iconAttachmentId: null,
             color: '#3B82F6',
+            trashRetentionDays: String(DEFAULT_TRASH_RETENTION_DAYS),
         },
     });
 
@@ -137,6 +140,10 @@ export default function ProjectGeneralSettings({ projectId }: ProjectGeneralSett
                 projectId,
                 settingKey: 'COLOR',
             });
+            const retentionResult = await invoke<any>('get_project_setting', {
+                projectId,
+                settingKey: 'TRASH_RETENTION_DAYS',
+            });
 
             const iconId = iconIdResult 
                 ? (typeof iconIdResult === 'string' ? iconIdResult : iconIdResult.toString())
@@ -151,6 +158,7 @@ export default function ProjectGeneralSettings({ projectId }: ProjectGeneralSett
                 description: projectData.description || '',
                 iconAttachmentId: iconId,
                 color: color,
+                trashRetentionDays: String(retentionResult ?? DEFAULT_TRASH_RETENTION_DAYS),
             });
 
             if (iconId) {
@@ -349,6 +357,14 @@ export default function ProjectGeneralSettings({ projectId }: ProjectGeneralSett
                 updatedBy: 'user',
             });
 
+            // Save trash retention setting
+            await invoke('set_project_setting', {
+                projectId,
+                settingKey: 'TRASH_RETENTION_DAYS',
+                settingValue: Number(data.trashRetentionDays),
+                updatedBy: 'user',
+            });
+
             // Reload project data
             await loadProjectData();
         } catch (err) {
@@ -492,6 +508,35 @@ export default function ProjectGeneralSettings({ projectId }: ProjectGeneralSett
                             />
                         </Box>
 
+                        <Separator size="4" />
+
+                        {/* Trash Retention Setting */}
+                        <Box>
+                            <Text size="2" weight="medium" mb="2" as="label" htmlFor="trashRetentionDays">
+                                Trash Retention (days)
+                            </Text>
+                            <Text size="1" color="gray" mb="3" as="p">
+                                Deleted items stay in the trash this long before they are removed permanently.
+                            </Text>
+                            <TextField.Root
+                                id="trashRetentionDays"
+                                type="number"
+                                min={0}
+                                {...register('trashRetentionDays', {
+                                    required: 'Trash retention is required',
+                                    validate: (value) => /^\d+$/.test(value.trim()) || 'Enter a whole number of days',
+                                })}
+                                size="3"
+                                style={{ width: '120px' }}
+                                color={errors.trashRetentionDays ? 'red' : undefined}
+                            />
+                            {errors.trashRetentionDays && (
+                                <Text size="1" color="red" mt="1" as="p">
+                                    {errors.trashRetentionDays.message}
+                                </Text>
+                            )}
+                        </Box>
+
                         {/* Error Message */}
                         {error && (
                             <Box>
@@ -17,6 +17,7 @@ import { getCurrentWindow } from "@tauri-apps/api/window";
 import {RichTextEditor} from "../editor";
 import type { WorkItemField } from "../../data/templates/types";
 import type {
+    FieldState,
     WorkItemTypeModel,
     WorkItemData,
     WorkItemEditProps,
@@ -54,11 +55,13 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
     const [newLabel, setNewLabel] = useState("");
     const [workItemTypes, setWorkItemTypes] = useState<WorkItemTypeModel[]>([]);
     const [isLoadingTypes, setIsLoadingTypes] = useState(false);
+    const [fieldStates, setFieldStates] = useState<FieldState[]>([]);
     
     const watchedLabels = watch("labels");
     const watchedType = watch("type");
     const watchedAssignmentFields = watch("assignmentFields");
     const watchedCustomFields = watch("customFields");
+    const watchedStatus = watch("status");
     
     // Get the currently selected work item type
     const selectedType = workItemTypes.find(
@@ -71,6 +74,55 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
     const assignmentFieldDefinitions = selectedType?.assignment_field_definitions || [];
     const workItemFields = selectedType?.work_item_fields || [];
     
+    // Conditional field rules: which custom fields are shown and required for the current
+    // status and values
+    useEffect(() => {
+        const typeId = selectedType?.id;
+        if (!typeId || !watchedStatus) {
+            setFieldStates([]);
+            return;
+        }
+
+        const toFieldValue = (fieldId: string, value: any, isAssignmentField: boolean) => ({
+            id: null,
+            project_id: activeProjectId,
+            work_item_id: '',
+            field_id: fieldId,
+            is_assignment_field: isAssignmentField,
+            value: typeof value === 'string' ? value : JSON.stringify(value),
+            created_at: '',
+            updated_at: null,
+            created_by: '',
+            updated_by: null,
+            is_active: true,
+            field_definition: null,
+        });
+        const fieldValues = [
+            ...Object.entries(watchedAssignmentFields || {})
+                .filter(([_, value]) => value !== undefined && value !== null)
+                .map(([fieldId, value]) => toFieldValue(fieldId, value, true)),
+            ...Object.entries(watchedCustomFields || {})
+                .filter(([_, value]) => value !== undefined && value !== null)
+                .map(([fieldId, value]) => toFieldValue(fieldId, value, false)),
+        ];
+
+        invoke<FieldState[]>('get_field_states', {
+            workItemTypeId: typeId,
+            status: watchedStatus,
+            fieldValues,
+        })
+            .then(setFieldStates)
+            .catch(error => {
+                console.error('Failed to get field states:', error);
+                setFieldStates([]);
+            });
+        // eslint-disable-next-line react-hooks/exhaustive-deps
+    }, [selectedType, watchedStatus, JSON.stringify(watchedCustomFields), JSON.stringify(watchedAssignmentFields)]);
+
+    const fieldStateFor = (field: WorkItemField) =>
+        fieldStates.find(state => state.field_id === field.id)
+        ?? { field_id: field.id, visible: true, required: field.required };
+
     // Update window title when project is loaded
     useEffect(() => {
         const updateWindowTitle = async () => {
@@ -128,8 +180,8 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
                         
                         // Set default priority and status from the first type
                         if (firstType.allowed_priorities.length > 0) {
-                            const firstPriority = firstType.allowed_priorities[0];
-                            setValue("priority", firstPriority.id, { shouldValidate: false });
+                            const defaultPriority = firstType.allowed_priorities.find(p => p.is_default) ?? firstType.allowed_priorities[0];
+                            setValue("priority", defaultPriority.id, { shouldValidate: false });
                         }
                         if (firstType.allowed_statuses.length > 0) {
                             const firstStatus = firstType.allowed_statuses[0];
@@ -159,7 +211,8 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
         // Check if current priority is valid for the new type
         const priorityExists = allowedPriorities.some(p => p.id === currentPriority);
         if (!priorityExists && allowedPriorities.length > 0) {
-            setValue("priority", allowedPriorities[0].id, { shouldValidate: false });
+            const defaultPriority = allowedPriorities.find(p => p.is_default) ?? allowedPriorities[0];
+            setValue("priority", defaultPriority.id, { shouldValidate: false });
         }
         
         // Check if current status is valid for the new type
@@ -237,9 +290,10 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
     };
 
     // Helper function to build validation rules for custom fields
-    const buildFieldValidationRules = (field: WorkItemField) => {
+    const buildFieldValidationRules = (field: WorkItemField, required: boolean) => {
         const rules: any = {
-            required: field.required ? `${field.label} is required` : false,
+            // An unchecked boolean field still has a value (false)
+            required: required && field.field_type !== "boolean" ? `${field.label} is required` : false,
         };
 
         if (field.validation) {
@@ -279,7 +333,12 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
     };
 
     const onSubmit = (data: WorkItemData) => {
-        onSave?.(data);
+        // Hidden fields can't be given a value, so leave them out
+        const customFields = { ...data.customFields };
+        workItemFields
+            .filter(field => !fieldStateFor(field).visible)
+            .forEach(field => delete customFields[field.id]);
+        onSave?.({ ...data, customFields });
     };
 
     const handleSaveAndAddAnother = async () => {
@@ -589,14 +648,18 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
                             {workItemFields.map((fieldDef) => {
                                 const fieldName = `customFields.${fieldDef.id}` as const;
                                 const fieldError = errors.customFields?.[fieldDef.id];
-                                const validationRules = buildFieldValidationRules(fieldDef);
+                                const ruleState = fieldStateFor(fieldDef);
+                                if (!ruleState.visible) {
+                                    return null;
+                                }
+                                const validationRules = buildFieldValidationRules(fieldDef, ruleState.required);
                                 
                                 return (
                                     <Flex key={fieldDef.id} align="start" gap="4">
                                         <Box style={{ width: "120px", flexShrink: 0, paddingTop: "8px" }}>
                                             <Text size="2" weight="medium" as="label" htmlFor={`custom-${fieldDef.id}`}>
                                                 {fieldDef.label}
-                                                {fieldDef.required && " *"}
+                                                {ruleState.required && " *"}
                                             </Text>
                                         </Box>
                                         <Box style={{ flex: 1 }}>
@@ -643,6 +706,8 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
 
                                                     case "number":
                                                     case "integer":
+                                                    case "currency":
+                                                    case "duration":
                                                         return (
                                                             <Controller
                                                                 name={fieldName}
@@ -660,12 +725,19 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
                                                                             if (value === "") {
                                                                                 controllerField.onChange("");
                                                                             } else if (!isNaN(Number(value))) {
-                                                                                controllerField.onChange(fieldDef.field_type === "integer" ? parseInt(value, 10) : Number(value));
+                                                                                controllerField.onChange(fieldDef.field_type === "integer" || fieldDef.field_type === "duration" ? parseInt(value, 10) : Number(value));
                                                                             }
                                                                         }}
                                                                         size="3"
                                                                         color={fieldState.error ? "red" : undefined}
-                                                                    />
+                                                                    >
+                                                                        {fieldDef.field_type === "currency" && fieldDef.currency_code && (
+                                                                            <TextField.Slot side="right">{fieldDef.currency_code}</TextField.Slot>
+                                                                        )}
+                                                                        {fieldDef.field_type === "duration" && (
+                                                                            <TextField.Slot side="right">min</TextField.Slot>
+                                                                        )}
+                                                                    </TextField.Root>
                                                                 )}
                                                             />
                                                         );
@@ -755,6 +827,104 @@ export default function WorkItemEdit({ workItem, activeProjectId, onSave, onCanc
                                                             />
                                                         );
 
+                                                    case "multi_select":
+                                                        return (
+                                                            <Controller
+                                                                name={fieldName}
+                                                                control={control}
+                                                                rules={validationRules}
+                                                                render={({ field: controllerField }) => {
+                                                                    const selected: string[] = Array.isArray(controllerField.value) ? controllerField.value : [];
+                                                                    return (
+                                                                        <Flex direction="column" gap="2">
+                                                                            {(fieldDef.options || []).map((option) => (
+                                                                                <Flex key={option.value} align="center" gap="2">
+                                                                                    <input
+                                                                                        type="checkbox"
+                                                                                        id={`custom-${fieldDef.id}-${option.value}`}
+                                                                                        checked={selected.includes(option.value)}
+                                                                                        onChange={(e) => controllerField.onChange(
+                                                                                            e.target.checked
+                                                                                                ? [...selected, option.value]
+                                                                                                : selected.filter(value => value !== option.value)
+                                                                                        )}
+                                                                                    />
+                                                                                    <Text size="2" as="label" htmlFor={`custom-${fieldDef.id}-${option.value}`}>
+                                                                                        {option.label}
+                                                                                    </Text>
+                                                                                </Flex>
+                                                                            ))}
+                                                                        </Flex>
+                                                                    );
+                                                                }}
+                                                            />
+                                                        );
+
+                                                    case "boolean":
+                                                        return (
+                                                            <Controller
+                                                                name={fieldName}
+                                                                control={control}
+                                                                rules={validationRules}
+                                                                render={({ field: controllerField }) => (
+                                                                    <Flex align="center" gap="2" style={{ paddingTop: "8px" }}>
+                                                                        <input
+                                                                            type="checkbox"
+                                                                            id={`custom-${fieldDef.id}`}
+                                                                            checked={controllerField.value === true || controllerField.value === "true"}
+                                                                            onChange={(e) => controllerField.onChange(e.target.checked)}
+                                                                        />
+                                                                    </Flex>
+                                                                )}
+                                                            />
+                                                        );
+
+                                                    case "url":
+                                                    case "email":
+                                                    case "person":
+                                                    case "team":
+                                                        return (
+                                                            <Controller
+                                                                name={fieldName}
+                                                                control={control}
+                                                                rules={validationRules}
+                                                                render={({ field: controllerField, fieldState }) => (
+                                                                    <TextField.Root
+                                                                        id={`custom-${fieldDef.id}`}
+                                                                        type={fieldDef.field_type === "url" || fieldDef.field_type === "email" ? fieldDef.field_type : "text"}
+                                                                        placeholder={
+                                                                            fieldDef.field_type === "person" || fieldDef.field_type === "team"
+                                                                                ? `Enter ${fieldDef.field_type} id`
+                                                                                : `Enter ${fieldDef.label.toLowerCase()}`
+                                                                        }
+                                                                        value={controllerField.value || ""}
+                                                                        onChange={(e) => controllerField.onChange(e.target.value)}
+                                                                        size="3"
+                                                                        color={fieldState.error ? "red" : undefined}
+                                                                    />
+                                                                )}
+                                                            />
+                                                        );
+
+                                                    case "rollup":
+                                                    case "formula":
+                                                        // Computed on read, so shown but never edited
+                                                        return (
+                                                            <Controller
+                                                                name={fieldName}
+                                                                control={control}
+                                                                render={({ field: controllerField }) => (
+                                                                    <TextField.Root
+                                                                        id={`custom-${fieldDef.id}`}
+                                                                        value={controllerField.value !== undefined && controllerField.value !== null ? String(controllerField.value) : ""}
+                                                                        placeholder={fieldDef.field_type === "rollup" ? "Computed from child work items" : "Computed from other fields"}
+                                                                        readOnly
+                                                                        size="3"
+                                                                    />
+                                                                )}
+                                                            />
+                                                        );
+
                                                     default:
                                                         // Fallback to text input for unknown field types
                                                         return (
@@ -156,8 +156,43 @@ export default function WorkItemView({ workItemId }: WorkItemViewProps) {
             }
         }
 
-        // For date/datetime fields, format the date
-        if ('field_type' in fieldDef && (fieldDef.field_type === 'date' || fieldDef.field_type === 'datetime')) {
+        // For multi-select fields, list the option labels
+        if ('field_type' in fieldDef && fieldDef.field_type === 'multi_select' && 'options' in fieldDef) {
+            try {
+                const selected = JSON.parse(String(value));
+                if (Array.isArray(selected)) {
+                    if (selected.length === 0) {
+                        return '—';
+                    }
+                    return selected
+                        .map(v => fieldDef.options?.find(opt => opt.value === v)?.label ?? String(v))
+                        .join(', ');
+                }
+            } catch {
+                // If not JSON, return as-is
+            }
+        }
+
+        if ('field_type' in fieldDef && fieldDef.field_type === 'boolean') {
+            return String(value) === 'true' ? 'Yes' : 'No';
+        }
+
+        if ('field_type' in fieldDef && fieldDef.field_type === 'currency' && 'currency_code' in fieldDef && fieldDef.currency_code) {
+            const amount = Number(value);
+            if (!isNaN(amount)) {
+                return amount.toLocaleString(undefined, { style: 'currency', currency: fieldDef.currency_code });
+            }
+        }
+
+        if ('field_type' in fieldDef && fieldDef.field_type === 'duration') {
+            const minutes = Number(value);
+            if (Number.isInteger(minutes)) {
+                const hours = Math.floor(minutes / 60);
+                return hours > 0 ? `${hours}h ${minutes % 60}m` : `${minutes}m`;
+            }
+        }
+
+        // For date/datetime fields, format the date || fieldDef.field_type === 'datetime')) {
             try {
                 const date = new Date(String(value));
                 if (!isNaN(date.getTime())) {
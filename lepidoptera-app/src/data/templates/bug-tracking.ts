@@ -203,6 +203,7 @@ export const bugTrackingTemplate: ProjectTemplate = {
           label: 'Root Cause',
           field_type: 'text',
           required: false,
+          required_when: { kind: 'status', statuses: ['completed'] },
         },
       ],
     },
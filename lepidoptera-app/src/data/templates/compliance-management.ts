@@ -263,6 +263,41 @@ export const complianceManagementTemplate: ProjectTemplate = {
           field_type: 'text',
           required: false,
         },
+        {
+          id: 'impact',
+          label: 'Impact (1-5)',
+          field_type: 'number',
+          required: false,
+          validation: { min: 1, max: 5 },
+        },
+        {
+          id: 'likelihood',
+          label: 'Likelihood (1-5)',
+          field_type: 'number',
+          required: false,
+          validation: { min: 1, max: 5 },
+        },
+        {
+          id: 'risk-score',
+          label: 'Risk Score',
+          field_type: 'formula',
+          required: false,
+          formula: 'impact * likelihood',
+        },
+        {
+          id: 'days-open',
+          label: 'Days Open',
+          field_type: 'formula',
+          required: false,
+          formula: 'round(now - created_at)',
+        },
+        {
+          id: 'days-to-remediation',
+          label: 'Days to Remediation',
+          field_type: 'formula',
+          required: false,
+          formula: 'round([remediation-date] - today)',
+        },
       ],
     },
   ],
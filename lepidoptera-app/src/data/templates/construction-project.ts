@@ -50,7 +50,18 @@ export const constructionProjectTemplate: ProjectTemplate = {
         color: '#6366f1',
         description: 'A construction program',
       },
-      workItemFields: [],
+      workItemFields: [
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'budget',
+          },
+        },
+      ],
     },
     {
       name: 'project',
@@ -89,6 +100,16 @@ export const constructionProjectTemplate: ProjectTemplate = {
           field_type: 'text',
           required: false,
         },
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'budget',
+          },
+        },
       ],
     },
     {
@@ -125,6 +146,16 @@ export const constructionProjectTemplate: ProjectTemplate = {
             { value: 'commissioning', label: 'Commissioning' },
           ],
         },
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'budget',
+          },
+        },
       ],
     },
     {
@@ -168,6 +199,16 @@ export const constructionProjectTemplate: ProjectTemplate = {
             { value: 'civil', label: 'Civil' },
           ],
         },
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'budget',
+          },
+        },
       ],
     },
     {
@@ -197,7 +238,18 @@ export const constructionProjectTemplate: ProjectTemplate = {
         color: '#f59e0b',
         description: 'A work package within a discipline',
       },
-      workItemFields: [],
+      workItemFields: [
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'budget',
+          },
+        },
+      ],
     },
     {
       name: 'activity',
@@ -234,6 +286,13 @@ export const constructionProjectTemplate: ProjectTemplate = {
           required: false,
           validation: { min: 0, max: 1000 },
         },
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'number',
+          required: false,
+          validation: { min: 0 },
+        },
       ],
     },
     {
@@ -68,6 +68,24 @@ export const hrOperationsTemplate: ProjectTemplate = {
           field_type: 'date',
           required: false,
         },
+        {
+          id: 'work-email',
+          label: 'Work Email',
+          field_type: 'email',
+          required: false,
+        },
+        {
+          id: 'line-manager',
+          label: 'Line Manager',
+          field_type: 'person',
+          required: false,
+        },
+        {
+          id: 'home-team',
+          label: 'Team',
+          field_type: 'team',
+          required: false,
+        },
       ],
     },
     {
@@ -134,6 +152,13 @@ export const hrOperationsTemplate: ProjectTemplate = {
           field_type: 'date',
           required: false,
         },
+        {
+          id: 'requires-manager-approval',
+          label: 'Requires Manager Approval',
+          field_type: 'boolean',
+          required: false,
+          default_value: true,
+        },
       ],
     },
     {
@@ -189,6 +214,12 @@ export const hrOperationsTemplate: ProjectTemplate = {
           field_type: 'date',
           required: false,
         },
+        {
+          id: 'expected-duration',
+          label: 'Expected Duration (minutes)',
+          field_type: 'duration',
+          required: false,
+        },
       ],
     },
     {
@@ -148,6 +148,20 @@ export const itIncidentManagementTemplate: ProjectTemplate = {
           field_type: 'date',
           required: false,
         },
+        {
+          id: 'resolution-summary',
+          label: 'Resolution Summary',
+          field_type: 'text',
+          required: false,
+          required_when: { kind: 'status', statuses: ['resolved', 'closed'] },
+        },
+        {
+          id: 'major-incident-review',
+          label: 'Major Incident Review',
+          field_type: 'text',
+          required: false,
+          visible_when: { kind: 'field', field_id: 'impact', operator: 'in', values: ['high', 'critical'] },
+        },
       ],
     },
     {
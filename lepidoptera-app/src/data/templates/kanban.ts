@@ -18,13 +18,13 @@ export const kanbanTemplate: ProjectTemplate = {
       displayName: 'Epic',
       allowedChildrenTypeNames: ['feature'],
       allowedStatuses: [
-        { id: 'backlog', label: 'Backlog', color: '#6b7280' },
-        { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
-        { id: 'done', label: 'Done', color: '#10b981' },
+        { id: 'backlog', label: 'Backlog', color: '#6b7280', category: 'todo' },
+        { id: 'in-progress', label: 'In Progress', color: '#3b82f6', category: 'in_progress' },
+        { id: 'done', label: 'Done', color: '#10b981', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
-        { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b' },
+        { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b', is_default: true },
         { id: 'high', label: 'High', value: 3, color: '#ef4444' },
       ],
       assignmentFieldDefinitions: [
@@ -54,14 +54,14 @@ export const kanbanTemplate: ProjectTemplate = {
       displayName: 'Feature',
       allowedChildrenTypeNames: ['work-item'],
       allowedStatuses: [
-        { id: 'backlog', label: 'Backlog', color: '#6b7280' },
-        { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
-        { id: 'review', label: 'In Review', color: '#f59e0b' },
-        { id: 'done', label: 'Done', color: '#10b981' },
+        { id: 'backlog', label: 'Backlog', color: '#6b7280', category: 'todo' },
+        { id: 'in-progress', label: 'In Progress', color: '#3b82f6', category: 'in_progress' },
+        { id: 'review', label: 'In Review', color: '#f59e0b', category: 'in_progress' },
+        { id: 'done', label: 'Done', color: '#10b981', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
-        { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b' },
+        { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b', is_default: true },
         { id: 'high', label: 'High', value: 3, color: '#ef4444' },
       ],
       assignmentFieldDefinitions: [
@@ -91,14 +91,14 @@ export const kanbanTemplate: ProjectTemplate = {
       displayName: 'Work Item',
       allowedChildrenTypeNames: ['task'],
       allowedStatuses: [
-        { id: 'backlog', label: 'Backlog', color: '#6b7280' },
-        { id: 'to-do', label: 'To Do', color: '#9ca3af' },
-        { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
-        { id: 'done', label: 'Done', color: '#10b981' },
+        { id: 'backlog', label: 'Backlog', color: '#6b7280', category: 'todo' },
+        { id: 'to-do', label: 'To Do', color: '#9ca3af', category: 'todo' },
+        { id: 'in-progress', label: 'In Progress', color: '#3b82f6', category: 'in_progress' },
+        { id: 'done', label: 'Done', color: '#10b981', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
-        { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b' },
+        { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b', is_default: true },
         { id: 'high', label: 'High', value: 3, color: '#ef4444' },
       ],
       assignmentFieldDefinitions: [
@@ -121,13 +121,13 @@ export const kanbanTemplate: ProjectTemplate = {
       displayName: 'Task',
       allowedChildrenTypeNames: [],
       allowedStatuses: [
-        { id: 'to-do', label: 'To Do', color: '#9ca3af' },
-        { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
-        { id: 'done', label: 'Done', color: '#10b981' },
+        { id: 'to-do', label: 'To Do', color: '#9ca3af', category: 'todo' },
+        { id: 'in-progress', label: 'In Progress', color: '#3b82f6', category: 'in_progress' },
+        { id: 'done', label: 'Done', color: '#10b981', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
-        { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b' },
+        { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b', is_default: true },
         { id: 'high', label: 'High', value: 3, color: '#ef4444' },
       ],
       assignmentFieldDefinitions: [
@@ -59,6 +59,16 @@ export const pmbokTemplate: ProjectTemplate = {
           field_type: 'text',
           required: false,
         },
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'budget',
+          },
+        },
       ],
     },
     {
@@ -97,6 +107,16 @@ export const pmbokTemplate: ProjectTemplate = {
           field_type: 'text',
           required: false,
         },
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'budget',
+          },
+        },
       ],
     },
     {
@@ -132,6 +152,16 @@ export const pmbokTemplate: ProjectTemplate = {
             { value: 'no-go', label: 'No Go' },
           ],
         },
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'budget',
+          },
+        },
       ],
     },
     {
@@ -161,7 +191,18 @@ export const pmbokTemplate: ProjectTemplate = {
         color: '#10b981',
         description: 'A deliverable at the lowest level of WBS',
       },
-      workItemFields: [],
+      workItemFields: [
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'budget',
+          },
+        },
+      ],
     },
     {
       name: 'activity',
@@ -198,6 +239,13 @@ export const pmbokTemplate: ProjectTemplate = {
           required: false,
           validation: { min: 0, max: 1000 },
         },
+        {
+          id: 'budget',
+          label: 'Budget',
+          field_type: 'number',
+          required: false,
+          validation: { min: 0 },
+        },
       ],
     },
     {
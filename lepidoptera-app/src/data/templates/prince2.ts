@@ -72,6 +72,19 @@ export const prince2Template: ProjectTemplate = {
         { id: 'managing', label: 'Managing Product Delivery', color: '#8b5cf6' },
         { id: 'closing', label: 'Closing a Project', color: '#6b7280' },
       ],
+      // Follows the PRINCE2 process model: stages alternate between controlling and
+      // managing product delivery until the project is closed
+      workflow: {
+        initial_status: 'starting-up',
+        terminal_statuses: ['closing'],
+        transitions: [
+          { from: 'starting-up', to: ['initiating'] },
+          { from: 'initiating', to: ['directing'] },
+          { from: 'directing', to: ['controlling', 'closing'] },
+          { from: 'controlling', to: ['managing', 'directing', 'closing'] },
+          { from: 'managing', to: ['controlling'] },
+        ],
+      },
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
         { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b' },
@@ -114,6 +127,14 @@ export const prince2Template: ProjectTemplate = {
         { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
         { id: 'completed', label: 'Completed', color: '#6b7280' },
       ],
+      workflow: {
+        initial_status: 'authorized',
+        terminal_statuses: ['completed'],
+        transitions: [
+          { from: 'authorized', to: ['in-progress'] },
+          { from: 'in-progress', to: ['completed'] },
+        ],
+      },
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
         { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b' },
@@ -67,7 +67,8 @@ export const procurementTemplate: ProjectTemplate = {
         {
           id: 'estimated-cost',
           label: 'Estimated Cost',
-          field_type: 'number',
+          field_type: 'currency',
+          currency_code: 'USD',
           required: false,
           validation: { min: 0 },
         },
@@ -130,7 +131,8 @@ export const procurementTemplate: ProjectTemplate = {
         {
           id: 'po-amount',
           label: 'PO Amount',
-          field_type: 'number',
+          field_type: 'currency',
+          currency_code: 'USD',
           required: false,
           validation: { min: 0 },
         },
@@ -146,6 +148,12 @@ export const procurementTemplate: ProjectTemplate = {
           field_type: 'date',
           required: false,
         },
+        {
+          id: 'vendor-website',
+          label: 'Vendor Website',
+          field_type: 'url',
+          required: false,
+        },
       ],
     },
     {
@@ -207,6 +215,12 @@ export const procurementTemplate: ProjectTemplate = {
             { value: 'incomplete', label: 'Incomplete' },
           ],
         },
+        {
+          id: 'inspection-passed',
+          label: 'Inspection Passed',
+          field_type: 'boolean',
+          required: false,
+        },
       ],
     },
     {
@@ -246,7 +260,8 @@ export const procurementTemplate: ProjectTemplate = {
         {
           id: 'invoice-amount',
           label: 'Invoice Amount',
-          field_type: 'number',
+          field_type: 'currency',
+          currency_code: 'USD',
           required: false,
           validation: { min: 0 },
         },
@@ -119,6 +119,35 @@ export const safeTemplate: ProjectTemplate = {
           field_type: 'text',
           required: false,
         },
+        {
+          id: 'story-points',
+          label: 'Story Points',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'story-points',
+          },
+        },
+        {
+          id: 'remaining-estimate',
+          label: 'Remaining Estimate (hours)',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'remaining-estimate',
+          },
+        },
+        {
+          id: 'percent-complete',
+          label: '% Complete',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'percent_done',
+          },
+        },
       ],
     },
     {
@@ -148,7 +177,37 @@ export const safeTemplate: ProjectTemplate = {
         color: '#3b82f6',
         description: 'A solution capability',
       },
-      workItemFields: [],
+      workItemFields: [
+        {
+          id: 'story-points',
+          label: 'Story Points',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'story-points',
+          },
+        },
+        {
+          id: 'remaining-estimate',
+          label: 'Remaining Estimate (hours)',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'remaining-estimate',
+          },
+        },
+        {
+          id: 'percent-complete',
+          label: '% Complete',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'percent_done',
+          },
+        },
+      ],
     },
     {
       name: 'feature',
@@ -186,6 +245,35 @@ export const safeTemplate: ProjectTemplate = {
           field_type: 'text',
           required: false,
         },
+        {
+          id: 'story-points',
+          label: 'Story Points',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'story-points',
+          },
+        },
+        {
+          id: 'remaining-estimate',
+          label: 'Remaining Estimate (hours)',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'remaining-estimate',
+          },
+        },
+        {
+          id: 'percent-complete',
+          label: '% Complete',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'percent_done',
+          },
+        },
       ],
     },
     {
@@ -224,6 +312,16 @@ export const safeTemplate: ProjectTemplate = {
           required: false,
           validation: { min: 1, max: 100 },
         },
+        {
+          id: 'remaining-estimate',
+          label: 'Remaining Estimate (hours)',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'remaining-estimate',
+          },
+        },
       ],
     },
     {
@@ -261,6 +359,13 @@ export const safeTemplate: ProjectTemplate = {
           required: false,
           validation: { min: 0, max: 1000 },
         },
+        {
+          id: 'remaining-estimate',
+          label: 'Remaining Estimate (hours)',
+          field_type: 'number',
+          required: false,
+          validation: { min: 0, max: 1000 },
+        },
       ],
     },
   ],
@@ -24,9 +24,9 @@ export const scrumTemplate: ProjectTemplate = {
       displayName: 'Sprint',
       allowedChildrenTypeNames: ['epic', 'user-story', 'task', 'bug'],
       allowedStatuses: [
-        { id: 'planned', label: 'Planned', color: '#6366f1' },
-        { id: 'active', label: 'Active', color: '#10b981' },
-        { id: 'completed', label: 'Completed', color: '#6b7280' },
+        { id: 'planned', label: 'Planned', color: '#6366f1', category: 'todo' },
+        { id: 'active', label: 'Active', color: '#10b981', category: 'in_progress' },
+        { id: 'completed', label: 'Completed', color: '#6b7280', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
@@ -72,9 +72,9 @@ export const scrumTemplate: ProjectTemplate = {
       displayName: 'Epic',
       allowedChildrenTypeNames: ['user-story', 'task'],
       allowedStatuses: [
-        { id: 'backlog', label: 'Backlog', color: '#6b7280' },
-        { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
-        { id: 'done', label: 'Done', color: '#10b981' },
+        { id: 'backlog', label: 'Backlog', color: '#6b7280', category: 'todo' },
+        { id: 'in-progress', label: 'In Progress', color: '#3b82f6', category: 'in_progress' },
+        { id: 'done', label: 'Done', color: '#10b981', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
@@ -102,6 +102,35 @@ export const scrumTemplate: ProjectTemplate = {
           field_type: 'text',
           required: false,
         },
+        {
+          id: 'story-points',
+          label: 'Story Points',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'story-points',
+          },
+        },
+        {
+          id: 'remaining-estimate',
+          label: 'Remaining Estimate (hours)',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'remaining-estimate',
+          },
+        },
+        {
+          id: 'percent-complete',
+          label: '% Complete',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'percent_done',
+          },
+        },
       ],
     },
     {
@@ -109,11 +138,11 @@ export const scrumTemplate: ProjectTemplate = {
       displayName: 'User Story',
       allowedChildrenTypeNames: ['task', 'subtask'],
       allowedStatuses: [
-        { id: 'backlog', label: 'Backlog', color: '#6b7280' },
-        { id: 'to-do', label: 'To Do', color: '#9ca3af' },
-        { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
-        { id: 'in-review', label: 'In Review', color: '#f59e0b' },
-        { id: 'done', label: 'Done', color: '#10b981' },
+        { id: 'backlog', label: 'Backlog', color: '#6b7280', category: 'todo' },
+        { id: 'to-do', label: 'To Do', color: '#9ca3af', category: 'todo' },
+        { id: 'in-progress', label: 'In Progress', color: '#3b82f6', category: 'in_progress' },
+        { id: 'in-review', label: 'In Review', color: '#f59e0b', category: 'in_progress' },
+        { id: 'done', label: 'Done', color: '#10b981', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
@@ -150,6 +179,16 @@ export const scrumTemplate: ProjectTemplate = {
           field_type: 'text',
           required: false,
         },
+        {
+          id: 'remaining-estimate',
+          label: 'Remaining Estimate (hours)',
+          field_type: 'rollup',
+          required: false,
+          rollup: {
+            function: 'sum',
+            child_field_id: 'remaining-estimate',
+          },
+        },
       ],
     },
     {
@@ -157,10 +196,10 @@ export const scrumTemplate: ProjectTemplate = {
       displayName: 'Task',
       allowedChildrenTypeNames: ['subtask'],
       allowedStatuses: [
-        { id: 'to-do', label: 'To Do', color: '#9ca3af' },
-        { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
-        { id: 'blocked', label: 'Blocked', color: '#ef4444' },
-        { id: 'done', label: 'Done', color: '#10b981' },
+        { id: 'to-do', label: 'To Do', color: '#9ca3af', category: 'todo' },
+        { id: 'in-progress', label: 'In Progress', color: '#3b82f6', category: 'in_progress' },
+        { id: 'blocked', label: 'Blocked', color: '#ef4444', category: 'in_progress' },
+        { id: 'done', label: 'Done', color: '#10b981', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
@@ -191,6 +230,13 @@ export const scrumTemplate: ProjectTemplate = {
             max: 1000,
           },
         },
+        {
+          id: 'remaining-estimate',
+          label: 'Remaining Estimate (hours)',
+          field_type: 'number',
+          required: false,
+          validation: { min: 0, max: 1000 },
+        },
       ],
     },
     {
@@ -198,12 +244,12 @@ export const scrumTemplate: ProjectTemplate = {
       displayName: 'Bug',
       allowedChildrenTypeNames: ['subtask'],
       allowedStatuses: [
-        { id: 'reported', label: 'Reported', color: '#ef4444' },
-        { id: 'confirmed', label: 'Confirmed', color: '#f59e0b' },
-        { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
-        { id: 'fixed', label: 'Fixed', color: '#10b981' },
-        { id: 'verified', label: 'Verified', color: '#059669' },
-        { id: 'closed', label: 'Closed', color: '#6b7280' },
+        { id: 'reported', label: 'Reported', color: '#ef4444', category: 'todo' },
+        { id: 'confirmed', label: 'Confirmed', color: '#f59e0b', category: 'todo' },
+        { id: 'in-progress', label: 'In Progress', color: '#3b82f6', category: 'in_progress' },
+        { id: 'fixed', label: 'Fixed', color: '#10b981', category: 'in_progress' },
+        { id: 'verified', label: 'Verified', color: '#059669', category: 'in_progress' },
+        { id: 'closed', label: 'Closed', color: '#6b7280', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
@@ -268,9 +314,9 @@ export const scrumTemplate: ProjectTemplate = {
       displayName: 'Subtask',
       allowedChildrenTypeNames: [],
       allowedStatuses: [
-        { id: 'to-do', label: 'To Do', color: '#9ca3af' },
-        { id: 'in-progress', label: 'In Progress', color: '#3b82f6' },
-        { id: 'done', label: 'Done', color: '#10b981' },
+        { id: 'to-do', label: 'To Do', color: '#9ca3af', category: 'todo' },
+        { id: 'in-progress', label: 'In Progress', color: '#3b82f6', category: 'in_progress' },
+        { id: 'done', label: 'Done', color: '#10b981', category: 'done' },
       ],
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
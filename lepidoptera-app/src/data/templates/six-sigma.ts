@@ -67,6 +67,18 @@ export const sixSigmaTemplate: ProjectTemplate = {
         { id: 'control', label: 'Control', color: '#f59e0b' },
         { id: 'completed', label: 'Completed', color: '#6b7280' },
       ],
+      // DMAIC phases run in order; a project can step back one phase if findings require it
+      workflow: {
+        initial_status: 'define',
+        terminal_statuses: ['completed'],
+        transitions: [
+          { from: 'define', to: ['measure'] },
+          { from: 'measure', to: ['analyze', 'define'] },
+          { from: 'analyze', to: ['improve', 'measure'] },
+          { from: 'improve', to: ['control', 'analyze'] },
+          { from: 'control', to: ['completed', 'improve'] },
+        ],
+      },
       allowedPriorities: [
         { id: 'low', label: 'Low', value: 1, color: '#6b7280' },
         { id: 'medium', label: 'Medium', value: 2, color: '#f59e0b' },
@@ -104,6 +116,41 @@ export const sixSigmaTemplate: ProjectTemplate = {
           field_type: 'text',
           required: false,
         },
+        {
+          id: 'units',
+          label: 'Units Inspected',
+          field_type: 'number',
+          required: false,
+          validation: { min: 0 },
+        },
+        {
+          id: 'opportunities',
+          label: 'Defect Opportunities per Unit',
+          field_type: 'number',
+          required: false,
+          validation: { min: 1 },
+        },
+        {
+          id: 'defects',
+          label: 'Defects',
+          field_type: 'number',
+          required: false,
+          validation: { min: 0 },
+        },
+        {
+          id: 'dpmo',
+          label: 'DPMO',
+          field_type: 'formula',
+          required: false,
+          formula: 'round(defects / (units * opportunities) * 1000000)',
+        },
+        {
+          id: 'process-yield',
+          label: 'Process Yield (%)',
+          field_type: 'formula',
+          required: false,
+          formula: 'round(100 - dpmo / 10000, 2)',
+        },
       ],
     },
     {
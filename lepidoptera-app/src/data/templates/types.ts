@@ -4,11 +4,25 @@
  * lepidoptera-app/src-tauri/work_items/src/schemas/work_item_type_schemas.rs
  */
 
+export type StatusCategory = 'todo' | 'in_progress' | 'done';
+
 export interface AllowedStatus {
   id: string;
   label: string;
   description?: string;
   color?: string;
+  category?: StatusCategory; // Shared meaning of the status across templates
+}
+
+export interface StatusTransition {
+  from: string;
+  to: string[];
+}
+
+export interface WorkflowDefinition {
+  initial_status?: string; // Status new work items start in
+  terminal_statuses?: string[]; // Statuses that cannot be moved out of
+  transitions?: StatusTransition[]; // When empty, any status can move to any other
 }
 
 export interface AllowedPriority {
@@ -16,6 +30,7 @@ export interface AllowedPriority {
   label: string;
   value: number;
   color?: string;
+  is_default?: boolean; // Priority new work items get when none is given
 }
 
 export interface AssignmentFieldDefinition {
@@ -23,7 +38,7 @@ export interface AssignmentFieldDefinition {
   label: string;
   field_type: string; // e.g., "person", "team", "custom"
   required: boolean;
-  default_value?: string;
+  default_value?: string; // Static value, or "current user"
 }
 
 export interface FieldValidation {
@@ -39,14 +54,39 @@ export interface FieldOption {
   label: string;
 }
 
+export type RollupFunction = 'sum' | 'count' | 'min' | 'max' | 'percent_done';
+
+export interface RollupDefinition {
+  function: RollupFunction;
+  child_field_id?: string; // Number field of the children to aggregate (sum, min, max)
+  child_type_ids?: string[]; // Only aggregate children of these types; empty means all
+}
+
+export type ConditionOperator = 'in' | 'not_in' | 'is_empty' | 'is_not_empty';
+
+// Condition on a work item's status or another field's value, used by field rules
+export type FieldCondition =
+  | { kind: 'status'; statuses: string[] }
+  | { kind: 'status_category'; categories: StatusCategory[] }
+  | { kind: 'field'; field_id: string; is_assignment_field?: boolean; operator: ConditionOperator; values?: string[] }
+  | { kind: 'all'; conditions: FieldCondition[] }
+  | { kind: 'any'; conditions: FieldCondition[] };
+
 export interface WorkItemField {
   id: string;
   label: string;
-  field_type: string; // e.g., "text", "number", "date", "select", etc.
+  // e.g., "text", "number", "date", "select", "multi_select", "boolean", "url", "email",
+  // "currency", "duration" (minutes), "person", "team", "rollup", "formula"
+  field_type: string;
   required: boolean;
-  default_value?: any;
+  default_value?: any; // Static value, or dynamic: "today", "now", "today+7d", "current user"
   validation?: FieldValidation;
-  options?: FieldOption[]; // For select/radio fields
+  options?: FieldOption[]; // For select/radio/multi_select fields
+  currency_code?: string; // For "currency" fields, ISO 4217 code such as "USD"
+  rollup?: RollupDefinition; // For "rollup" fields, computed from child work items on read
+  formula?: string; // For "formula" fields, e.g. "impact * likelihood" or "round(now - created_at)"
+  required_when?: FieldCondition; // Required while the condition holds, on top of `required`
+  visible_when?: FieldCondition; // Shown only while the condition holds; hidden fields are never required
 }
 
 export interface WorkItemDetails {
@@ -86,6 +126,9 @@ export interface WorkItemTypeTemplate {
   
   // Custom fields for this work item type
   workItemFields: WorkItemField[];
+  
+  // Optional status workflow (transitions, initial and terminal statuses)
+  workflow?: WorkflowDefinition;
 }
 
 /**
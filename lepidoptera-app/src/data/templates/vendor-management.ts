@@ -69,6 +69,31 @@ export const vendorManagementTemplate: ProjectTemplate = {
           required: false,
           validation: { min: 0, max: 100 },
         },
+        {
+          id: 'website',
+          label: 'Website',
+          field_type: 'url',
+          required: false,
+        },
+        {
+          id: 'contact-email',
+          label: 'Contact Email',
+          field_type: 'email',
+          required: false,
+        },
+        {
+          id: 'service-categories',
+          label: 'Service Categories',
+          field_type: 'multi_select',
+          required: false,
+          options: [
+            { value: 'hardware', label: 'Hardware' },
+            { value: 'software', label: 'Software' },
+            { value: 'professional-services', label: 'Professional Services' },
+            { value: 'logistics', label: 'Logistics' },
+            { value: 'facilities', label: 'Facilities' },
+          ],
+        },
       ],
     },
     {
@@ -110,7 +135,8 @@ export const vendorManagementTemplate: ProjectTemplate = {
         {
           id: 'contract-value',
           label: 'Contract Value',
-          field_type: 'number',
+          field_type: 'currency',
+          currency_code: 'USD',
           required: false,
           validation: { min: 0 },
         },
@@ -132,6 +158,13 @@ export const vendorManagementTemplate: ProjectTemplate = {
           field_type: 'date',
           required: false,
         },
+        {
+          id: 'auto-renew',
+          label: 'Auto Renew',
+          field_type: 'boolean',
+          required: false,
+          default_value: false,
+        },
       ],
     },
     {
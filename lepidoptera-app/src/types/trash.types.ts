@@ -0,0 +1,50 @@
+/**
+ * TypeScript types for the Trash
+ *
+ * These types match the Rust models defined in:
+ * - lepidoptera-app/src-tauri/trash/src/models/
+ *
+ */
+
+/**
+ * Trash Entity Type
+ * Matches Rust TrashEntityType
+ */
+export type TrashEntityType =
+    | "work_item"
+    | "work_item_type"
+    | "document"
+    | "attachment"
+    | "person";
+
+/**
+ * Trash Entry
+ * Matches Rust TrashEntryModel. `project_id` is null for workspace entities such as persons.
+ */
+export interface TrashEntry {
+    id?: string | null;
+    project_id?: string | null;
+    entity_type: TrashEntityType;
+    entity_id: string;
+    display_name: string;
+    deleted_at: string;
+    deleted_by: string;
+}
+
+/**
+ * Skipped Trash Entry
+ * Matches Rust SkippedTrashEntry
+ */
+export interface SkippedTrashEntry {
+    entry: TrashEntry;
+    error: string;
+}
+
+/**
+ * Empty Trash Result
+ * Matches Rust EmptyTrashResult
+ */
+export interface EmptyTrashResult {
+    purged: TrashEntry[];
+    skipped: SkippedTrashEntry[];
+}
@@ -6,7 +6,7 @@
  *
  */
 
-import type { AllowedStatus, AllowedPriority, AssignmentFieldDefinition, WorkItemField } from '../data/templates/types';
+import type { AllowedStatus, AllowedPriority, AssignmentFieldDefinition, WorkItemField, WorkflowDefinition, StatusCategory } from '../data/templates/types';
 
 /**
  * Work Item Type Model
@@ -32,6 +32,7 @@ export interface WorkItemTypeModel {
         [key: string]: any;
     };
     work_item_fields: WorkItemField[];
+    workflow?: WorkflowDefinition | null;
 }
 
 /**
@@ -80,6 +81,9 @@ export interface WorkItemModel {
     project_id: string;
     type_id: string;
     sequential_number?: string | null;
+    updated_by?: string | null;
+    archived_at?: string | null;
+    archived_by?: string | null;
     field_values: WorkItemFieldValue[];
     // Optional fields that might be stored separately or in field_values
     labels?: string[];
@@ -109,13 +113,45 @@ export interface WorkItemData {
 export interface WorkItemQuery {
     project_id: string;
     statuses?: string[];
+    status_categories?: StatusCategory[];
     type_ids?: string[];
     assigned_to?: string;
     title_contains?: string;
+    field_value_queries?: FieldValueQuery[];
+    include_archived?: boolean;
     page?: number;
     page_size?: number;
 }
 
+/**
+ * Field Value Operator
+ * Matches Rust FieldValueOperator. Number and date fields compare by value, other fields as text.
+ */
+export type FieldValueOperator =
+    | "eq"
+    | "ne"
+    | "gt"
+    | "gte"
+    | "lt"
+    | "lte"
+    | "contains"
+    | "starts_with"
+    | "in"
+    | "is_empty"
+    | "is_not_empty";
+
+/**
+ * Field Value Query
+ * Matches Rust FieldValueQuery
+ */
+export interface FieldValueQuery {
+    field_id: string;
+    is_assignment_field: boolean;
+    operator?: FieldValueOperator;
+    value?: string;
+    values?: string[];
+}
+
 /**
  * Work Item List Request
  * Request payload for listing work items
@@ -123,6 +159,7 @@ export interface WorkItemQuery {
 export interface WorkItemListRequest {
     query: WorkItemQuery;
     include_fields?: string[];
+    include_category_counts?: boolean;
 }
 
 /**
@@ -153,7 +190,10 @@ export interface WorkItemListItem {
     project_id: string;
     type_id: string;
     sequential_number?: string;
+    updated_by?: string;
+    archived_at?: string;
     status_detail?: AllowedStatus;
+    status_category?: StatusCategory;
     priority_detail?: PriorityDetail;
     field_values: any[];
 }
@@ -168,6 +208,274 @@ export interface WorkItemListResponse {
     page?: number;
     page_size?: number;
     total_pages?: number;
+    category_counts?: StatusCategoryCounts;
+}
+
+/**
+ * Status Category Counts
+ * Number of matching work items per status category
+ */
+export interface StatusCategoryCounts {
+    todo: number;
+    in_progress: number;
+    done: number;
+    uncategorized: number;
+}
+
+/**
+ * Work Item History Change Type
+ * Matches Rust WorkItemChangeType
+ */
+export type WorkItemChangeType =
+    | "created"
+    | "updated"
+    | "field_value"
+    | "assignment_field_value"
+    | "relationship_added"
+    | "relationship_removed"
+    | "archived"
+    | "restored"
+    | "moved";
+
+/**
+ * Work Item History Entry
+ * Matches Rust WorkItemHistoryEntryModel
+ */
+export interface WorkItemHistoryEntry {
+    id?: string | null;
+    project_id: string;
+    work_item_id: string;
+    change_type: WorkItemChangeType;
+    field?: string | null;
+    old_value?: string | null;
+    new_value?: string | null;
+    changed_by: string;
+    changed_at: string;
+}
+
+/**
+ * Work Item Hierarchy Entry
+ * A work item reached by walking the parent/child hierarchy.
+ * Matches Rust WorkItemHierarchyEntry
+ */
+export interface WorkItemHierarchyEntry {
+    work_item: WorkItemModel;
+    via_work_item_id: string;
+    depth: number;
+}
+
+/**
+ * Work Item Tree Node
+ * Matches Rust WorkItemTreeNode
+ */
+export interface WorkItemTreeNode {
+    work_item: WorkItemModel;
+    depth: number;
+    children: WorkItemTreeNode[];
+}
+
+/**
+ * Work Item Selection
+ * The work items a bulk operation applies to. Pagination on a query selection is ignored.
+ * Matches Rust WorkItemSelection
+ */
+export type WorkItemSelection =
+    | { type: 'ids'; ids: string[] }
+    | { type: 'query'; query: WorkItemQuery };
+
+/**
+ * Field Value Patch
+ * A null value removes the field's value.
+ * Matches Rust FieldValuePatch
+ */
+export interface FieldValuePatch {
+    field_id: string;
+    is_assignment_field?: boolean;
+    value: string | null;
+}
+
+/**
+ * Work Item Patch
+ * Omitted keys are left alone; assigned_to: null unassigns.
+ * Matches Rust WorkItemPatch
+ */
+export interface WorkItemPatch {
+    status?: string;
+    priority?: number;
+    assigned_to?: string | null;
+    field_values?: FieldValuePatch[];
+}
+
+/**
+ * Bulk Item Status
+ * Matches Rust BulkItemStatus
+ */
+export type BulkItemStatus = 'updated' | 'unchanged' | 'failed' | 'skipped';
+
+/**
+ * Bulk Item Result
+ * Matches Rust BulkItemResult
+ */
+export interface BulkItemResult {
+    work_item_id: string;
+    sequential_number?: string | null;
+    status: BulkItemStatus;
+    error?: string | null;
+}
+
+/**
+ * Bulk Update Result
+ * Changes are all-or-nothing: if any item fails, no item is updated.
+ * Matches Rust BulkUpdateResult
+ */
+export interface BulkUpdateResult {
+    results: BulkItemResult[];
+    updated: number;
+    failed: number;
+}
+
+/**
+ * Work Item Type Change
+ * Maps old status and field ids to the new type's. Unmapped values the new type has no field for are kept inactive.
+ * Matches Rust WorkItemTypeChange
+ */
+export interface WorkItemTypeChange {
+    new_type_id: string;
+    status_mapping?: Record<string, string>;
+    field_mapping?: Record<string, string>;
+    assignment_field_mapping?: Record<string, string>;
+}
+
+/**
+ * Work Item Clone Options
+ * What a cloned work item keeps from its source. recursive also clones its non-archived descendants.
+ * Matches Rust WorkItemCloneOptions
+ */
+export interface WorkItemCloneOptions {
+    recursive?: boolean;
+    reset_status?: boolean;
+    clear_assignees?: boolean;
+}
+
+/**
+ * Cloned Work Item
+ * Matches Rust ClonedWorkItem
+ */
+export interface ClonedWorkItem {
+    source_work_item_id: string;
+    work_item_id: string;
+    sequential_number?: string | null;
+}
+
+/**
+ * Work Item Clone Result
+ * The copy of the requested work item, and every copy made (the root first, then descendants by depth)
+ * Matches Rust WorkItemCloneResult
+ */
+export interface WorkItemCloneResult {
+    work_item: WorkItemModel;
+    cloned: ClonedWorkItem[];
+}
+
+/**
+ * Work Item Move
+ * type_changes is keyed by source type id; unmapped types move to the destination type of the same name.
+ * Relationships to work items that stay behind are detached.
+ * Matches Rust WorkItemMove
+ */
+export interface WorkItemMove {
+    destination_project_id: string;
+    type_changes?: Record<string, WorkItemTypeChange>;
+    move_children?: boolean;
+}
+
+/**
+ * Moved Work Item
+ * previous_sequential_number keeps resolving as an alias.
+ * Matches Rust MovedWorkItem
+ */
+export interface MovedWorkItem {
+    work_item_id: string;
+    previous_sequential_number?: string | null;
+    sequential_number: string;
+}
+
+/**
+ * Work Item Move Result
+ * Matches Rust WorkItemMoveResult
+ */
+export interface WorkItemMoveResult {
+    work_item: WorkItemModel;
+    moved: MovedWorkItem[];
+    detached_relationships: number;
+}
+
+/**
+ * Removed Definition Usage
+ * A status or field removed by a type update, with how many work items still use it
+ * Matches Rust RemovedDefinitionUsage
+ */
+export interface RemovedDefinitionUsage {
+    id: string;
+    label: string;
+    work_item_count: number;
+}
+
+/**
+ * Work Item Type Impact
+ * What an update to a work item type would do to the work items of that type
+ * Matches Rust WorkItemTypeImpact
+ */
+export interface WorkItemTypeImpact {
+    work_item_type_id: string;
+    work_item_count: number;
+    removed_statuses: RemovedDefinitionUsage[];
+    removed_fields: RemovedDefinitionUsage[];
+    removed_assignment_fields: RemovedDefinitionUsage[];
+}
+
+/**
+ * Work Item Type Migration
+ * Removed statuses and fields still in use must be mapped to the updated type or orphaned.
+ * Matches Rust WorkItemTypeMigration
+ */
+export interface WorkItemTypeMigration {
+    status_mapping?: Record<string, string>;
+    field_mapping?: Record<string, string>;
+    assignment_field_mapping?: Record<string, string>;
+    orphaned_statuses?: string[];
+    orphaned_fields?: string[];
+    orphaned_assignment_fields?: string[];
+}
+
+/**
+ * Field State
+ * Whether a custom field is shown and required for a work item's current status and values.
+ * Matches Rust FieldState
+ */
+export interface FieldState {
+    field_id: string;
+    visible: boolean;
+    required: boolean;
+}
+
+/**
+ * Saved View
+ * A named work item query and column selection. Views without a person_id are shared.
+ * Matches Rust SavedViewModel
+ */
+export interface SavedView {
+    id?: string | null;
+    project_id: string;
+    person_id?: string | null;
+    name: string;
+    query: WorkItemQuery;
+    include_fields?: string[] | null;
+    created_at: string;
+    updated_at?: string | null;
+    created_by: string;
+    updated_by?: string | null;
+    is_active: boolean;
 }
 
 /**